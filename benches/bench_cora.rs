@@ -4,209 +4,159 @@ use test::{black_box, Bencher};
 
 use std::collections::HashMap;
 
+use heterogeneous_graphlets::perfect_graphlet_hash::{GraphletHashLayout, PolynomialLayout};
 use heterogeneous_graphlets::prelude::*;
 use rayon::prelude::*;
 
-/// Compressed Sparse Row Graph
-pub struct CSRGraph {
-    /// The number of nodes in the graph.
+/// Create a new scale-free CSRGraph using preferential attachment.
+///
+/// # Arguments
+/// * `seed` - The seed of the pseudo-random number generator.
+/// * `number_of_nodes` - The number of nodes in the graph.
+/// * `number_of_node_labels` - The number of node labels in the graph.
+/// * `edges_per_node` - The number of edges each new node attaches with.
+///
+/// # Implementation details
+/// We follow the Barabási-Albert model: each new node is connected to
+/// `edges_per_node` distinct existing nodes, sampled with probability
+/// proportional to their degree. Nodes are labelled round-robin.
+fn scale_free(
+    mut seed: u64,
     number_of_nodes: usize,
-    /// The number of edges in the graph.
-    number_of_edges: usize,
-    /// The number of node labels in the graph.
     number_of_node_labels: u8,
-    /// The node labels of the graph.
-    node_labels: Vec<u8>,
-    /// The offsets of the graph.
-    offsets: Vec<usize>,
-    /// The edges of the graph.
-    edges: Vec<usize>,
-}
-
-unsafe impl Send for CSRGraph {}
-unsafe impl Sync for CSRGraph {}
-
-fn read_csv(path: &str) -> Result<Vec<Vec<usize>>, String> {
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(false)
-        .from_path(path)
-        .map_err(|e| e.to_string())?;
-    let mut result = Vec::new();
-    for record in reader.records() {
-        let record = record.map_err(|e| e.to_string())?;
-        result.push(
-            record
-                .into_iter()
-                .map(|value| value.parse::<usize>().map_err(|e| e.to_string()))
-                .collect::<Result<Vec<usize>, String>>()?,
-        );
-    }
-    Ok(result)
-}
-
-impl CSRGraph {
-    /// Create a new CSRGraph from the provided node list and edge list.
-    ///
-    /// # Arguments
-    /// * `node_list_path` - The path to the node list.
-    /// * `edge_list_path` - The path to the edge list.
-    ///
-    /// # Implementation details
-    /// We expect the node list to be a csv file containing a single column
-    /// with the NUMERIC node labels. The length of the column must be equal
-    /// to the number of nodes in the graph. An example of the node list is
-    /// the following:
-    ///
-    /// ```csv
-    /// 0
-    /// 1
-    /// 2
-    /// 1
-    /// 4
-    /// ```
-    ///
-    /// The edge list must be a csv file containing two columns with the NUMERIC
-    /// source and destination node IDs. The length of the columns must be equal
-    /// to the number of edges in the graph. An example of the edge list is the
-    /// following:
-    ///
-    /// ```csv
-    /// 0,1
-    /// 1,2
-    /// 2,3
-    /// 1,4
-    /// 4,5
-    /// ```
-    ///
-    pub fn from_csv(node_list_path: &str, edge_list_path: &str) -> Result<Self, String> {
-        let mut edge_list = read_csv(edge_list_path)?;
-        edge_list.sort_unstable();
-
-        let number_of_edges = edge_list.len();
-
-        let node_labels = read_csv(node_list_path)?
-            .into_iter()
-            .map(|node_label| {
-                assert!(node_label.len() == 1);
-                node_label[0] as u8
-            })
-            .collect::<Vec<u8>>();
-        let number_of_nodes = node_labels.len();
-        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
-        let mut edges = Vec::with_capacity(number_of_edges);
-
-        let mut current_offset = 0;
-        let mut current_node = 0;
-        offsets.push(current_offset);
-
-        for edge in edge_list {
-            let src = edge[0];
-            let dst = edge[1];
-            assert!(
-                src < number_of_nodes,
-                "src: {}, number_of_nodes: {}",
-                src,
-                number_of_nodes
-            );
-            assert!(
-                dst < number_of_nodes,
-                "dst: {}, number_of_nodes: {}",
-                dst,
-                number_of_nodes
-            );
-            assert!(src != dst, "Self-loops are not supported.");
-            if src != current_node {
-                current_node = src;
-                offsets.push(current_offset);
+    edges_per_node: usize,
+) -> CSRGraph {
+    let mut next_random = move || {
+        // Xorshift64, which requires a non-zero state.
+        seed ^= seed << 13;
+        seed ^= seed >> 7;
+        seed ^= seed << 17;
+        seed as usize
+    };
+    let mut edges = Vec::new();
+    // Each node appears in this list once per incident edge, so that
+    // uniformly sampling from it is a degree-proportional sampling.
+    let mut endpoints: Vec<usize> = Vec::new();
+    for src in 1..number_of_nodes {
+        let mut destinations = Vec::with_capacity(edges_per_node);
+        while destinations.len() < edges_per_node.min(src) {
+            let dst = if endpoints.is_empty() {
+                next_random() % src
+            } else {
+                endpoints[next_random() % endpoints.len()]
+            };
+            if !destinations.contains(&dst) {
+                destinations.push(dst);
             }
-            current_offset += 1;
-            edges.push(dst);
         }
-
-        while offsets.len() <= number_of_nodes {
-            offsets.push(current_offset);
+        for dst in destinations {
+            edges.push((src, dst));
+            edges.push((dst, src));
+            endpoints.push(src);
+            endpoints.push(dst);
         }
-
-        Ok(Self {
-            number_of_nodes,
-            number_of_edges,
-            number_of_node_labels: node_labels.iter().max().unwrap() + 1,
-            node_labels,
-            offsets,
-            edges,
-        })
     }
 
-    /// Iterates in parallel over the edges.
-    pub fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
-        (0..self.number_of_nodes)
-            .into_par_iter()
-            .flat_map(move |node| {
-                let src_offset = self.offsets[node];
-                let dst_offset = self.offsets[node + 1];
-                self.edges[src_offset..dst_offset]
-                    .par_iter()
-                    .map(move |dst| (node, *dst))
-            })
-    }
+    CSRGraph::from_edges(
+        (0..number_of_nodes)
+            .map(|node| (node % number_of_node_labels as usize) as u8)
+            .collect(),
+        edges,
+    )
+    .unwrap()
+}
 
-    /// Iterates over the edges.
-    pub fn iter_edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
-        (0..self.number_of_nodes).into_iter().flat_map(move |node| {
-            let src_offset = self.offsets[node];
-            let dst_offset = self.offsets[node + 1];
-            self.edges[src_offset..dst_offset]
-                .iter()
-                .map(move |dst| (node, *dst))
-        })
+/// Create a new dense random CSRGraph, following the Erdős–Rényi model.
+///
+/// # Arguments
+/// * `seed` - The non-zero seed of the random number generator.
+/// * `number_of_nodes` - The number of nodes of the graph.
+/// * `number_of_node_labels` - The number of node labels, assigned round-robin.
+/// * `edge_percentage` - The probability, in percent, of each edge to appear.
+fn dense_random(
+    mut seed: u64,
+    number_of_nodes: usize,
+    number_of_node_labels: u8,
+    edge_percentage: u64,
+) -> CSRGraph {
+    let mut edges = Vec::new();
+    for src in 0..number_of_nodes {
+        for dst in (src + 1)..number_of_nodes {
+            // Xorshift64, which requires a non-zero state.
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            if seed % 100 < edge_percentage {
+                edges.push((src, dst));
+                edges.push((dst, src));
+            }
+        }
     }
+
+    CSRGraph::from_edges(
+        (0..number_of_nodes)
+            .map(|node| (node % number_of_node_labels as usize) as u8)
+            .collect(),
+        edges,
+    )
+    .unwrap()
 }
 
-impl Graph for CSRGraph {
+/// A view of a CSRGraph counting its graphlets with the dense counter.
+pub struct DenseCounterGraph<'a>(&'a CSRGraph);
+
+impl<'a> Graph for DenseCounterGraph<'a> {
     type Node = usize;
-    type NeighbourIter<'a> = std::iter::Copied<std::slice::Iter<'a, usize>>;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
 
     fn get_number_of_nodes(&self) -> usize {
-        self.number_of_nodes
+        self.0.get_number_of_nodes()
     }
 
     fn get_number_of_edges(&self) -> usize {
-        self.number_of_edges
+        self.0.get_number_of_edges()
     }
 
     fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
-        let src_offset = self.offsets[node];
-        let dst_offset = self.offsets[node + 1];
-        self.edges[src_offset..dst_offset].iter().copied()
+        self.0.iter_neighbours(node)
     }
 }
 
-impl TypedGraph for CSRGraph {
+impl<'a> ParallelGraph for DenseCounterGraph<'a> {
+    fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
+        self.0.par_iter_edges()
+    }
+}
+
+impl<'a> TypedGraph for DenseCounterGraph<'a> {
     type NodeLabel = u8;
 
     fn get_number_of_node_labels(&self) -> Self::NodeLabel {
-        self.number_of_node_labels
+        self.0.get_number_of_node_labels()
     }
 
     fn get_number_of_node_labels_usize(&self) -> usize {
-        self.number_of_node_labels as usize
+        self.0.get_number_of_node_labels_usize()
     }
 
     fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
-        label_index as u8
+        self.0.get_node_label_from_usize(label_index)
     }
 
     fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
-        label as usize
+        self.0.get_node_label_index(label)
     }
 
     fn get_node_label(&self, node: usize) -> Self::NodeLabel {
-        self.node_labels[node] as u8
+        self.0.get_node_label(node)
     }
 }
 
-impl HeterogeneousGraphlets<u16, u32> for CSRGraph {
-    type GraphLetCounter = HashMap<u16, u32>;
+impl<'a> HeterogeneousGraphlets<u16, u32> for DenseCounterGraph<'a> {
+    type GraphLetCounter = DenseGraphletCounter<u16, u32>;
 }
 
 #[bench]
@@ -249,6 +199,61 @@ fn bench_single_thread_citeseer(b: &mut Bencher) {
     });
 }
 
+#[bench]
+fn bench_single_thread_citeseer_hashmap_counter(b: &mut Bencher) {
+    let graph = CSRGraph::from_csv(
+        "tests/data/citeseer/node_list.csv",
+        "tests/data/citeseer/edge_list.csv",
+    )
+    .unwrap();
+    eprintln!(
+        "CiteSeer with the HashMap counter: {} node labels",
+        graph.get_number_of_node_labels()
+    );
+    b.iter(|| {
+        let mut counter: HashMap<u16, u32> = HashMap::new();
+        graph
+            .iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                for (graphlet, count) in graph.get_heterogeneous_graphlet(src, dst) {
+                    counter.insert_count(graphlet, count);
+                }
+            });
+        black_box(counter)
+    });
+}
+
+#[bench]
+fn bench_single_thread_citeseer_dense_counter(b: &mut Bencher) {
+    let graph = CSRGraph::from_csv(
+        "tests/data/citeseer/node_list.csv",
+        "tests/data/citeseer/edge_list.csv",
+    )
+    .unwrap();
+    eprintln!(
+        "CiteSeer with the dense counter: {} node labels",
+        graph.get_number_of_node_labels()
+    );
+    let dense_graph = DenseCounterGraph(&graph);
+    b.iter(|| {
+        let mut counter: DenseGraphletCounter<u16, u32> =
+            DenseGraphletCounter::with_number_of_elements(graph.get_number_of_node_labels());
+        graph
+            .iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                for (graphlet, count) in dense_graph
+                    .get_heterogeneous_graphlet(src, dst)
+                    .iter_graphlets_and_counts()
+                {
+                    counter.insert_count(graphlet, count);
+                }
+            });
+        black_box(counter)
+    });
+}
+
 #[bench]
 fn bench_24_threads_cora(b: &mut Bencher) {
     let graph = CSRGraph::from_csv(
@@ -288,3 +293,98 @@ fn bench_24_threads_citeseer(b: &mut Bencher) {
         });
     });
 }
+
+#[bench]
+fn bench_24_threads_cora_reduced_counters(b: &mut Bencher) {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+    let dense_graph = DenseCounterGraph(&graph);
+    b.iter(|| black_box(dense_graph.par_count_all_graphlets()));
+}
+
+#[bench]
+fn bench_24_threads_cora_atomic_counter(b: &mut Bencher) {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+    let dense_graph = DenseCounterGraph(&graph);
+    let maximal_hash = <PolynomialLayout as GraphletHashLayout<u16, u8>>::maximal_hash::<
+        ExtendedGraphletType,
+    >(graph.get_number_of_node_labels());
+    b.iter(|| {
+        let atomic_counter = DenseAtomicCounter::new(maximal_hash);
+        dense_graph.par_count_all_graphlets_into(&atomic_counter);
+        black_box(atomic_counter.into_counter())
+    });
+}
+
+#[bench]
+fn bench_24_threads_scale_free(b: &mut Bencher) {
+    let graph = scale_free(0x5EED, 2_000, 4, 3);
+    b.iter(|| {
+        graph
+            .par_iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                black_box(graph.get_heterogeneous_graphlet(src, dst));
+            });
+    });
+}
+
+#[bench]
+fn bench_24_threads_scale_free_by_cost(b: &mut Bencher) {
+    let graph = scale_free(0x5EED, 2_000, 4, 3);
+    b.iter(|| {
+        graph
+            .par_iter_edges_by_cost()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                black_box(graph.get_heterogeneous_graphlet(src, dst));
+            });
+    });
+}
+
+#[bench]
+fn bench_24_threads_dense_random(b: &mut Bencher) {
+    let graph = dense_random(0x5EED, 100, 4, 50);
+    b.iter(|| {
+        graph
+            .par_iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                black_box(graph.get_heterogeneous_graphlet(src, dst));
+            });
+    });
+}
+
+#[bench]
+fn bench_random_graph_iter_neighbours(b: &mut Bencher) {
+    let graph = RandomGraph::new(0x5EED, 10_000, 4, 3);
+    b.iter(|| {
+        black_box(
+            (0..graph.get_number_of_nodes())
+                .map(|node| graph.iter_neighbours(node).count())
+                .sum::<usize>(),
+        )
+    });
+}
+
+#[cfg(feature = "roaring")]
+#[bench]
+fn bench_24_threads_dense_random_roaring(b: &mut Bencher) {
+    let graph = dense_random(0x5EED, 100, 4, 50);
+    let roaring_graph = RoaringGraph::new(&graph).unwrap();
+    b.iter(|| {
+        graph
+            .par_iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .for_each(|(src, dst)| {
+                black_box(roaring_graph.get_heterogeneous_graphlet(src, dst));
+            });
+    });
+}
@@ -0,0 +1,99 @@
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::ops::{Add, Mul};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::numbers::Primitive;
+use crate::prelude::*;
+
+/// Trait of the graphlet counters that can be shared across threads.
+///
+/// Differently from [`GraphLetCounter`], the counts are inserted through a shared
+/// reference, so that the threads of a parallel counting can all insert into the
+/// same counter instead of merging their own ones.
+pub trait ConcurrentGraphLetCounter<Graphlet, Count>: Sync {
+    /// Inserts the provided number of graphlets, concurrently with the other insertions.
+    ///
+    /// # Arguments
+    /// * `graphlet` - The graphlet to insert into the graphlet set.
+    /// * `count` - The number of graphlets to insert.
+    fn insert_count(&self, graphlet: Graphlet, count: Count);
+
+    /// Adds the provided number to the node quadruples examined while counting.
+    ///
+    /// # Arguments
+    /// * `n` - The number of newly examined node quadruples.
+    fn record_examined(&self, n: Count);
+}
+
+/// A graphlet counter storing the counts in a vector of atomics indexed by the graphlet hashes.
+///
+/// As the [`DenseGraphletCounter`], it is best suited for graphs with few node labels,
+/// and it is meant to be shared across the threads of
+/// [`HeterogeneousGraphlets::par_count_all_graphlets_into`], which increment the
+/// counts directly instead of folding and merging their own counters.
+///
+/// # Implementation details
+/// The vector cannot grow while it is shared, and is therefore sized upfront for the
+/// largest graphlet hash. The counts are incremented with relaxed atomic additions,
+/// as they are only read once the counting is over. Since the counts cannot be
+/// borrowed while they may be incremented, the counter does not implement
+/// [`GraphLetCounter`], and is converted into a [`DenseGraphletCounter`] with
+/// [`DenseAtomicCounter::into_counter`] to be queried.
+#[derive(Debug)]
+pub struct DenseAtomicCounter<Graphlet> {
+    /// The count of each graphlet, where the i-th entry refers to the graphlet i.
+    counts: Vec<AtomicU64>,
+    /// The number of node quadruples examined while counting.
+    examined: AtomicU64,
+    /// The graphlet type, which does not need to be shared across threads.
+    graphlet: PhantomData<fn() -> Graphlet>,
+}
+
+impl<Graphlet> DenseAtomicCounter<Graphlet>
+where
+    Graphlet: Debug + Copy + Primitive<usize> + Mul<Output = Graphlet> + Add<Output = Graphlet>,
+    usize: Primitive<Graphlet>,
+{
+    /// Returns a new counter for the graphlet hashes up to the provided one.
+    ///
+    /// # Arguments
+    /// * `maximal_hash` - The largest graphlet hash to be counted, such as the
+    ///   [`maximal_hash`](crate::perfect_graphlet_hash::GraphletHashLayout::maximal_hash)
+    ///   of the layout.
+    pub fn new(maximal_hash: Graphlet) -> Self {
+        Self {
+            counts: (0..=usize::convert(maximal_hash))
+                .map(|_| AtomicU64::new(0))
+                .collect(),
+            examined: AtomicU64::new(0),
+            graphlet: PhantomData,
+        }
+    }
+
+    /// Returns the counts as a dense graphlet counter.
+    pub fn into_counter(self) -> DenseGraphletCounter<Graphlet, u64> {
+        let mut graphlet_counter = DenseGraphletCounter::with_number_of_elements(());
+        for (graphlet, count) in self.counts.into_iter().enumerate() {
+            graphlet_counter.insert_count(Graphlet::convert(graphlet), count.into_inner());
+        }
+        graphlet_counter.record_examined(self.examined.into_inner());
+        graphlet_counter
+    }
+}
+
+impl<Graphlet, Count> ConcurrentGraphLetCounter<Graphlet, Count> for DenseAtomicCounter<Graphlet>
+where
+    usize: Primitive<Graphlet>,
+    u64: Primitive<Count>,
+{
+    /// # Raises
+    /// * If the graphlet hash is larger than the maximal hash of the counter.
+    fn insert_count(&self, graphlet: Graphlet, count: Count) {
+        self.counts[usize::convert(graphlet)].fetch_add(u64::convert(count), Ordering::Relaxed);
+    }
+
+    fn record_examined(&self, n: Count) {
+        self.examined.fetch_add(u64::convert(n), Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,132 @@
+use crate::perfect_graphlet_hash::max_labels_for_width;
+use crate::prelude::*;
+use std::collections::HashMap;
+
+/// The graphlet counts of a graph, computed with the smallest viable graphlet width.
+///
+/// Each variant holds the counter keyed by the graphlet hashes of the corresponding
+/// width, computed with the default
+/// [`PolynomialLayout`](crate::perfect_graphlet_hash::PolynomialLayout).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AutoWidthCounts {
+    U16(HashMap<u16, u64>),
+    U32(HashMap<u32, u64>),
+    U64(HashMap<u64, u64>),
+    U128(HashMap<u128, u64>),
+}
+
+impl AutoWidthCounts {
+    /// Returns the number of bits of the graphlet hashes.
+    pub fn width(&self) -> usize {
+        match self {
+            AutoWidthCounts::U16(_) => 16,
+            AutoWidthCounts::U32(_) => 32,
+            AutoWidthCounts::U64(_) => 64,
+            AutoWidthCounts::U128(_) => 128,
+        }
+    }
+
+    /// Returns the number of distinct graphlets in the counter.
+    pub fn len(&self) -> usize {
+        match self {
+            AutoWidthCounts::U16(counter) => counter.len(),
+            AutoWidthCounts::U32(counter) => counter.len(),
+            AutoWidthCounts::U64(counter) => counter.len(),
+            AutoWidthCounts::U128(counter) => counter.len(),
+        }
+    }
+
+    /// Returns whether the counter is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the count of the provided graphlet hash, widened to `u128`.
+    ///
+    /// # Arguments
+    /// * `graphlet` - The graphlet hash whose count should be returned.
+    ///
+    /// # Implementation details
+    /// Hashes that do not fit the width of the counter have a zero count.
+    pub fn get(&self, graphlet: u128) -> u64 {
+        let count = match self {
+            AutoWidthCounts::U16(counter) => u16::try_from(graphlet)
+                .ok()
+                .and_then(|graphlet| counter.get(&graphlet)),
+            AutoWidthCounts::U32(counter) => u32::try_from(graphlet)
+                .ok()
+                .and_then(|graphlet| counter.get(&graphlet)),
+            AutoWidthCounts::U64(counter) => u64::try_from(graphlet)
+                .ok()
+                .and_then(|graphlet| counter.get(&graphlet)),
+            AutoWidthCounts::U128(counter) => counter.get(&graphlet),
+        };
+        count.copied().unwrap_or(0)
+    }
+
+    /// Returns the graphlet hashes, widened to `u128`, and their counts.
+    pub fn to_widened(&self) -> HashMap<u128, u64> {
+        match self {
+            AutoWidthCounts::U16(counter) => counter
+                .iter()
+                .map(|(&graphlet, &count)| (graphlet as u128, count))
+                .collect(),
+            AutoWidthCounts::U32(counter) => counter
+                .iter()
+                .map(|(&graphlet, &count)| (graphlet as u128, count))
+                .collect(),
+            AutoWidthCounts::U64(counter) => counter
+                .iter()
+                .map(|(&graphlet, &count)| (graphlet as u128, count))
+                .collect(),
+            AutoWidthCounts::U128(counter) => counter.clone(),
+        }
+    }
+}
+
+/// Returns the graphlet counts of the provided graph, computed with the smallest viable width.
+///
+/// # Arguments
+/// * `graph` - The graph whose graphlets should be counted.
+///
+/// # Implementation details
+/// The width is the smallest among `u16`, `u32`, `u64` and `u128` whose
+/// [`max_labels_for_width`] is at least the number of node labels of the graph,
+/// so that the maximal hash of the default
+/// [`PolynomialLayout`](crate::perfect_graphlet_hash::PolynomialLayout) does not overflow.
+///
+/// # Raises
+/// * If the graph has more node labels than `u128` hashes can encode.
+pub fn count_with_auto_width<G>(graph: &G) -> AutoWidthCounts
+where
+    G: TypedGraph<NodeLabel = usize>
+        + HeterogeneousGraphlets<u16, u64, GraphLetCounter = HashMap<u16, u64>>
+        + HeterogeneousGraphlets<u32, u64, GraphLetCounter = HashMap<u32, u64>>
+        + HeterogeneousGraphlets<u64, u64, GraphLetCounter = HashMap<u64, u64>>
+        + HeterogeneousGraphlets<u128, u64, GraphLetCounter = HashMap<u128, u64>>,
+{
+    let number_of_node_labels = graph.get_number_of_node_labels();
+    if number_of_node_labels <= max_labels_for_width::<u16>() {
+        AutoWidthCounts::U16(HeterogeneousGraphlets::<u16, u64>::count_all_graphlets(
+            graph,
+        ))
+    } else if number_of_node_labels <= max_labels_for_width::<u32>() {
+        AutoWidthCounts::U32(HeterogeneousGraphlets::<u32, u64>::count_all_graphlets(
+            graph,
+        ))
+    } else if number_of_node_labels <= max_labels_for_width::<u64>() {
+        AutoWidthCounts::U64(HeterogeneousGraphlets::<u64, u64>::count_all_graphlets(
+            graph,
+        ))
+    } else if number_of_node_labels <= max_labels_for_width::<u128>() {
+        AutoWidthCounts::U128(HeterogeneousGraphlets::<u128, u64>::count_all_graphlets(
+            graph,
+        ))
+    } else {
+        panic!(
+            "The graph has {} node labels, but at most {} can be encoded in `u128` graphlets.",
+            number_of_node_labels,
+            max_labels_for_width::<u128>()
+        );
+    }
+}
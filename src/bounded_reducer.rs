@@ -0,0 +1,296 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::Debug;
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::ops::{Add, AddAssign, Mul};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::numbers::*;
+use crate::prelude::*;
+
+/// The number of bytes of each record of a run, i.e. a graphlet and its count as `u128`.
+const RECORD_SIZE: usize = 32;
+
+/// The number of runs written so far by the current process, used to name the runs.
+static NUMBER_OF_RUNS: AtomicUsize = AtomicUsize::new(0);
+
+/// A graphlet counter that bounds its memory usage by flushing its counts to disk.
+///
+/// # Implementation details
+/// The counts are accumulated in an in-memory map until it holds more than the provided
+/// number of graphlets. At that point the map is sorted by graphlet and written to a new
+/// file, called a run, and cleared. The runs are merged at the end by
+/// [`into_sorted_iter`](BoundedReducer::into_sorted_iter), which only keeps one record
+/// per run in memory, so that the merged counts never need to fit in memory at once.
+///
+/// Each record of a run stores the graphlet and its count as little-endian `u128`.
+/// The runs are removed once merged, or when the reducer is dropped.
+pub struct BoundedReducer<Graphlet, Count> {
+    /// The counts accumulated in memory since the last flush.
+    counter: HashMap<Graphlet, Count>,
+    /// The maximal number of graphlets to keep in memory.
+    maximal_number_of_graphlets: usize,
+    /// The directory where the runs are written.
+    directory: PathBuf,
+    /// The paths of the runs written so far.
+    runs: Vec<PathBuf>,
+}
+
+impl<Graphlet, Count> BoundedReducer<Graphlet, Count>
+where
+    Graphlet: Copy + Eq + Hash + Ord + Primitive<u128>,
+    Count: Copy + AddAssign + Primitive<u128>,
+    u128: Primitive<Graphlet> + Primitive<Count>,
+{
+    /// Returns a new reducer writing its runs to the temporary directory of the system.
+    ///
+    /// # Arguments
+    /// * `maximal_number_of_graphlets` - The maximal number of graphlets to keep in memory.
+    ///
+    /// # Raises
+    /// * If the provided maximal number of graphlets is zero.
+    pub fn new(maximal_number_of_graphlets: usize) -> Result<Self, String> {
+        Self::with_directory(maximal_number_of_graphlets, std::env::temp_dir())
+    }
+
+    /// Returns a new reducer writing its runs to the provided directory.
+    ///
+    /// # Arguments
+    /// * `maximal_number_of_graphlets` - The maximal number of graphlets to keep in memory.
+    /// * `directory` - The existing directory where the runs are written.
+    ///
+    /// # Raises
+    /// * If the provided maximal number of graphlets is zero.
+    pub fn with_directory(
+        maximal_number_of_graphlets: usize,
+        directory: PathBuf,
+    ) -> Result<Self, String> {
+        if maximal_number_of_graphlets == 0 {
+            return Err("The maximal number of graphlets must be strictly positive.".to_string());
+        }
+        Ok(Self {
+            counter: HashMap::new(),
+            maximal_number_of_graphlets,
+            directory,
+            runs: Vec::new(),
+        })
+    }
+
+    /// Returns the number of runs written to disk so far.
+    pub fn number_of_runs(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Inserts the provided count of the provided graphlet, flushing to disk if needed.
+    ///
+    /// # Arguments
+    /// * `graphlet` - The graphlet to insert.
+    /// * `count` - The number of times the graphlet should be inserted.
+    ///
+    /// # Raises
+    /// * If the counts cannot be written to disk.
+    pub fn insert_count(&mut self, graphlet: Graphlet, count: Count) -> Result<(), String> {
+        match self.counter.get_mut(&graphlet) {
+            Some(current_count) => *current_count += count,
+            None => {
+                self.counter.insert(graphlet, count);
+            }
+        }
+        if self.counter.len() > self.maximal_number_of_graphlets {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Inserts all the counts of the provided counter.
+    ///
+    /// # Arguments
+    /// * `counter` - The counter whose counts should be inserted.
+    ///
+    /// # Raises
+    /// * If the counts cannot be written to disk.
+    pub fn insert_counter<C>(&mut self, counter: &C) -> Result<(), String>
+    where
+        C: GraphLetCounter<Graphlet, Count>,
+        Graphlet: Debug + Mul<Output = Graphlet> + Add<Output = Graphlet>,
+        Count: Debug + One,
+    {
+        for (&graphlet, &count) in counter.iter_graphlets_and_counts_ref() {
+            self.insert_count(graphlet, count)?;
+        }
+        Ok(())
+    }
+
+    /// Moves the counts and the runs of the provided reducer into the current one.
+    ///
+    /// # Arguments
+    /// * `other` - The reducer to absorb, for instance the one of another thread.
+    ///
+    /// # Raises
+    /// * If the counts cannot be written to disk.
+    pub fn append(&mut self, mut other: Self) -> Result<(), String> {
+        self.runs.append(&mut other.runs);
+        for (graphlet, count) in std::mem::take(&mut other.counter) {
+            self.insert_count(graphlet, count)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the counts accumulated in memory to a new sorted run.
+    ///
+    /// # Raises
+    /// * If the run cannot be written to disk.
+    pub fn flush(&mut self) -> Result<(), String> {
+        if self.counter.is_empty() {
+            return Ok(());
+        }
+        let mut counts = self.counter.drain().collect::<Vec<(Graphlet, Count)>>();
+        counts.sort_unstable_by_key(|&(graphlet, _)| graphlet);
+
+        let path = self.directory.join(format!(
+            "heterogeneous_graphlets_run_{}_{}.bin",
+            std::process::id(),
+            NUMBER_OF_RUNS.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut writer = BufWriter::new(File::create(&path).map_err(|e| e.to_string())?);
+        // We register the run before writing it, so that it is removed on failure.
+        self.runs.push(path);
+        for (graphlet, count) in counts {
+            writer
+                .write_all(&u128::convert(graphlet).to_le_bytes())
+                .and_then(|_| writer.write_all(&u128::convert(count).to_le_bytes()))
+                .map_err(|e| e.to_string())?;
+        }
+        writer.flush().map_err(|e| e.to_string())
+    }
+
+    /// Returns an iterator over the merged counts, sorted by graphlet.
+    ///
+    /// # Raises
+    /// * If the runs cannot be written to or read from disk.
+    pub fn into_sorted_iter(mut self) -> Result<SortedRunsIter<Graphlet, Count>, String> {
+        self.flush()?;
+        // The iterator takes over the runs, and removes them when dropped.
+        let mut iterator = SortedRunsIter {
+            runs: std::mem::take(&mut self.runs),
+            readers: Vec::new(),
+            heap: BinaryHeap::new(),
+            counts: Vec::new(),
+            graphlet: std::marker::PhantomData,
+        };
+        for run_index in 0..iterator.runs.len() {
+            let reader = File::open(&iterator.runs[run_index]).map_err(|e| e.to_string())?;
+            iterator.readers.push(BufReader::new(reader));
+            iterator.counts.push(0);
+            iterator.push_next_record(run_index)?;
+        }
+        Ok(iterator)
+    }
+
+    /// Returns the merged counts.
+    ///
+    /// # Raises
+    /// * If the runs cannot be written to or read from disk.
+    ///
+    /// # Implementation details
+    /// Differently from [`into_sorted_iter`](BoundedReducer::into_sorted_iter), the merged
+    /// counts are collected in memory, which is only viable when they fit in memory.
+    pub fn into_counter(self) -> Result<HashMap<Graphlet, Count>, String> {
+        self.into_sorted_iter()?.collect()
+    }
+}
+
+impl<Graphlet, Count> Drop for BoundedReducer<Graphlet, Count> {
+    fn drop(&mut self) {
+        for path in self.runs.iter() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
+
+/// Iterator over the counts of several sorted runs, merged and sorted by graphlet.
+pub struct SortedRunsIter<Graphlet, Count> {
+    /// The paths of the runs being merged.
+    runs: Vec<PathBuf>,
+    /// The readers of the runs being merged.
+    readers: Vec<BufReader<File>>,
+    /// The smallest unmerged graphlet of each run that is not exhausted, with the index of the run.
+    heap: BinaryHeap<Reverse<(u128, usize)>>,
+    /// The count of the smallest unmerged graphlet of each run.
+    counts: Vec<u128>,
+    /// The type of the graphlets and of the counts.
+    graphlet: std::marker::PhantomData<(Graphlet, Count)>,
+}
+
+impl<Graphlet, Count> SortedRunsIter<Graphlet, Count> {
+    /// Reads the next record of the provided run, if any, and pushes it into the heap.
+    ///
+    /// # Arguments
+    /// * `run_index` - The index of the run to read from.
+    ///
+    /// # Raises
+    /// * If the run cannot be read or is truncated.
+    fn push_next_record(&mut self, run_index: usize) -> Result<(), String> {
+        let mut record = [0_u8; RECORD_SIZE];
+        let mut number_of_read_bytes = 0;
+        while number_of_read_bytes < RECORD_SIZE {
+            match self.readers[run_index].read(&mut record[number_of_read_bytes..]) {
+                Ok(0) => break,
+                Ok(number_of_bytes) => number_of_read_bytes += number_of_bytes,
+                Err(error) => return Err(error.to_string()),
+            }
+        }
+        match number_of_read_bytes {
+            0 => Ok(()),
+            RECORD_SIZE => {
+                let (graphlet, count) = record.split_at(RECORD_SIZE / 2);
+                self.counts[run_index] = u128::from_le_bytes(count.try_into().unwrap());
+                self.heap.push(Reverse((
+                    u128::from_le_bytes(graphlet.try_into().unwrap()),
+                    run_index,
+                )));
+                Ok(())
+            }
+            _ => Err(format!(
+                "The run {:?} is truncated.",
+                self.runs[run_index].display()
+            )),
+        }
+    }
+}
+
+impl<Graphlet, Count> Iterator for SortedRunsIter<Graphlet, Count>
+where
+    Graphlet: Primitive<u128>,
+    Count: Primitive<u128>,
+{
+    type Item = Result<(Graphlet, Count), String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((graphlet, _)) = *self.heap.peek()?;
+        let mut count = 0_u128;
+        // We sum the counts of the graphlet over all the runs containing it.
+        while let Some(&Reverse((other_graphlet, run_index))) = self.heap.peek() {
+            if other_graphlet != graphlet {
+                break;
+            }
+            self.heap.pop();
+            count += self.counts[run_index];
+            if let Err(error) = self.push_next_record(run_index) {
+                return Some(Err(error));
+            }
+        }
+        Some(Ok((Graphlet::convert(graphlet), Count::convert(count))))
+    }
+}
+
+impl<Graphlet, Count> Drop for SortedRunsIter<Graphlet, Count> {
+    fn drop(&mut self) {
+        for path in self.runs.iter() {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+use alloc::{vec, vec::Vec};
+
+/// A view of a typed graph whose node labels are remapped to a dense range.
+///
+/// The graph is structurally identical to the wrapped one, but its node labels
+/// are remapped to the range `0..number_of_distinct_labels`, so that labels that
+/// do not appear in the graph do not inflate the graphlet hash space.
+pub struct CompactLabeledGraph<'a, G: TypedGraph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The compacted label of each label index of the wrapped graph, if it appears in the graph.
+    compacted_labels: Vec<Option<usize>>,
+    /// The number of distinct node labels of the wrapped graph.
+    number_of_node_labels: usize,
+}
+
+impl<'a, G: TypedGraph> CompactLabeledGraph<'a, G> {
+    /// Returns the compacted view of the provided graph and the original node labels.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose node labels should be compacted.
+    ///
+    /// # Implementation details
+    /// The compacted labels follow the order of the original label indices,
+    /// and the i-th element of the returned vector is the original node label
+    /// associated to the compacted label i.
+    pub fn new(graph: &'a G) -> (Self, Vec<G::NodeLabel>) {
+        let mut appearing_labels = vec![false; graph.get_number_of_node_labels_usize()];
+        for node in 0..graph.get_number_of_nodes() {
+            appearing_labels[graph.get_node_label_index(graph.get_node_label(node))] = true;
+        }
+
+        let mut original_labels = Vec::new();
+        let compacted_labels = appearing_labels
+            .into_iter()
+            .enumerate()
+            .map(|(label_index, appears)| {
+                appears.then(|| {
+                    original_labels.push(graph.get_node_label_from_usize(label_index));
+                    original_labels.len() - 1
+                })
+            })
+            .collect::<Vec<_>>();
+
+        (
+            Self {
+                graph,
+                compacted_labels,
+                number_of_node_labels: original_labels.len(),
+            },
+            original_labels,
+        )
+    }
+}
+
+impl<'a, G: TypedGraph> Graph for CompactLabeledGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = G::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for CompactLabeledGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph
+            .get_node_label_from_usize(self.number_of_node_labels)
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(
+            self.compacted_labels[self
+                .graph
+                .get_node_label_index(self.graph.get_node_label(node))]
+            .unwrap(),
+        )
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(CompactLabeledGraph);
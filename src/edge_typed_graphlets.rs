@@ -1,21 +1,221 @@
-use std::fmt::Debug;
-use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+use alloc::collections::BTreeMap;
+#[cfg(feature = "std")]
+use alloc::{format, string::String};
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+#[cfg(feature = "std")]
+use std::collections::hash_map::DefaultHasher;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "rand")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use std::io::Write;
 
 use crate::graphlet_set::*;
-use crate::numbers::{Maximal, One, Primitive, Two, Zero};
+use crate::numbers::{Checked, Maximal, One, Primitive, Two, Zero};
 use crate::orbits::*;
 use crate::{graphlet_counter::GraphLetCounter, perfect_graphlet_hash::*, prelude::*};
 
 use crate::debug_typed_graph::DebugTypedGraph;
+use crate::unlabeled_graph::UnlabeledGraph;
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+/// Value of the last seen neighbour once the neighbours are exhausted, which is
+/// larger than any node so that no further node is considered a neighbour.
+const EXHAUSTED: usize = usize::MAX;
+
+/// Implements [`HeterogeneousGraphlets`] for a view `$view<'a, G>` over a graph `G`,
+/// whose node labels are of the same type of the ones of `G`.
+///
+/// The view uses the same graphlet counter of the wrapped graph.
+macro_rules! impl_heterogeneous_graphlets_for_view {
+    ($view:ident) => {
+        impl<'a, G, Graphlet, Count, Layout>
+            $crate::edge_typed_graphlets::HeterogeneousGraphlets<Graphlet, Count, Layout>
+            for $view<'a, G>
+        where
+            G: $crate::edge_typed_graphlets::HeterogeneousGraphlets<Graphlet, Count, Layout>,
+            Count: core::fmt::Debug
+                + Copy
+                + $crate::numbers::Primitive<usize>
+                + PartialOrd
+                + $crate::numbers::One
+                + $crate::numbers::Two
+                + $crate::numbers::Zero
+                + core::ops::AddAssign
+                + core::ops::Add<Count, Output = Count>
+                + core::ops::Sub<Count, Output = Count>
+                + core::ops::Div<Count, Output = Count>
+                + core::ops::Mul<Count, Output = Count>
+                + core::ops::Rem<Count, Output = Count>
+                + $crate::numbers::Checked,
+            Graphlet: Copy
+                + core::fmt::Debug
+                + $crate::numbers::Maximal
+                + $crate::numbers::Primitive<G::NodeLabel>
+                + From<$crate::graphlet_set::ReducedGraphletType>
+                + From<$crate::graphlet_set::ExtendedGraphletType>
+                + core::ops::Mul<Output = Graphlet>
+                + core::ops::Add<Output = Graphlet>
+                + core::ops::Div<Output = Graphlet>
+                + core::ops::Rem<Output = Graphlet>
+                + core::ops::Sub<Output = Graphlet>
+                + $crate::numbers::One
+                + $crate::numbers::Zero
+                + Ord,
+            u128: $crate::numbers::Primitive<Graphlet>,
+            G::NodeLabel: Ord
+                + $crate::numbers::One
+                + $crate::numbers::Zero
+                + core::ops::Mul<G::NodeLabel, Output = G::NodeLabel>
+                + core::ops::Add<G::NodeLabel, Output = G::NodeLabel>
+                + core::ops::Div<G::NodeLabel, Output = G::NodeLabel>
+                + core::ops::Rem<G::NodeLabel, Output = G::NodeLabel>
+                + Copy,
+            $crate::graphlet_set::ReducedGraphletType:
+                $crate::graphlet_set::GraphletSet<Graphlet> + From<Graphlet>,
+            $crate::graphlet_set::ExtendedGraphletType:
+                $crate::graphlet_set::GraphletSet<Graphlet> + From<Graphlet>,
+            Layout: $crate::perfect_graphlet_hash::GraphletHashLayout<Graphlet, G::NodeLabel>,
+        {
+            type GraphLetCounter = G::GraphLetCounter;
+        }
+    };
+}
+
+pub(crate) use impl_heterogeneous_graphlets_for_view;
+
+/// Buffers of the per-label counts used while counting the graphlets of an edge.
+///
+/// The buffers are cleared, and not reallocated, between the edges, so that a single
+/// scratch can be reused by [`HeterogeneousGraphlets::get_heterogeneous_graphlet_into`]
+/// over all the edges of a graph.
+#[derive(Debug, Clone, Default)]
+pub struct GraphletScratch<Count> {
+    /// The number of triangles closed by a node of each label.
+    triangle_labels_counts: Vec<Count>,
+    /// The number of neighbours of each label solely of the source node.
+    src_neighbour_labels_counts: Vec<Count>,
+    /// The number of neighbours of each label solely of the destination node.
+    dst_neighbour_labels_counts: Vec<Count>,
+}
+
+impl<Count: Copy + Zero> GraphletScratch<Count> {
+    /// Returns a new scratch sized for the provided number of node labels.
+    ///
+    /// # Arguments
+    /// * `number_of_node_labels` - The number of node labels of the graph.
+    ///
+    pub fn new(number_of_node_labels: usize) -> Self {
+        Self {
+            triangle_labels_counts: vec![Count::ZERO; number_of_node_labels],
+            src_neighbour_labels_counts: vec![Count::ZERO; number_of_node_labels],
+            dst_neighbour_labels_counts: vec![Count::ZERO; number_of_node_labels],
+        }
+    }
+
+    /// Sets all the counts to zero, resizing the buffers to the provided number of node labels.
+    ///
+    /// # Arguments
+    /// * `number_of_node_labels` - The number of node labels of the graph.
+    ///
+    fn reset(&mut self, number_of_node_labels: usize) {
+        for counts in [
+            &mut self.triangle_labels_counts,
+            &mut self.src_neighbour_labels_counts,
+            &mut self.dst_neighbour_labels_counts,
+        ] {
+            counts.clear();
+            counts.resize(number_of_node_labels, Count::ZERO);
+        }
+    }
+}
 
-const NOT_UPDATED: usize = usize::MAX;
+/// Calls the provided function on each three- and four-node graphlet including the provided edge.
+///
+/// # Arguments
+/// * `graph` - The graph whose graphlets should be enumerated.
+/// * `src` - The source node of the edge.
+/// * `dst` - The destination node of the edge.
+/// * `callback` - The function called with the kind and the nodes of each graphlet.
+///
+/// # Implementation details
+/// The nodes of each graphlet start with the source and destination nodes, followed
+/// by the further nodes. The further nodes are the neighbours of the edge, and for
+/// the four-node graphlets either a second neighbour of the edge or a neighbour of
+/// the first further node adjacent to neither the source nor the destination node,
+/// so that each connected node set including the edge is enumerated once.
+fn for_each_edge_graphlet<G: Graph>(
+    graph: &G,
+    src: usize,
+    dst: usize,
+    mut callback: impl FnMut(ExtendedGraphletType, &[usize]),
+) {
+    let mut insert = |nodes: &[usize]| {
+        let is_adjacent = |i: usize, j: usize| {
+            i < nodes.len() && j < nodes.len() && graph.is_neighbour(nodes[i], nodes[j])
+        };
+        let degree = |i: usize| (0..4).filter(|&j| is_adjacent(i, j)).count();
+        let graphlet_kind = match (nodes.len(), (0..4).map(degree).sum::<usize>() / 2) {
+            (3, 2) => ExtendedGraphletType::Triad,
+            (3, _) => ExtendedGraphletType::Triangle,
+            (_, 6) => ExtendedGraphletType::FourClique,
+            // The missing edge of the chordal cycles is between the further nodes
+            // when the edge is the chord.
+            (_, 5) if !is_adjacent(2, 3) => ExtendedGraphletType::ChordalCycleCenter,
+            (_, 5) => ExtendedGraphletType::ChordalCycleEdge,
+            (_, 4) if (0..4).all(|i| degree(i) == 2) => ExtendedGraphletType::FourCycle,
+            // The center of the tailed triangles is the node of degree three.
+            (_, 4) => match (degree(0), degree(1)) {
+                (3, 1) | (1, 3) => ExtendedGraphletType::TailedTriTail,
+                (3, 2) | (2, 3) => ExtendedGraphletType::TailedTriEdge,
+                _ => ExtendedGraphletType::TailedTriCenter,
+            },
+            _ if (0..4).any(|i| degree(i) == 3) => ExtendedGraphletType::FourStar,
+            _ if degree(0) == 2 && degree(1) == 2 => ExtendedGraphletType::FourPathCenter,
+            _ => ExtendedGraphletType::FourPathEdge,
+        };
+        callback(graphlet_kind, nodes);
+    };
+
+    // The further nodes adjacent to the source or the destination node.
+    let mut neighbours = graph
+        .iter_neighbours(src)
+        .chain(graph.iter_neighbours(dst))
+        .filter(|&node| node != src && node != dst)
+        .collect::<Vec<usize>>();
+    neighbours.sort_unstable();
+    neighbours.dedup();
+
+    for (i, &first) in neighbours.iter().enumerate() {
+        insert(&[src, dst, first]);
+        // The pairs of further nodes adjacent to the edge are always connected.
+        for &second in neighbours.iter().skip(i + 1) {
+            insert(&[src, dst, first, second]);
+        }
+        // The further nodes adjacent solely to the first node.
+        for second in graph.iter_neighbours(first) {
+            if second != src && second != dst && neighbours.binary_search(&second).is_err() {
+                insert(&[src, dst, first, second]);
+            }
+        }
+    }
+}
 
-pub trait HeterogeneousGraphlets<Graphlet, Count>: TypedGraph
+pub trait HeterogeneousGraphlets<Graphlet, Count, Layout = PolynomialLayout>: TypedGraph
 where
     Count: Debug
         + Copy
         + Primitive<usize>
-        + Ord
+        + PartialOrd
         + One
         + Two
         + Zero
@@ -24,7 +224,8 @@ where
         + Sub<Count, Output = Count>
         + Div<Count, Output = Count>
         + Mul<Count, Output = Count>
-        + Rem<Count, Output = Count>,
+        + Rem<Count, Output = Count>
+        + Checked,
     Self: Sized,
     Graphlet: Copy
         + Debug
@@ -51,13 +252,14 @@ where
         + Copy,
     ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
     ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
-    (
-        Self::NodeLabel,
-        Self::NodeLabel,
-        Self::NodeLabel,
-        Self::NodeLabel,
-    ): PerfectGraphletHash<Graphlet, Self::NodeLabel> + Sized,
+    Layout: GraphletHashLayout<Graphlet, Self::NodeLabel>,
 {
+    /// The counter of the graphlets of an edge.
+    ///
+    /// # Implementation details
+    /// Associated type defaults are not available on stable Rust, so implementors
+    /// must name the counter: the canonical choice is [`DefaultCounter`], as in
+    /// the implementation for [`RandomGraph`].
     type GraphLetCounter: GraphLetCounter<Graphlet, Count>;
 
     #[inline(always)]
@@ -68,37 +270,186 @@ where
     /// * `dst` - The destination node of the edge.
     ///
     fn get_heterogeneous_graphlet(&self, src: usize, dst: usize) -> Self::GraphLetCounter {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        self.get_heterogeneous_graphlet_into(
+            src,
+            dst,
+            &mut GraphletScratch::new(self.get_number_of_node_labels_usize()),
+            &mut graphlet_counter,
+        );
+        graphlet_counter
+    }
+
+    /// Returns the direction of the provided edge and the number of its graphlets, oriented by the direction.
+    ///
+    /// # Arguments
+    /// * `src` - The first node of the edge.
+    /// * `dst` - The second node of the edge.
+    ///
+    /// # Implementation details
+    /// The graphlets are those of the underlying undirected graph, counted from the
+    /// tail to the head of the edge: as the labels of the graphlets start with the
+    /// labels of the first and second node of the edge, the orbit positions of the
+    /// two nodes are told apart by the direction of the edge. Reciprocal edges are
+    /// counted from the first to the second node provided. Only the direction of the
+    /// provided edge is taken into account, while the other edges of the graphlets
+    /// are counted as undirected, so that, for instance, a transitive triangle and a
+    /// cyclic one share their graphlets. Returns `None` when the nodes are not adjacent.
+    ///
+    fn get_directed_heterogeneous_graphlet(
+        &self,
+        src: usize,
+        dst: usize,
+    ) -> Option<(EdgeDirection, Self::GraphLetCounter)>
+    where
+        Self: DirectedGraph,
+    {
+        let direction = self.get_edge_direction(src, dst)?;
+        let graphlet_counter = match direction {
+            EdgeDirection::Forward | EdgeDirection::Reciprocal => {
+                self.get_heterogeneous_graphlet(src, dst)
+            }
+            EdgeDirection::Backward => self.get_heterogeneous_graphlet(dst, src),
+        };
+        Some((direction, graphlet_counter))
+    }
+
+    #[inline(always)]
+    /// Writes the number of graphlets of the provided edge into the provided counter.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `scratch` - The buffers of the per-label counts, reused across calls.
+    /// * `graphlet_counter` - The counter to write the graphlets into.
+    ///
+    /// # Implementation details
+    /// Both the scratch buffers and the counter are cleared before counting, so that
+    /// reusing them over many edges avoids allocating them anew for each edge.
+    /// The previous content of the counter is therefore discarded.
+    ///
+    /// The node quadruples including the edge whose induced subgraph is connected are
+    /// recorded as examined in the counter: since each of them takes part in exactly one
+    /// four-node graphlet of the edge, the examined quadruples are the sum of the counts
+    /// of the four-node graphlet kinds.
+    ///
+    fn get_heterogeneous_graphlet_into(
+        &self,
+        src: usize,
+        dst: usize,
+        scratch: &mut GraphletScratch<Count>,
+        graphlet_counter: &mut Self::GraphLetCounter,
+    ) {
+        self.get_heterogeneous_graphlet_masked_into(
+            src,
+            dst,
+            GraphletMask::all(),
+            scratch,
+            graphlet_counter,
+        );
+    }
+
+    #[inline(always)]
+    /// Returns the number of graphlets of the provided edge, restricted to the provided graphlet types.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `mask` - The graphlet types to count.
+    ///
+    /// # Implementation details
+    /// See [`GraphletMask`] for which graphlet types share their traversal, and thus
+    /// for the speedup that can be expected from a mask.
+    ///
+    fn get_heterogeneous_graphlet_masked(
+        &self,
+        src: usize,
+        dst: usize,
+        mask: GraphletMask,
+    ) -> Self::GraphLetCounter {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        self.get_heterogeneous_graphlet_masked_into(
+            src,
+            dst,
+            mask,
+            &mut GraphletScratch::new(self.get_number_of_node_labels_usize()),
+            &mut graphlet_counter,
+        );
+        graphlet_counter
+    }
+
+    #[inline(always)]
+    /// Writes the number of graphlets of the provided edge, restricted to the provided graphlet types, into the provided counter.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `mask` - The graphlet types to count.
+    /// * `scratch` - The buffers of the per-label counts, reused across calls.
+    /// * `graphlet_counter` - The counter to write the graphlets into.
+    ///
+    /// # Implementation details
+    /// The graphlet types required by the orbit formulas of the graphlet types of the
+    /// mask are counted as well, and removed from the counter once they have been used.
+    /// The traversals finding solely graphlet types that are not counted are skipped.
+    ///
+    /// The examined node quadruples are recorded only along the traversals that are not
+    /// skipped, and are therefore complete only when counting all the graphlet types.
+    ///
+    fn get_heterogeneous_graphlet_masked_into(
+        &self,
+        src: usize,
+        dst: usize,
+        mask: GraphletMask,
+        scratch: &mut GraphletScratch<Count>,
+        graphlet_counter: &mut Self::GraphLetCounter,
+    ) {
         // We check that the provided graphlet type can be encoded in the provided graphlet type.
         debug_assert!(
-            u128::convert(<(
-                Self::NodeLabel,
-                Self::NodeLabel,
-                Self::NodeLabel,
-                Self::NodeLabel
-            ) as PerfectGraphletHash<Graphlet, Self::NodeLabel>>::maximal_hash::<
-                ExtendedGraphletType,
-            >(self.get_number_of_node_labels()))
-                <= u128::convert(Graphlet::MAXIMAL),
+            u128::convert(Layout::maximal_hash::<ExtendedGraphletType>(
+                self.get_number_of_node_labels()
+            )) <= u128::convert(Graphlet::MAXIMAL),
             concat!(
                 "The maximal hash value of the provided graphlet type is larger than the ",
                 "maximum value of the graphlet type. This means that the graphlet type ",
                 "cannot be encoded in the provided graphlet type. Specifically, the ",
-                "maximum hash value is {:?}, while the maximum graphlet value is {:?}."
+                "maximum hash value is {:?}, while the maximum graphlet value is {:?}. ",
+                "See `max_labels_for_width` for the number of labels each graphlet type supports."
             ),
-            <(
-                Self::NodeLabel,
-                Self::NodeLabel,
-                Self::NodeLabel,
-                Self::NodeLabel
-            ) as PerfectGraphletHash<Graphlet, Self::NodeLabel>>::maximal_hash::<
-                ExtendedGraphletType,
-            >(self.get_number_of_node_labels()),
+            Layout::maximal_hash::<ExtendedGraphletType>(self.get_number_of_node_labels()),
             Graphlet::MAXIMAL
         );
 
-        // We allocate the graphlet set for the unique rare graphlets.
-        let mut graphlet_counter =
-            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        // We check that the neighbours of the source and destination nodes are sorted,
+        // as the merges of their neighbourhoods would otherwise miss graphlets.
+        debug_assert!(
+            self.has_sorted_neighbours(src) && self.has_sorted_neighbours(dst),
+            concat!(
+                "The neighbours of the nodes {} and {} must be strictly increasing. ",
+                "See `Graph::validate_sorted_neighbours` to find the nodes whose neighbours are not."
+            ),
+            src,
+            dst
+        );
+
+        // We clear the graphlet set left over by the previous edge.
+        graphlet_counter.clear();
+
+        // We also count the graphlet types required by the orbit formulas of the
+        // graphlet types of the mask, and we check which traversals are needed.
+        let counted = mask.with_dependencies();
+        let traverse_paths = counted.contains_any(&[
+            ExtendedGraphletType::FourPathEdge,
+            ExtendedGraphletType::TailedTriTail,
+            ExtendedGraphletType::FourCycle,
+        ]);
+        let traverse_triangles = counted.contains_any(&[
+            ExtendedGraphletType::FourClique,
+            ExtendedGraphletType::ChordalCycleEdge,
+            ExtendedGraphletType::TailedTriCenter,
+        ]);
 
         // We get the iterator of the neighbours of the source and destination nodes.
         // We observe that the iterators are sorted.
@@ -109,14 +460,26 @@ where
         let src_node_type = self.get_node_label(src);
         let dst_node_type = self.get_node_label(dst);
 
-        // We allocate counters for the node labels of triangles:
-        let mut triangle_labels_counts = vec![Count::ZERO; self.get_number_of_node_labels_usize()];
-        // Similarly, we allocate counters for the node labels of the source and destination neighbours
-        // that are solely neighbours of the source or destination nodes.
-        let mut src_neighbour_labels_counts =
-            vec![Count::ZERO; self.get_number_of_node_labels_usize()];
-        let mut dst_neighbour_labels_counts =
-            vec![Count::ZERO; self.get_number_of_node_labels_usize()];
+        // We check the hashes for overflows of the graphlet type in debug builds, as the
+        // maximal hash of a custom layout may not account for every encoded value.
+        let encode = |labels, graphlet_kind: ExtendedGraphletType, number_of_elements| {
+            if cfg!(debug_assertions) {
+                Layout::try_encode(labels, graphlet_kind, number_of_elements)
+                    .unwrap_or_else(|error| panic!("{}", error))
+            } else {
+                Layout::encode(labels, graphlet_kind, number_of_elements)
+            }
+        };
+
+        // We reset the counters for the node labels of triangles, and similarly the counters
+        // for the node labels of the source and destination neighbours that are solely
+        // neighbours of the source or destination nodes.
+        scratch.reset(self.get_number_of_node_labels_usize());
+        let GraphletScratch {
+            triangle_labels_counts,
+            src_neighbour_labels_counts,
+            dst_neighbour_labels_counts,
+        } = scratch;
 
         // We define here the function used to handle the cases for the typed paths, as it will be
         // necessary to invoce such function multiple times.
@@ -130,20 +493,26 @@ where
 
                 // We have found a 3-path, which can also be called a 3-star.
                 // We compute the hash associated to the 3-star graphlet and insert it into the graphlet counter.
-                graphlet_counter.insert(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label(root),
-                        // A 3-star has only 3 possible node types characterizing it.
-                        // Thus, we can use the last node label as a dummy value.
-                        self.get_number_of_node_labels(),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::Triad,
-                            self.get_number_of_node_labels(),
+                if counted.contains(ExtendedGraphletType::Triad) {
+                    graphlet_counter.insert(encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label(root),
+                            // A 3-star has only 3 possible node types characterizing it.
+                            // Thus, we fill the last position with the dummy label of the layout.
+                            Layout::dummy_label(self.get_number_of_node_labels()),
                         ),
-                );
+                        ExtendedGraphletType::Triad,
+                        self.get_number_of_node_labels(),
+                    ));
+                }
+
+                // The second order neighbours of the root node only take part in the
+                // 4-path-edge, tailed-tri-tail and 4-cycle orbits.
+                if !traverse_paths {
+                    return;
+                }
 
                 // We start to iterate over the neighbours of the provided root node.
                 // The neighbouring nodes must not be equal to the source or destination nodes.
@@ -172,49 +541,37 @@ where
                 // to NOT appear in the source or destination iterators, and if the value is lower than the value of the source or
                 // destination iterators, it will never appear again, and thus it will never appear in the source or destination iterators.
 
-                // These values are surely updated immediately, so for better code clarity we initialize them with a value that is
-                // quite clear instead of using any dummy value.
-                let mut last_src_neighbour = NOT_UPDATED;
-                let mut last_dst_neighbour = NOT_UPDATED;
-
                 // We iterate over the second order neighbours of the root node.
                 while let Some(&second_order_neighbour) = second_order_iterator.peek() {
                     // We skip the second order neighbour if it is the same as the source or destination nodes.
                     if second_order_neighbour == src || second_order_neighbour == dst {
-                        second_order_iterator.advance_by(1).unwrap();
+                        second_order_iterator.next();
                         continue;
                     }
 
                     // If the second order neighbour is larger than the source node,
-                    // we increase the iterator of the source node.
-                    if let Some(&second_order_src) = src_second_order_iterator.peek() {
-                        last_src_neighbour = second_order_src;
-                        if second_order_neighbour > second_order_src {
-                            src_second_order_iterator.advance_by(1).unwrap();
+                    // we increase the iterator of the source node. Once the iterator is exhausted,
+                    // no further node can be a neighbour of the source node.
+                    let last_src_neighbour = match src_second_order_iterator.peek() {
+                        Some(&second_order_src) if second_order_neighbour > second_order_src => {
+                            src_second_order_iterator.next();
                             continue;
                         }
-                    }
+                        Some(&second_order_src) => second_order_src,
+                        None => EXHAUSTED,
+                    };
 
                     // Similarly, if the second order neighbour is larger than the destination node,
-                    // we increase the iterator of the destination node.
-                    if let Some(&second_order_dst) = dst_second_order_iterator.peek() {
-                        last_dst_neighbour = second_order_dst;
-                        if second_order_neighbour > second_order_dst {
-                            dst_second_order_iterator.advance_by(1).unwrap();
+                    // we increase the iterator of the destination node. Once the iterator is exhausted,
+                    // no further node can be a neighbour of the destination node.
+                    let last_dst_neighbour = match dst_second_order_iterator.peek() {
+                        Some(&second_order_dst) if second_order_neighbour > second_order_dst => {
+                            dst_second_order_iterator.next();
                             continue;
                         }
-                    }
-
-                    debug_assert!(last_src_neighbour != NOT_UPDATED);
-                    debug_assert!(last_dst_neighbour != NOT_UPDATED);
-
-                    // If the second order neighbour is larger than both the source and destination neighbouring nodes,
-                    // it means that necessarily both other iterators have finished, and thus we can break the loop.
-                    if second_order_neighbour > last_src_neighbour
-                        && second_order_neighbour > last_dst_neighbour
-                    {
-                        break;
-                    }
+                        Some(&second_order_dst) => second_order_dst,
+                        None => EXHAUSTED,
+                    };
 
                     // If the second order neighbour is smaller than both the source and destination neighbouring nodes,
                     // it means that it is not a neighbour of either the source or destination nodes as the iterators are sorted
@@ -224,21 +581,23 @@ where
                     {
                         // We compute the hash associated to the 4-path-edge orbit
                         // and insert it into the graphlet counter.
-                        graphlet_counter.insert(
-                            (
-                                src_node_type,
-                                dst_node_type,
-                                self.get_node_label(second_order_neighbour),
-                                self.get_node_label(root),
-                            )
-                                .encode_with_graphlet::<ExtendedGraphletType>(
-                                    ExtendedGraphletType::FourPathEdge,
-                                    self.get_number_of_node_labels(),
+                        if counted.contains(ExtendedGraphletType::FourPathEdge) {
+                            graphlet_counter.insert(encode(
+                                (
+                                    src_node_type,
+                                    dst_node_type,
+                                    self.get_node_label(second_order_neighbour),
+                                    self.get_node_label(root),
                                 ),
-                        );
+                                ExtendedGraphletType::FourPathEdge,
+                                self.get_number_of_node_labels(),
+                            ));
+                        }
+                        // The second order neighbour completes a quadruple with the root.
+                        graphlet_counter.record_examined(Count::ONE);
 
                         // Now we can increase the iterator of the second order neighbours.
-                        second_order_iterator.advance_by(1).unwrap();
+                        second_order_iterator.next();
 
                         continue;
                     }
@@ -252,28 +611,28 @@ where
                     {
                         // We compute the hash associated to the tailed-tri-tail orbit
                         // and insert it into the graphlet counter.
-                        graphlet_counter.insert(
-                            (
-                                src_node_type,
-                                dst_node_type,
-                                self.get_node_label(second_order_neighbour),
-                                self.get_node_label(root),
-                            )
-                                .encode_with_graphlet::<ExtendedGraphletType>(
-                                    ExtendedGraphletType::TailedTriTail,
-                                    self.get_number_of_node_labels(),
+                        if counted.contains(ExtendedGraphletType::TailedTriTail) {
+                            graphlet_counter.insert(encode(
+                                (
+                                    src_node_type,
+                                    dst_node_type,
+                                    self.get_node_label(second_order_neighbour),
+                                    self.get_node_label(root),
                                 ),
-                        );
+                                ExtendedGraphletType::TailedTriTail,
+                                self.get_number_of_node_labels(),
+                            ));
+                        }
 
                         // Now we can increase the iterator of the second order neighbours
                         // and the source second order neighbours.
-                        src_second_order_iterator.advance_by(1).unwrap();
-                        second_order_iterator.advance_by(1).unwrap();
+                        src_second_order_iterator.next();
+                        second_order_iterator.next();
 
                         continue;
                     }
 
-                    second_order_iterator.advance_by(1).unwrap();
+                    second_order_iterator.next();
                 }
             };
         let handle_dst_rooted_typed_paths =
@@ -286,20 +645,26 @@ where
 
                 // We have found a 3-path, which can also be called a 3-star.
                 // We compute the hash associated to the 3-star graphlet and insert it into the graphlet counter.
-                graphlet_counter.insert(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label(root),
-                        // A 3-star has only 3 possible node types characterizing it.
-                        // Thus, we can use the last node label as a dummy value.
-                        self.get_number_of_node_labels(),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::Triad,
-                            self.get_number_of_node_labels(),
+                if counted.contains(ExtendedGraphletType::Triad) {
+                    graphlet_counter.insert(encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label(root),
+                            // A 3-star has only 3 possible node types characterizing it.
+                            // Thus, we fill the last position with the dummy label of the layout.
+                            Layout::dummy_label(self.get_number_of_node_labels()),
                         ),
-                );
+                        ExtendedGraphletType::Triad,
+                        self.get_number_of_node_labels(),
+                    ));
+                }
+
+                // The second order neighbours of the root node only take part in the
+                // 4-path-edge, tailed-tri-tail and 4-cycle orbits.
+                if !traverse_paths {
+                    return;
+                }
 
                 // We start to iterate over the neighbours of the provided root node.
                 // The neighbouring nodes must not be equal to the source or destination nodes.
@@ -331,52 +696,39 @@ where
                 // to NOT appear in the source or destination iterators, and if the value is lower than the value of the source or
                 // destination iterators, it will never appear again, and thus it will never appear in the source or destination iterators.
 
-                // These values are surely updated immediately, so for better code clarity we initialize them with a value that is
-                // quite clear instead of using any dummy value.
-
-                let mut last_src_neighbour = NOT_UPDATED;
-                let mut last_dst_neighbour = NOT_UPDATED;
-
                 // We iterate over the second order neighbours of the root node.
 
                 // We iterate over the second order neighbours of the root node.
                 while let Some(&second_order_neighbour) = second_order_iterator.peek() {
                     // We skip the second order neighbour if it is the same as the source or destination nodes.
                     if second_order_neighbour == src || second_order_neighbour == dst {
-                        second_order_iterator.advance_by(1).unwrap();
+                        second_order_iterator.next();
                         continue;
                     }
 
                     // If the second order neighbour is larger than the source node,
-                    // we increase the iterator of the source node.
-                    if let Some(&second_order_src) = src_second_order_iterator.peek() {
-                        last_src_neighbour = second_order_src;
-                        if second_order_neighbour > second_order_src {
-                            src_second_order_iterator.advance_by(1).unwrap();
+                    // we increase the iterator of the source node. Once the iterator is exhausted,
+                    // no further node can be a neighbour of the source node.
+                    let last_src_neighbour = match src_second_order_iterator.peek() {
+                        Some(&second_order_src) if second_order_neighbour > second_order_src => {
+                            src_second_order_iterator.next();
                             continue;
                         }
-                    }
+                        Some(&second_order_src) => second_order_src,
+                        None => EXHAUSTED,
+                    };
 
                     // Similarly, if the second order neighbour is larger than the destination node,
-                    // we increase the iterator of the destination node.
-                    if let Some(&second_order_dst) = dst_second_order_iterator.peek() {
-                        last_dst_neighbour = second_order_dst;
-                        if second_order_neighbour > second_order_dst {
-                            dst_second_order_iterator.advance_by(1).unwrap();
+                    // we increase the iterator of the destination node. Once the iterator is exhausted,
+                    // no further node can be a neighbour of the destination node.
+                    let last_dst_neighbour = match dst_second_order_iterator.peek() {
+                        Some(&second_order_dst) if second_order_neighbour > second_order_dst => {
+                            dst_second_order_iterator.next();
                             continue;
                         }
-                    }
-
-                    debug_assert!(last_src_neighbour != NOT_UPDATED);
-                    debug_assert!(last_dst_neighbour != NOT_UPDATED);
-
-                    // If the second order neighbour is larger than both the source and destination neighbouring nodes,
-                    // it means that necessarily both other iterators have finished, and thus we can break the loop.
-                    if second_order_neighbour > last_src_neighbour
-                        && second_order_neighbour > last_dst_neighbour
-                    {
-                        break;
-                    }
+                        Some(&second_order_dst) => second_order_dst,
+                        None => EXHAUSTED,
+                    };
 
                     // If the second order neighbour is smaller than both the source and destination neighbouring nodes,
                     // it means that it is not a neighbour of either the source or destination nodes as the iterators are sorted
@@ -386,21 +738,23 @@ where
                     {
                         // We compute the hash associated to the 4-path-edge orbit
                         // and insert it into the graphlet counter.
-                        graphlet_counter.insert(
-                            (
-                                src_node_type,
-                                dst_node_type,
-                                self.get_node_label(second_order_neighbour),
-                                self.get_node_label(root),
-                            )
-                                .encode_with_graphlet::<ExtendedGraphletType>(
-                                    ExtendedGraphletType::FourPathEdge,
-                                    self.get_number_of_node_labels(),
+                        if counted.contains(ExtendedGraphletType::FourPathEdge) {
+                            graphlet_counter.insert(encode(
+                                (
+                                    src_node_type,
+                                    dst_node_type,
+                                    self.get_node_label(second_order_neighbour),
+                                    self.get_node_label(root),
                                 ),
-                        );
+                                ExtendedGraphletType::FourPathEdge,
+                                self.get_number_of_node_labels(),
+                            ));
+                        }
+                        // The second order neighbour completes a quadruple with the root.
+                        graphlet_counter.record_examined(Count::ONE);
 
                         // Now we can increase the iterator of the second order neighbours.
-                        second_order_iterator.advance_by(1).unwrap();
+                        second_order_iterator.next();
 
                         continue;
                     }
@@ -414,23 +768,23 @@ where
                     {
                         // We compute the hash associated to the tailed-tri-tail orbit
                         // and insert it into the graphlet counter.
-                        graphlet_counter.insert(
-                            (
-                                src_node_type,
-                                dst_node_type,
-                                self.get_node_label(second_order_neighbour),
-                                self.get_node_label(root),
-                            )
-                                .encode_with_graphlet::<ExtendedGraphletType>(
-                                    ExtendedGraphletType::TailedTriTail,
-                                    self.get_number_of_node_labels(),
+                        if counted.contains(ExtendedGraphletType::TailedTriTail) {
+                            graphlet_counter.insert(encode(
+                                (
+                                    src_node_type,
+                                    dst_node_type,
+                                    self.get_node_label(second_order_neighbour),
+                                    self.get_node_label(root),
                                 ),
-                        );
+                                ExtendedGraphletType::TailedTriTail,
+                                self.get_number_of_node_labels(),
+                            ));
+                        }
 
                         // Now we can increase the iterator of the second order neighbours
                         // and the source second order neighbours.
-                        dst_second_order_iterator.advance_by(1).unwrap();
-                        second_order_iterator.advance_by(1).unwrap();
+                        dst_second_order_iterator.next();
+                        second_order_iterator.next();
 
                         continue;
                     }
@@ -442,28 +796,28 @@ where
                         && second_order_neighbour < last_dst_neighbour
                     {
                         // We compute the hash associated to the 4-cycle
-                        graphlet_counter.insert(
-                            (
-                                src_node_type,
-                                dst_node_type,
-                                self.get_node_label(second_order_neighbour),
-                                self.get_node_label(root),
-                            )
-                                .encode_with_graphlet::<ExtendedGraphletType>(
-                                    ExtendedGraphletType::FourCycle,
-                                    self.get_number_of_node_labels(),
+                        if counted.contains(ExtendedGraphletType::FourCycle) {
+                            graphlet_counter.insert(encode(
+                                (
+                                    src_node_type,
+                                    dst_node_type,
+                                    self.get_node_label(second_order_neighbour),
+                                    self.get_node_label(root),
                                 ),
-                        );
+                                ExtendedGraphletType::FourCycle,
+                                self.get_number_of_node_labels(),
+                            ));
+                        }
 
                         // Now we can increase the iterator of the second order neighbours
                         // and the source second order neighbours.
-                        src_second_order_iterator.advance_by(1).unwrap();
-                        second_order_iterator.advance_by(1).unwrap();
+                        src_second_order_iterator.next();
+                        second_order_iterator.next();
 
                         continue;
                     }
 
-                    second_order_iterator.advance_by(1).unwrap();
+                    second_order_iterator.next();
                 }
             };
 
@@ -472,18 +826,18 @@ where
         {
             // We skip the neighbours if they are the same as the source or destination nodes.
             if src_neighbour == src || src_neighbour == dst {
-                src_iter.advance_by(1).unwrap();
+                src_iter.next();
                 continue;
             }
 
             if dst_neighbour == src || dst_neighbour == dst {
-                dst_iter.advance_by(1).unwrap();
+                dst_iter.next();
                 continue;
             }
 
             match src_neighbour.cmp(&dst_neighbour) {
                 // If the two neighbours are the same, we have identified a triangle.
-                std::cmp::Ordering::Equal => {
+                core::cmp::Ordering::Equal => {
                     // We get the node labels of the source only, as both have
                     // necessarily the same node label.
                     let node_neighbour_type = self.get_node_label(src_neighbour);
@@ -493,20 +847,71 @@ where
                         [self.get_node_label_index(node_neighbour_type)] += Count::ONE;
 
                     // We insert the triangle into the graphlet counter.
-                    graphlet_counter.insert(
-                        (
-                            src_node_type,
-                            dst_node_type,
-                            node_neighbour_type,
-                            // A triangle has only 3 possible node types characterizing it.
-                            // Thus, we can use the last node label as a dummy value.
-                            self.get_number_of_node_labels(),
-                        )
-                            .encode_with_graphlet::<ExtendedGraphletType>(
-                                ExtendedGraphletType::Triangle,
-                                self.get_number_of_node_labels(),
+                    if counted.contains(ExtendedGraphletType::Triangle) {
+                        graphlet_counter.insert(encode(
+                            (
+                                src_node_type,
+                                dst_node_type,
+                                node_neighbour_type,
+                                // A triangle has only 3 possible node types characterizing it.
+                                // Thus, we fill the last position with the dummy label of the layout.
+                                Layout::dummy_label(self.get_number_of_node_labels()),
                             ),
-                    );
+                            ExtendedGraphletType::Triangle,
+                            self.get_number_of_node_labels(),
+                        ));
+                    }
+
+                    // The second order neighbours of the triangle node only take part in the
+                    // 4-clique, chord-cycle-edge and tailed-triangle-center orbits.
+                    if !traverse_triangles {
+                        src_iter.next();
+                        dst_iter.next();
+                        continue;
+                    }
+
+                    // If the graph supports fast neighbourhood membership checks, we classify
+                    // the neighbours of the triangle node by looking them up in the neighbourhoods
+                    // of the source and destination nodes, instead of merging the sorted iterators.
+                    if Self::HAS_FAST_NEIGHBOUR_LOOKUP {
+                        for second_order_neighbour in self.iter_neighbours(src_neighbour) {
+                            if second_order_neighbour == src || second_order_neighbour == dst {
+                                continue;
+                            }
+                            let graphlet_kind = match (
+                                self.is_neighbour(src, second_order_neighbour),
+                                self.is_neighbour(dst, second_order_neighbour),
+                            ) {
+                                // Each 4-clique is counted once, from its smallest triangle node.
+                                (true, true) if second_order_neighbour <= src_neighbour => {
+                                    ExtendedGraphletType::FourClique
+                                }
+                                (true, true) => continue,
+                                (true, false) | (false, true) => {
+                                    ExtendedGraphletType::ChordalCycleEdge
+                                }
+                                (false, false) => {
+                                    graphlet_counter.record_examined(Count::ONE);
+                                    ExtendedGraphletType::TailedTriCenter
+                                }
+                            };
+                            if counted.contains(graphlet_kind) {
+                                graphlet_counter.insert(encode(
+                                    (
+                                        src_node_type,
+                                        dst_node_type,
+                                        node_neighbour_type,
+                                        self.get_node_label(second_order_neighbour),
+                                    ),
+                                    graphlet_kind,
+                                    self.get_number_of_node_labels(),
+                                ));
+                            }
+                        }
+                        src_iter.next();
+                        dst_iter.next();
+                        continue;
+                    }
 
                     // We iterate over the neighbours of the triangle node.
                     // These nodes will be second-order neighbours of the source and destination nodes.
@@ -530,49 +935,41 @@ where
                     // to NOT appear in the source or destination iterators, and if the value is lower than the value of the source or
                     // destination iterators, it will never appear again, and thus it will never appear in the source or destination iterators.
 
-                    // These values are surely updated immediately, so for better code clarity we initialize them with a value that is
-                    // quite clear instead of using any dummy value.
-                    let mut last_src_neighbour = NOT_UPDATED;
-                    let mut last_dst_neighbour = NOT_UPDATED;
-
                     // We iterate over the second order neighbours of the triangle node.
                     while let Some(&second_order_neighbour) = second_order_iterator.peek() {
                         // We skip the second order neighbour if it is the same as the source or destination nodes.
                         if second_order_neighbour == src || second_order_neighbour == dst {
-                            second_order_iterator.advance_by(1).unwrap();
+                            second_order_iterator.next();
                             continue;
                         }
 
                         // If the second order neighbour is larger than the source node,
-                        // we increase the iterator of the source node.
-                        if let Some(&second_order_src) = src_second_order_iterator.peek() {
-                            last_src_neighbour = second_order_src;
-                            if second_order_neighbour > second_order_src {
-                                src_second_order_iterator.advance_by(1).unwrap();
+                        // we increase the iterator of the source node. Once the iterator is exhausted,
+                        // no further node can be a neighbour of the source node.
+                        let last_src_neighbour = match src_second_order_iterator.peek() {
+                            Some(&second_order_src)
+                                if second_order_neighbour > second_order_src =>
+                            {
+                                src_second_order_iterator.next();
                                 continue;
                             }
-                        }
+                            Some(&second_order_src) => second_order_src,
+                            None => EXHAUSTED,
+                        };
 
                         // Similarly, if the second order neighbour is larger than the destination node,
-                        // we increase the iterator of the destination node.
-                        if let Some(&second_order_dst) = dst_second_order_iterator.peek() {
-                            last_dst_neighbour = second_order_dst;
-                            if second_order_neighbour > second_order_dst {
-                                dst_second_order_iterator.advance_by(1).unwrap();
+                        // we increase the iterator of the destination node. Once the iterator is exhausted,
+                        // no further node can be a neighbour of the destination node.
+                        let last_dst_neighbour = match dst_second_order_iterator.peek() {
+                            Some(&second_order_dst)
+                                if second_order_neighbour > second_order_dst =>
+                            {
+                                dst_second_order_iterator.next();
                                 continue;
                             }
-                        }
-
-                        debug_assert!(last_src_neighbour != NOT_UPDATED);
-                        debug_assert!(last_dst_neighbour != NOT_UPDATED);
-
-                        // If the second order neighbour is larger than both the source and destination neighbouring nodes,
-                        // it means that necessarily both other iterators have finished, and thus we can break the loop.
-                        if second_order_neighbour > last_src_neighbour
-                            && second_order_neighbour > last_dst_neighbour
-                        {
-                            break;
-                        }
+                            Some(&second_order_dst) => second_order_dst,
+                            None => EXHAUSTED,
+                        };
 
                         // If the second order neighbour is less or equal to the triangle node,
                         if second_order_neighbour <= src_neighbour
@@ -581,23 +978,23 @@ where
                         {
                             // We compute the hash associated to the 4-clique graphlet
                             // and insert it into the graphlet counter.
-                            graphlet_counter.insert(
-                                (
-                                    src_node_type,
-                                    dst_node_type,
-                                    node_neighbour_type,
-                                    self.get_node_label(last_src_neighbour),
-                                )
-                                    .encode_with_graphlet::<ExtendedGraphletType>(
-                                        ExtendedGraphletType::FourClique,
-                                        self.get_number_of_node_labels(),
+                            if counted.contains(ExtendedGraphletType::FourClique) {
+                                graphlet_counter.insert(encode(
+                                    (
+                                        src_node_type,
+                                        dst_node_type,
+                                        node_neighbour_type,
+                                        self.get_node_label(last_src_neighbour),
                                     ),
-                            );
+                                    ExtendedGraphletType::FourClique,
+                                    self.get_number_of_node_labels(),
+                                ));
+                            }
 
                             // Now we can update all involved iterators with the next value.
-                            src_second_order_iterator.advance_by(1).unwrap();
-                            dst_second_order_iterator.advance_by(1).unwrap();
-                            second_order_iterator.advance_by(1).unwrap();
+                            src_second_order_iterator.next();
+                            dst_second_order_iterator.next();
+                            second_order_iterator.next();
 
                             continue;
                         }
@@ -611,22 +1008,22 @@ where
                         {
                             // In this case, we have identified a chord-cycle-edge orbit.
                             // We compute the hash associated to the chord-cycle-edge graphlet.
-                            graphlet_counter.insert(
-                                (
-                                    src_node_type,
-                                    dst_node_type,
-                                    node_neighbour_type,
-                                    self.get_node_label(second_order_neighbour),
-                                )
-                                    .encode_with_graphlet::<ExtendedGraphletType>(
-                                        ExtendedGraphletType::ChordalCycleEdge,
-                                        self.get_number_of_node_labels(),
+                            if counted.contains(ExtendedGraphletType::ChordalCycleEdge) {
+                                graphlet_counter.insert(encode(
+                                    (
+                                        src_node_type,
+                                        dst_node_type,
+                                        node_neighbour_type,
+                                        self.get_node_label(second_order_neighbour),
                                     ),
-                            );
+                                    ExtendedGraphletType::ChordalCycleEdge,
+                                    self.get_number_of_node_labels(),
+                                ));
+                            }
 
                             // Now we can update all involved iterators with the next value.
-                            src_second_order_iterator.advance_by(1).unwrap();
-                            second_order_iterator.advance_by(1).unwrap();
+                            src_second_order_iterator.next();
+                            second_order_iterator.next();
 
                             continue;
                         }
@@ -663,22 +1060,22 @@ where
 
                             // Again, in this case, we have identified a chord-cycle-edge orbit.
                             // We compute the hash associated to the chord-cycle-edge graphlet.
-                            graphlet_counter.insert(
-                                (
-                                    src_node_type,
-                                    dst_node_type,
-                                    node_neighbour_type,
-                                    self.get_node_label(second_order_neighbour),
-                                )
-                                    .encode_with_graphlet::<ExtendedGraphletType>(
-                                        ExtendedGraphletType::ChordalCycleEdge,
-                                        self.get_number_of_node_labels(),
+                            if counted.contains(ExtendedGraphletType::ChordalCycleEdge) {
+                                graphlet_counter.insert(encode(
+                                    (
+                                        src_node_type,
+                                        dst_node_type,
+                                        node_neighbour_type,
+                                        self.get_node_label(second_order_neighbour),
                                     ),
-                            );
+                                    ExtendedGraphletType::ChordalCycleEdge,
+                                    self.get_number_of_node_labels(),
+                                ));
+                            }
 
                             // Now we can update all involved iterators with the next value.
-                            dst_second_order_iterator.advance_by(1).unwrap();
-                            second_order_iterator.advance_by(1).unwrap();
+                            dst_second_order_iterator.next();
+                            second_order_iterator.next();
 
                             continue;
                         }
@@ -690,29 +1087,30 @@ where
                         {
                             // In this case, we have identified a tailed-triangle-center orbit.
                             // We compute the hash associated to the tailed-triangle-center graphlet.
-                            graphlet_counter.insert(
-                                (
-                                    src_node_type,
-                                    dst_node_type,
-                                    node_neighbour_type,
-                                    self.get_node_label(second_order_neighbour),
-                                )
-                                    .encode_with_graphlet::<ExtendedGraphletType>(
-                                        ExtendedGraphletType::TailedTriCenter,
-                                        self.get_number_of_node_labels(),
+                            if counted.contains(ExtendedGraphletType::TailedTriCenter) {
+                                graphlet_counter.insert(encode(
+                                    (
+                                        src_node_type,
+                                        dst_node_type,
+                                        node_neighbour_type,
+                                        self.get_node_label(second_order_neighbour),
                                     ),
-                            );
+                                    ExtendedGraphletType::TailedTriCenter,
+                                    self.get_number_of_node_labels(),
+                                ));
+                            }
+                            graphlet_counter.record_examined(Count::ONE);
 
                             // Now we can update all involved iterators with the next value.
-                            second_order_iterator.advance_by(1).unwrap();
+                            second_order_iterator.next();
 
                             continue;
                         }
-                        second_order_iterator.advance_by(1).unwrap();
+                        second_order_iterator.next();
                     }
                     // We can now advance the two iterators of the source and destination nodes.
-                    src_iter.advance_by(1).unwrap();
-                    dst_iter.advance_by(1).unwrap();
+                    src_iter.next();
+                    dst_iter.next();
                 }
                 // Otherwise, if the two neighbours are not the same, both
                 // may compose a 3-path with the source and destination nodes.
@@ -721,31 +1119,31 @@ where
                 // the larger node will also appear in the other iterator, but because
                 // of the sorted nature of the iterators we are sure that the smaller
                 // will never appear in the other iterator.
-                std::cmp::Ordering::Less => {
+                core::cmp::Ordering::Less => {
                     // If the source neighbour is smaller than the destination neighbour,
                     // it forms a 3-path with the source and destination nodes.
                     handle_src_rooted_typed_paths(
                         src_neighbour,
-                        &mut graphlet_counter,
-                        &mut src_neighbour_labels_counts,
+                        graphlet_counter,
+                        src_neighbour_labels_counts,
                     );
 
                     // We update the iterator with the lesser of the two nodes, which
                     // in this case is the source iterator:
-                    src_iter.advance_by(1).unwrap();
+                    src_iter.next();
                 }
-                std::cmp::Ordering::Greater => {
+                core::cmp::Ordering::Greater => {
                     // If the destination neighbour is smaller than the source neighbour,
                     // it forms a 3-path with the source and destination nodes.
                     handle_dst_rooted_typed_paths(
                         dst_neighbour,
-                        &mut graphlet_counter,
-                        &mut dst_neighbour_labels_counts,
+                        graphlet_counter,
+                        dst_neighbour_labels_counts,
                     );
 
                     // We update the iterator with the lesser of the two nodes, which
                     // in this case is the destination iterator:
-                    dst_iter.advance_by(1).unwrap();
+                    dst_iter.next();
                 }
             }
         }
@@ -761,8 +1159,8 @@ where
 
             handle_src_rooted_typed_paths(
                 src_neighbour,
-                &mut graphlet_counter,
-                &mut src_neighbour_labels_counts,
+                graphlet_counter,
+                src_neighbour_labels_counts,
             );
         }
 
@@ -775,15 +1173,48 @@ where
 
             handle_dst_rooted_typed_paths(
                 dst_neighbour,
-                &mut graphlet_counter,
-                &mut dst_neighbour_labels_counts,
+                graphlet_counter,
+                dst_neighbour_labels_counts,
             );
         }
 
+        // The remaining examined quadruples are those whose two further nodes are both
+        // neighbours of the source or destination nodes, which are all connected.
+        let number_of_neighbours = triangle_labels_counts
+            .iter()
+            .chain(src_neighbour_labels_counts.iter())
+            .chain(dst_neighbour_labels_counts.iter())
+            .fold(Count::ZERO, |total, &count| total + count);
+        graphlet_counter.record_examined(binomial_two(number_of_neighbours));
+
         // Now we are done with counting some of the triangle-based and path-based graphlets,
         // and we need to complete the process by counting the remaining graphlets with the
         // orbital counts as detailed in the "Heterogeneous Graphlets" paper, equations 19, 23, 26 and 30.
 
+        // The counts of the graphlet types required by the orbit formulas are read once
+        // for each pair of labels, so that those counted solely for the orbit formulas
+        // can be removed from the counter as they are read.
+        let get_or_remove_number_of_graphlets =
+            |graphlet_counter: &mut Self::GraphLetCounter,
+             (rows_label, columns_label): (usize, usize),
+             graphlet_kind: ExtendedGraphletType| {
+                let graphlet = encode(
+                    (
+                        src_node_type,
+                        dst_node_type,
+                        self.get_node_label_from_usize(rows_label),
+                        self.get_node_label_from_usize(columns_label),
+                    ),
+                    graphlet_kind,
+                    self.get_number_of_node_labels(),
+                );
+                if mask.contains(graphlet_kind) {
+                    graphlet_counter.get_number_of_graphlets(graphlet)
+                } else {
+                    graphlet_counter.remove(graphlet)
+                }
+            };
+
         // We start by iterating over the graph labels
         for rows_label in 0..self.get_number_of_node_labels_usize() {
             let number_of_triangles_with_row_label = triangle_labels_counts[rows_label];
@@ -800,12 +1231,12 @@ where
                     "of neighbours of the source and destination nodes with the same label. ",
                     "We expected {:?} but found {:?}. The count vector is {:?}."
                 ),
-                self.get_node_label(rows_label),
+                self.get_node_label_from_usize(rows_label),
                 number_of_triangles_with_row_label,
                 DebugTypedGraph::from(self).get_intersection_size_of_label(
                     src,
                     dst,
-                    self.get_node_label(rows_label)
+                    self.get_node_label_from_usize(rows_label)
                 ),
                 triangle_labels_counts
             );
@@ -872,7 +1303,7 @@ where
                     "We expected {:?} but found {:?}. The count vector is {:?}. ",
                     "The neighbours of source of the current label are {:?} and the neighbours of destination of the current label are {:?}."
                 ),
-                self.get_node_label(rows_label),
+                self.get_node_label_from_usize(rows_label),
                 self.get_node_label_from_usize(rows_label),
                 src, dst,
                 number_of_triangles_with_row_label + number_of_src_neighbours_with_row_label,
@@ -900,7 +1331,7 @@ where
                     "We expected {:?} but found {:?}. The count vector is {:?}. ",
                     "The neighbours of source of the current label are {:?} and the neighbours of destination of the current label are {:?}."
                 ),
-                self.get_node_label(rows_label),
+                self.get_node_label_from_usize(rows_label),
                 self.get_node_label_from_usize(rows_label),
                 src, dst,
                 number_of_triangles_with_row_label + number_of_dst_neighbours_with_row_label,
@@ -917,44 +1348,22 @@ where
             // We need to retrieve the number of graphlets for the combination of labels
             // (source node label, destination node label, rows label, columns label),
             // for the four cycles, tailed-tri-tail, chord-cycle-edge and four-clique orbits.
-            let number_of_homogenously_typed_four_cycles: Count = graphlet_counter
-                .get_number_of_graphlets(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(rows_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::FourCycle,
-                            self.get_number_of_node_labels(),
-                        ),
-                );
-            let number_of_homogenously_typed_tailed_tri_tails: Count = graphlet_counter
-                .get_number_of_graphlets(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(rows_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::TailedTriTail,
-                            self.get_number_of_node_labels(),
-                        ),
+            let number_of_homogenously_typed_four_cycles: Count = get_or_remove_number_of_graphlets(
+                graphlet_counter,
+                (rows_label, rows_label),
+                ExtendedGraphletType::FourCycle,
+            );
+            let number_of_homogenously_typed_tailed_tri_tails: Count =
+                get_or_remove_number_of_graphlets(
+                    graphlet_counter,
+                    (rows_label, rows_label),
+                    ExtendedGraphletType::TailedTriTail,
                 );
-            let number_of_homogenously_typed_chordal_cycle_edges: Count = graphlet_counter
-                .get_number_of_graphlets(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(rows_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::ChordalCycleEdge,
-                            self.get_number_of_node_labels(),
-                        ),
+            let number_of_homogenously_typed_chordal_cycle_edges: Count =
+                get_or_remove_number_of_graphlets(
+                    graphlet_counter,
+                    (rows_label, rows_label),
+                    ExtendedGraphletType::ChordalCycleEdge,
                 );
 
             // We can verify whether the value of chordal cycle edges is self-consistent
@@ -976,115 +1385,183 @@ where
                 dst
             );
 
-            let number_of_homogenously_typed_four_cliques = graphlet_counter
-                .get_number_of_graphlets(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(rows_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
-                            ExtendedGraphletType::FourClique,
-                            self.get_number_of_node_labels(),
-                        ),
+            let number_of_homogenously_typed_four_cliques: Count =
+                get_or_remove_number_of_graphlets(
+                    graphlet_counter,
+                    (rows_label, rows_label),
+                    ExtendedGraphletType::FourClique,
                 );
 
             // Now we have all ingredients to compute the number of graphlets for the
             // graphlets (4), (5), (9) and (11), which are four-path center orbits,
             // four-star orbits, tailed tri-edge orbits and chordal cycle center orbits.
+            // In debug builds, the orbit counts are computed with checked arithmetic, so that
+            // the counts of non-simple graphs, which would wrap around, panic with the orbit
+            // and the edge at fault.
 
             // We start with the four-path center orbits.
-            let number_homogeneously_of_four_path_center_orbits: Count =
-                get_homogeneously_typed_four_path_orbit_count(
-                    number_of_homogenously_typed_four_cycles,
-                    number_of_src_neighbours_with_row_label,
-                    number_of_dst_neighbours_with_row_label,
-                );
+            if mask.contains(ExtendedGraphletType::FourPathCenter) {
+                let number_homogeneously_of_four_path_center_orbits: Count =
+                    if cfg!(debug_assertions) {
+                        checked_homogeneously_typed_four_path_orbit_count(
+                            number_of_homogenously_typed_four_cycles,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                        .unwrap_or_else(|| {
+                            orbit_count_overflow(
+                                ExtendedGraphletType::FourPathCenter,
+                                src,
+                                dst,
+                                (rows_label, rows_label),
+                            )
+                        })
+                    } else {
+                        get_homogeneously_typed_four_path_orbit_count(
+                            number_of_homogenously_typed_four_cycles,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                    };
 
-            // We update the graphlet counter with the number of four-path center orbits.
-            graphlet_counter.insert_count(
-                (
-                    src_node_type,
-                    dst_node_type,
-                    self.get_node_label_from_usize(rows_label),
-                    self.get_node_label_from_usize(rows_label),
-                )
-                    .encode_with_graphlet::<ExtendedGraphletType>(
+                // We update the graphlet counter with the number of four-path center orbits.
+                graphlet_counter.insert_count(
+                    encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label_from_usize(rows_label),
+                            self.get_node_label_from_usize(rows_label),
+                        ),
                         ExtendedGraphletType::FourPathCenter,
                         self.get_number_of_node_labels(),
                     ),
-                number_homogeneously_of_four_path_center_orbits,
-            );
+                    number_homogeneously_of_four_path_center_orbits,
+                );
+            }
 
             // We continue with the four-star orbits.
-            let number_of_homogeneously_typed_four_star_orbits: Count =
-                get_homogeneously_typed_four_star_orbit_count(
-                    number_of_homogenously_typed_tailed_tri_tails,
-                    number_of_src_neighbours_with_row_label,
-                    number_of_dst_neighbours_with_row_label,
-                );
+            if mask.contains(ExtendedGraphletType::FourStar) {
+                let number_of_homogeneously_typed_four_star_orbits: Count =
+                    if cfg!(debug_assertions) {
+                        checked_homogeneously_typed_four_star_orbit_count(
+                            number_of_homogenously_typed_tailed_tri_tails,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                        .unwrap_or_else(|| {
+                            orbit_count_overflow(
+                                ExtendedGraphletType::FourStar,
+                                src,
+                                dst,
+                                (rows_label, rows_label),
+                            )
+                        })
+                    } else {
+                        get_homogeneously_typed_four_star_orbit_count(
+                            number_of_homogenously_typed_tailed_tri_tails,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                    };
 
-            // We update the graphlet counter with the number of four-star orbits.
-            graphlet_counter.insert_count(
-                (
-                    src_node_type,
-                    dst_node_type,
-                    self.get_node_label_from_usize(rows_label),
-                    self.get_node_label_from_usize(rows_label),
-                )
-                    .encode_with_graphlet::<ExtendedGraphletType>(
+                // We update the graphlet counter with the number of four-star orbits.
+                graphlet_counter.insert_count(
+                    encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label_from_usize(rows_label),
+                            self.get_node_label_from_usize(rows_label),
+                        ),
                         ExtendedGraphletType::FourStar,
                         self.get_number_of_node_labels(),
                     ),
-                number_of_homogeneously_typed_four_star_orbits,
-            );
+                    number_of_homogeneously_typed_four_star_orbits,
+                );
+            }
 
             // We continue with the tailed tri-edge orbits.
-            let number_of_homogeneously_tailed_tri_edge_orbits: Count =
-                get_homogeneously_typed_tailed_triangle_tri_edge_orbit_count(
-                    number_of_homogenously_typed_chordal_cycle_edges,
-                    number_of_triangles_with_row_label,
-                    number_of_src_neighbours_with_row_label,
-                    number_of_dst_neighbours_with_row_label,
-                );
+            if mask.contains(ExtendedGraphletType::TailedTriEdge) {
+                let number_of_homogeneously_tailed_tri_edge_orbits: Count =
+                    if cfg!(debug_assertions) {
+                        checked_homogeneously_typed_tailed_triangle_tri_edge_orbit_count(
+                            number_of_homogenously_typed_chordal_cycle_edges,
+                            number_of_triangles_with_row_label,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                        .unwrap_or_else(|| {
+                            orbit_count_overflow(
+                                ExtendedGraphletType::TailedTriEdge,
+                                src,
+                                dst,
+                                (rows_label, rows_label),
+                            )
+                        })
+                    } else {
+                        get_homogeneously_typed_tailed_triangle_tri_edge_orbit_count(
+                            number_of_homogenously_typed_chordal_cycle_edges,
+                            number_of_triangles_with_row_label,
+                            number_of_src_neighbours_with_row_label,
+                            number_of_dst_neighbours_with_row_label,
+                        )
+                    };
 
-            // We update the graphlet counter with the number of tailed tri-edge orbits.
-            graphlet_counter.insert_count(
-                (
-                    src_node_type,
-                    dst_node_type,
-                    self.get_node_label_from_usize(rows_label),
-                    self.get_node_label_from_usize(rows_label),
-                )
-                    .encode_with_graphlet::<ExtendedGraphletType>(
+                // We update the graphlet counter with the number of tailed tri-edge orbits.
+                graphlet_counter.insert_count(
+                    encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label_from_usize(rows_label),
+                            self.get_node_label_from_usize(rows_label),
+                        ),
                         ExtendedGraphletType::TailedTriEdge,
                         self.get_number_of_node_labels(),
                     ),
-                number_of_homogeneously_tailed_tri_edge_orbits,
-            );
+                    number_of_homogeneously_tailed_tri_edge_orbits,
+                );
+            }
 
             // We continue with the chordal cycle center orbits.
-            let number_of_homogeneously_chordal_cycle_center_orbits =
-                get_homogeneously_typed_chordal_cycle_center_orbit_count(
-                    number_of_homogenously_typed_four_cliques,
-                    number_of_triangles_with_row_label,
-                );
+            if mask.contains(ExtendedGraphletType::ChordalCycleCenter) {
+                let number_of_homogeneously_chordal_cycle_center_orbits: Count =
+                    if cfg!(debug_assertions) {
+                        checked_homogeneously_typed_chordal_cycle_center_orbit_count(
+                            number_of_homogenously_typed_four_cliques,
+                            number_of_triangles_with_row_label,
+                        )
+                        .unwrap_or_else(|| {
+                            orbit_count_overflow(
+                                ExtendedGraphletType::ChordalCycleCenter,
+                                src,
+                                dst,
+                                (rows_label, rows_label),
+                            )
+                        })
+                    } else {
+                        get_homogeneously_typed_chordal_cycle_center_orbit_count(
+                            number_of_homogenously_typed_four_cliques,
+                            number_of_triangles_with_row_label,
+                        )
+                    };
 
-            // We update the graphlet counter with the number of chordal cycle center orbits.
-            graphlet_counter.insert_count(
-                (
-                    src_node_type,
-                    dst_node_type,
-                    self.get_node_label_from_usize(rows_label),
-                    self.get_node_label_from_usize(rows_label),
-                )
-                    .encode_with_graphlet::<ExtendedGraphletType>(
+                // We update the graphlet counter with the number of chordal cycle center orbits.
+                graphlet_counter.insert_count(
+                    encode(
+                        (
+                            src_node_type,
+                            dst_node_type,
+                            self.get_node_label_from_usize(rows_label),
+                            self.get_node_label_from_usize(rows_label),
+                        ),
                         ExtendedGraphletType::ChordalCycleCenter,
                         self.get_number_of_node_labels(),
                     ),
-                number_of_homogeneously_chordal_cycle_center_orbits,
-            );
+                    number_of_homogeneously_chordal_cycle_center_orbits,
+                );
+            }
 
             // We iterate on the upper triangular matrix of the triangle labels counts.
             for columns_label in (rows_label + 1)..self.get_number_of_node_labels_usize() {
@@ -1247,44 +1724,35 @@ where
                 // We need to retrieve the number of graphlets for the combination of labels
                 // (source node label, destination node label, rows label, columns label),
                 // for the four cycles, tailed-tri-tail, chord-cycle-edge and four-clique orbits.
-                let number_of_heterogenously_typed_four_cycles: Count = graphlet_counter
-                    .get_number_of_graphlets(
-                        (
-                            src_node_type,
-                            dst_node_type,
-                            self.get_node_label_from_usize(rows_label),
-                            self.get_node_label_from_usize(columns_label),
+                // The order of the last two labels in these keys depends on the node IDs
+                // found by the traversal, so we sum the counts of both orders.
+                let get_unordered_number_of_graphlets =
+                    |graphlet_counter: &mut Self::GraphLetCounter,
+                     graphlet_kind: ExtendedGraphletType| {
+                        get_or_remove_number_of_graphlets(
+                            graphlet_counter,
+                            (rows_label, columns_label),
+                            graphlet_kind,
+                        ) + get_or_remove_number_of_graphlets(
+                            graphlet_counter,
+                            (columns_label, rows_label),
+                            graphlet_kind,
                         )
-                            .encode_with_graphlet::<ExtendedGraphletType>(
-                                ExtendedGraphletType::FourCycle,
-                                self.get_number_of_node_labels(),
-                            ),
+                    };
+                let number_of_heterogenously_typed_four_cycles: Count =
+                    get_unordered_number_of_graphlets(
+                        graphlet_counter,
+                        ExtendedGraphletType::FourCycle,
                     );
-                let number_of_heterogenously_typed_tailed_tri_tails: Count = graphlet_counter
-                    .get_number_of_graphlets(
-                        (
-                            src_node_type,
-                            dst_node_type,
-                            self.get_node_label_from_usize(rows_label),
-                            self.get_node_label_from_usize(columns_label),
-                        )
-                            .encode_with_graphlet::<ExtendedGraphletType>(
-                                ExtendedGraphletType::TailedTriTail,
-                                self.get_number_of_node_labels(),
-                            ),
+                let number_of_heterogenously_typed_tailed_tri_tails: Count =
+                    get_unordered_number_of_graphlets(
+                        graphlet_counter,
+                        ExtendedGraphletType::TailedTriTail,
                     );
-                let number_of_heterogenously_typed_chordal_cycle_edges: Count = graphlet_counter
-                    .get_number_of_graphlets(
-                        (
-                            src_node_type,
-                            dst_node_type,
-                            self.get_node_label_from_usize(rows_label),
-                            self.get_node_label_from_usize(columns_label),
-                        )
-                            .encode_with_graphlet::<ExtendedGraphletType>(
-                                ExtendedGraphletType::ChordalCycleEdge,
-                                self.get_number_of_node_labels(),
-                            ),
+                let number_of_heterogenously_typed_chordal_cycle_edges: Count =
+                    get_unordered_number_of_graphlets(
+                        graphlet_counter,
+                        ExtendedGraphletType::ChordalCycleEdge,
                     );
 
                 // We can verify whether the value of chordal cycle edges is self-consistent
@@ -1311,28 +1779,20 @@ where
                     ),
                     src, dst,
                     number_of_heterogenously_typed_chordal_cycle_edges,
-                    self.get_node_label(rows_label),
+                    self.get_node_label_from_usize(rows_label),
                     number_of_triangles_with_row_label,
-                    self.get_node_label(rows_label),
+                    self.get_node_label_from_usize(rows_label),
                     number_of_src_neighbours_with_row_label,
                     self.get_node_label(columns_label),
                     number_of_src_neighbours_with_column_label,
-                    self.get_node_label(rows_label),
+                    self.get_node_label_from_usize(rows_label),
                     number_of_dst_neighbours_with_column_label
                 );
 
-                let number_of_heterogenously_typed_four_cliques = graphlet_counter
-                    .get_number_of_graphlets(
-                        (
-                            src_node_type,
-                            dst_node_type,
-                            self.get_node_label_from_usize(rows_label),
-                            self.get_node_label_from_usize(columns_label),
-                        )
-                            .encode_with_graphlet::<ExtendedGraphletType>(
-                                ExtendedGraphletType::FourClique,
-                                self.get_number_of_node_labels(),
-                            ),
+                let number_of_heterogenously_typed_four_cliques: Count =
+                    get_unordered_number_of_graphlets(
+                        graphlet_counter,
+                        ExtendedGraphletType::FourClique,
                     );
 
                 // Now we have all ingredients to compute the number of graphlets for the
@@ -1340,107 +1800,1823 @@ where
                 // four-star orbits, tailed tri-edge orbits and chordal cycle center orbits.
 
                 // We start with the four-path center orbits.
-                let number_of_heterogenously_of_four_path_center_orbits: Count =
-                    get_heterogeneously_typed_four_path_orbit_count(
-                        number_of_heterogenously_typed_four_cycles,
-                        number_of_src_neighbours_with_row_label,
-                        number_of_dst_neighbours_with_row_label,
-                        number_of_src_neighbours_with_column_label,
-                        number_of_dst_neighbours_with_column_label,
-                    );
+                if mask.contains(ExtendedGraphletType::FourPathCenter) {
+                    let number_of_heterogenously_of_four_path_center_orbits: Count =
+                        if cfg!(debug_assertions) {
+                            checked_heterogeneously_typed_four_path_orbit_count(
+                                number_of_heterogenously_typed_four_cycles,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                            .unwrap_or_else(|| {
+                                orbit_count_overflow(
+                                    ExtendedGraphletType::FourPathCenter,
+                                    src,
+                                    dst,
+                                    (rows_label, columns_label),
+                                )
+                            })
+                        } else {
+                            get_heterogeneously_typed_four_path_orbit_count(
+                                number_of_heterogenously_typed_four_cycles,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                        };
 
-                // We update the graphlet counter with the number of four-path center orbits.
-                graphlet_counter.insert_count(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(columns_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
+                    // We update the graphlet counter with the number of four-path center orbits.
+                    graphlet_counter.insert_count(
+                        encode(
+                            (
+                                src_node_type,
+                                dst_node_type,
+                                self.get_node_label_from_usize(rows_label),
+                                self.get_node_label_from_usize(columns_label),
+                            ),
                             ExtendedGraphletType::FourPathCenter,
                             self.get_number_of_node_labels(),
                         ),
-                    number_of_heterogenously_of_four_path_center_orbits,
-                );
+                        number_of_heterogenously_of_four_path_center_orbits,
+                    );
+                }
 
                 // We continue with the four-star orbits.
-                let number_of_heterogeneously_four_star_orbits: Count =
-                    get_heterogeneously_typed_four_star_orbit_count(
-                        number_of_heterogenously_typed_tailed_tri_tails,
-                        number_of_src_neighbours_with_row_label,
-                        number_of_dst_neighbours_with_row_label,
-                        number_of_src_neighbours_with_column_label,
-                        number_of_dst_neighbours_with_column_label,
-                    );
+                if mask.contains(ExtendedGraphletType::FourStar) {
+                    let number_of_heterogeneously_four_star_orbits: Count =
+                        if cfg!(debug_assertions) {
+                            checked_heterogeneously_typed_four_star_orbit_count(
+                                number_of_heterogenously_typed_tailed_tri_tails,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                            .unwrap_or_else(|| {
+                                orbit_count_overflow(
+                                    ExtendedGraphletType::FourStar,
+                                    src,
+                                    dst,
+                                    (rows_label, columns_label),
+                                )
+                            })
+                        } else {
+                            get_heterogeneously_typed_four_star_orbit_count(
+                                number_of_heterogenously_typed_tailed_tri_tails,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                        };
 
-                // We update the graphlet counter with the number of four-star orbits.
-                graphlet_counter.insert_count(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(columns_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
+                    // We update the graphlet counter with the number of four-star orbits.
+                    graphlet_counter.insert_count(
+                        encode(
+                            (
+                                src_node_type,
+                                dst_node_type,
+                                self.get_node_label_from_usize(rows_label),
+                                self.get_node_label_from_usize(columns_label),
+                            ),
                             ExtendedGraphletType::FourStar,
                             self.get_number_of_node_labels(),
                         ),
-                    number_of_heterogeneously_four_star_orbits,
-                );
+                        number_of_heterogeneously_four_star_orbits,
+                    );
+                }
 
                 // We continue with the tailed tri-edge orbits.
-                let number_of_heterogeneously_tailed_tri_edge_orbits: Count =
-                    get_heterogeneously_typed_tailed_triangle_tri_edge_orbit_count(
-                        number_of_heterogenously_typed_chordal_cycle_edges,
-                        number_of_triangles_with_row_label,
-                        number_of_triangles_with_column_label,
-                        number_of_src_neighbours_with_row_label,
-                        number_of_dst_neighbours_with_row_label,
-                        number_of_src_neighbours_with_column_label,
-                        number_of_dst_neighbours_with_column_label,
-                    );
+                if mask.contains(ExtendedGraphletType::TailedTriEdge) {
+                    let number_of_heterogeneously_tailed_tri_edge_orbits: Count =
+                        if cfg!(debug_assertions) {
+                            checked_heterogeneously_typed_tailed_triangle_tri_edge_orbit_count(
+                                number_of_heterogenously_typed_chordal_cycle_edges,
+                                number_of_triangles_with_row_label,
+                                number_of_triangles_with_column_label,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                            .unwrap_or_else(|| {
+                                orbit_count_overflow(
+                                    ExtendedGraphletType::TailedTriEdge,
+                                    src,
+                                    dst,
+                                    (rows_label, columns_label),
+                                )
+                            })
+                        } else {
+                            get_heterogeneously_typed_tailed_triangle_tri_edge_orbit_count(
+                                number_of_heterogenously_typed_chordal_cycle_edges,
+                                number_of_triangles_with_row_label,
+                                number_of_triangles_with_column_label,
+                                number_of_src_neighbours_with_row_label,
+                                number_of_dst_neighbours_with_row_label,
+                                number_of_src_neighbours_with_column_label,
+                                number_of_dst_neighbours_with_column_label,
+                            )
+                        };
 
-                // We update the graphlet counter with the number of tailed tri-edge orbits.
-                graphlet_counter.insert_count(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(columns_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
+                    // We update the graphlet counter with the number of tailed tri-edge orbits.
+                    graphlet_counter.insert_count(
+                        encode(
+                            (
+                                src_node_type,
+                                dst_node_type,
+                                self.get_node_label_from_usize(rows_label),
+                                self.get_node_label_from_usize(columns_label),
+                            ),
                             ExtendedGraphletType::TailedTriEdge,
                             self.get_number_of_node_labels(),
                         ),
-                    number_of_heterogeneously_tailed_tri_edge_orbits,
-                );
+                        number_of_heterogeneously_tailed_tri_edge_orbits,
+                    );
+                }
 
                 // We continue with the chordal cycle center orbits.
-                let number_of_heterogeneously_typed_chordal_cycle_center_orbits =
-                    get_heterogeneously_typed_chordal_cycle_center_orbit_count(
-                        number_of_heterogenously_typed_four_cliques,
-                        number_of_triangles_with_row_label,
-                        number_of_triangles_with_column_label,
-                    );
+                if mask.contains(ExtendedGraphletType::ChordalCycleCenter) {
+                    let number_of_heterogeneously_typed_chordal_cycle_center_orbits: Count =
+                        if cfg!(debug_assertions) {
+                            checked_heterogeneously_typed_chordal_cycle_center_orbit_count(
+                                number_of_heterogenously_typed_four_cliques,
+                                number_of_triangles_with_row_label,
+                                number_of_triangles_with_column_label,
+                            )
+                            .unwrap_or_else(|| {
+                                orbit_count_overflow(
+                                    ExtendedGraphletType::ChordalCycleCenter,
+                                    src,
+                                    dst,
+                                    (rows_label, columns_label),
+                                )
+                            })
+                        } else {
+                            get_heterogeneously_typed_chordal_cycle_center_orbit_count(
+                                number_of_heterogenously_typed_four_cliques,
+                                number_of_triangles_with_row_label,
+                                number_of_triangles_with_column_label,
+                            )
+                        };
 
-                // We update the graphlet counter with the number of chordal cycle center orbits.
-                graphlet_counter.insert_count(
-                    (
-                        src_node_type,
-                        dst_node_type,
-                        self.get_node_label_from_usize(rows_label),
-                        self.get_node_label_from_usize(columns_label),
-                    )
-                        .encode_with_graphlet::<ExtendedGraphletType>(
+                    // We update the graphlet counter with the number of chordal cycle center orbits.
+                    graphlet_counter.insert_count(
+                        encode(
+                            (
+                                src_node_type,
+                                dst_node_type,
+                                self.get_node_label_from_usize(rows_label),
+                                self.get_node_label_from_usize(columns_label),
+                            ),
                             ExtendedGraphletType::ChordalCycleCenter,
                             self.get_number_of_node_labels(),
                         ),
-                    number_of_heterogeneously_typed_chordal_cycle_center_orbits,
-                );
+                        number_of_heterogeneously_typed_chordal_cycle_center_orbits,
+                    );
+                }
             }
         }
-        // We return the graphlet counter.
+    }
+
+    /// Returns the graphlets of the provided edge appearing at least the provided number of times.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `min_count` - The minimum number of occurrences of the graphlets to keep.
+    ///
+    /// # Implementation details
+    /// All the graphlets of the edge are counted first, as the orbit formulas deriving
+    /// four-path centers, four-stars, tailed-triangle edges and chordal-cycle centers
+    /// require the complete counts of the other kinds. The graphlets below the
+    /// threshold are then dropped from the returned counter.
+    ///
+    fn get_heterogeneous_graphlet_min_count(
+        &self,
+        src: usize,
+        dst: usize,
+        min_count: Count,
+    ) -> Self::GraphLetCounter {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        for (graphlet, count) in self
+            .get_heterogeneous_graphlet(src, dst)
+            .iter_graphlets_and_counts()
+            .filter(|&(_, count)| count >= min_count)
+        {
+            graphlet_counter.insert_count(graphlet, count);
+        }
         graphlet_counter
     }
+
+    /// Returns the number of graphlets of the provided edge, merging the orbits of each graphlet.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The graphlets are encoded with the [`ReducedGraphletType`] associated to
+    /// their orbit by [`ExtendedGraphletType::to_reduced`], so that
+    /// the count of each reduced graphlet is the sum of the counts of its orbits
+    /// with the same labels. The labels keep the order of the orbit they were
+    /// counted in: for instance, the tail of a tailed triangle is the third node
+    /// of a tailed-triangle edge and the fourth node of a tailed-triangle center.
+    ///
+    /// Since the orbits are decoded from their hashes, the layout must be able to
+    /// tell apart the graphlet kinds for the number of node labels of the graph.
+    ///
+    fn get_heterogeneous_graphlet_reduced(&self, src: usize, dst: usize) -> Self::GraphLetCounter {
+        let number_of_elements = self.get_number_of_node_labels();
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(number_of_elements);
+        for (graphlet, count) in self
+            .get_heterogeneous_graphlet(src, dst)
+            .iter_graphlets_and_counts()
+        {
+            let (graphlet_kind, labels) =
+                Layout::decode::<ExtendedGraphletType>(graphlet, number_of_elements);
+            graphlet_counter.insert_count(
+                Layout::encode(labels, graphlet_kind.to_reduced(), number_of_elements),
+                count,
+            );
+        }
+        graphlet_counter
+    }
+
+    /// Returns the number of five-node graphlets of the provided edge.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// Only the orbits of [`FiveNodeGraphletType`] are counted, enumerating for each of
+    /// them the induced subgraphs including the edge with the shape of the graphlet.
+    /// The enumeration of the paths reaches the third-order neighbours of the edge,
+    /// and is therefore much more expensive than the four-node graphlet counting.
+    ///
+    /// The graphlets are encoded with the quintuple hash, independently of the layout,
+    /// with the labels of the source and destination nodes followed by those of the
+    /// three further nodes. The further labels are sorted for the four-stars and the
+    /// five-cliques, whose further nodes are interchangeable, while for the paths and
+    /// the cycles they follow the graphlet away from the edge:
+    /// * For the five-path edges, from the neighbour of the edge to the far end.
+    /// * For the five-path inner edges, the node beyond one endpoint of the edge,
+    ///   followed by the two nodes beyond the other endpoint.
+    /// * For the five-cycles, from the neighbour of the destination node to the
+    ///   neighbour of the source node.
+    ///
+    fn get_heterogeneous_5_graphlet(&self, src: usize, dst: usize) -> Self::GraphLetCounter
+    where
+        Graphlet: From<FiveNodeGraphletType>,
+        FiveNodeGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Self::NodeLabel: Primitive<Graphlet>,
+    {
+        let number_of_elements = self.get_number_of_node_labels();
+        // We check that the quintuple hashes fit in the provided graphlet type.
+        debug_assert!(
+            u128::convert(
+                <[Self::NodeLabel; 5]>::maximal_hash::<FiveNodeGraphletType>(number_of_elements)
+            ) <= u128::convert(Graphlet::MAXIMAL),
+            "The five-node graphlets of {:?} node labels cannot be encoded in the graphlet type.",
+            number_of_elements
+        );
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(number_of_elements);
+        let mut insert = |graphlet_kind: FiveNodeGraphletType, further: [usize; 3]| {
+            let mut labels = further.map(|node| self.get_node_label(node));
+            if matches!(
+                graphlet_kind,
+                FiveNodeGraphletType::FiveStar | FiveNodeGraphletType::FiveClique
+            ) {
+                labels.sort_unstable();
+            }
+            graphlet_counter.insert(
+                [
+                    self.get_node_label(src),
+                    self.get_node_label(dst),
+                    labels[0],
+                    labels[1],
+                    labels[2],
+                ]
+                .encode_with_graphlet(graphlet_kind, number_of_elements),
+            );
+        };
+        // Returns whether the node is adjacent to none of the provided nodes.
+        let is_independent_from = |node: usize, others: &[usize]| {
+            others
+                .iter()
+                .all(|&other| node != other && !self.is_neighbour(node, other))
+        };
+        // Returns the neighbours of the first node not adjacent to the second one.
+        let get_exclusive_neighbours = |node: usize, other: usize| {
+            self.iter_neighbours(node)
+                .filter(|&neighbour| is_independent_from(neighbour, &[other]))
+                .collect::<Vec<usize>>()
+        };
+        let common_neighbours = self
+            .iter_neighbours(src)
+            .filter(|&neighbour| neighbour != dst && self.is_neighbour(dst, neighbour))
+            .collect::<Vec<usize>>();
+
+        // The five-cliques are the triangles among the common neighbours.
+        for (i, &first) in common_neighbours.iter().enumerate() {
+            for (j, &second) in common_neighbours.iter().enumerate().skip(i + 1) {
+                if !self.is_neighbour(first, second) {
+                    continue;
+                }
+                for &third in common_neighbours.iter().skip(j + 1) {
+                    if self.is_neighbour(first, third) && self.is_neighbour(second, third) {
+                        insert(FiveNodeGraphletType::FiveClique, [first, second, third]);
+                    }
+                }
+            }
+        }
+
+        for (node, other) in [(src, dst), (dst, src)] {
+            let exclusive_neighbours = get_exclusive_neighbours(node, other);
+
+            // The four-stars centered in the node are the triples of independent
+            // neighbours adjacent solely to the node.
+            for (i, &first) in exclusive_neighbours.iter().enumerate() {
+                for (j, &second) in exclusive_neighbours.iter().enumerate().skip(i + 1) {
+                    if self.is_neighbour(first, second) {
+                        continue;
+                    }
+                    for &third in exclusive_neighbours.iter().skip(j + 1) {
+                        if is_independent_from(third, &[first, second]) {
+                            insert(FiveNodeGraphletType::FiveStar, [first, second, third]);
+                        }
+                    }
+                }
+            }
+
+            // The five-paths where the other node is an end and the path continues
+            // from the node.
+            for &first in exclusive_neighbours.iter() {
+                for second in self.iter_neighbours(first) {
+                    if !is_independent_from(second, &[node, other]) {
+                        continue;
+                    }
+                    for third in self.iter_neighbours(second) {
+                        if is_independent_from(third, &[node, other, first]) {
+                            insert(FiveNodeGraphletType::FivePathEdge, [first, second, third]);
+                        }
+                    }
+                }
+            }
+
+            // The five-paths with a single node beyond the node and two nodes beyond the other node.
+            let other_exclusive_neighbours = get_exclusive_neighbours(other, node);
+            for &first in exclusive_neighbours.iter() {
+                for &second in other_exclusive_neighbours.iter() {
+                    if self.is_neighbour(first, second) {
+                        continue;
+                    }
+                    for third in self.iter_neighbours(second) {
+                        if is_independent_from(third, &[node, other, first]) {
+                            insert(FiveNodeGraphletType::FivePathInner, [first, second, third]);
+                        }
+                    }
+                }
+            }
+        }
+
+        // The five-cycles close a path from the destination node to the source node
+        // through three further nodes.
+        let src_exclusive_neighbours = get_exclusive_neighbours(src, dst);
+        for first in get_exclusive_neighbours(dst, src) {
+            for second in self.iter_neighbours(first) {
+                if !is_independent_from(second, &[src, dst]) {
+                    continue;
+                }
+                for &third in src_exclusive_neighbours.iter() {
+                    if self.is_neighbour(second, third) && !self.is_neighbour(first, third) {
+                        insert(FiveNodeGraphletType::FiveCycle, [first, second, third]);
+                    }
+                }
+            }
+        }
+
+        graphlet_counter
+    }
+
+    /// Returns the number of edge-typed graphlets of the provided edge.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The graphlets are distinguished by the labels of their edges besides those of
+    /// their nodes, and are encoded with [`encode_edge_typed`] independently of the layout.
+    /// As the per-label counts used by the orbit formulas cannot account for the edge
+    /// labels, the three- and four-node graphlets including the edge are enumerated,
+    /// reaching the second-order neighbours of the edge. The enumeration is therefore
+    /// more expensive than the node-labeled graphlet counting.
+    ///
+    /// The nodes of each graphlet are encoded starting from the source and destination
+    /// nodes, followed by the further nodes. Of the orderings obtained by swapping the
+    /// source and destination nodes, and the further nodes of the four-node graphlets,
+    /// the one with the smallest node and edge labels is encoded, so that each labeled
+    /// graphlet has a single hash.
+    ///
+    /// The edge labels multiply the hashes by `(m + 1)^6`, where `m` is the number of
+    /// edge labels, so that the graphlet type must be wider than the one needed by the
+    /// node-labeled graphlets: see [`maximal_edge_typed_hash`].
+    ///
+    fn get_edge_typed_graphlet(&self, src: usize, dst: usize) -> Self::GraphLetCounter
+    where
+        Self: EdgeTypedGraph,
+        Graphlet: Primitive<<Self as EdgeTypedGraph>::EdgeLabel>,
+    {
+        let number_of_node_labels = Graphlet::convert(self.get_number_of_node_labels());
+        let number_of_edge_labels = Graphlet::convert(self.get_number_of_edge_labels());
+        // We check that the edge-typed hashes fit in the provided graphlet type.
+        debug_assert!(
+            maximal_edge_typed_hash(
+                u128::convert(number_of_node_labels) as usize,
+                u128::convert(number_of_edge_labels) as usize
+            )
+            .is_some_and(|maximal_hash| maximal_hash - 1 <= u128::convert(Graphlet::MAXIMAL)),
+            "The edge-typed graphlets of {:?} node labels and {:?} edge labels cannot be encoded in the graphlet type.",
+            self.get_number_of_node_labels(),
+            self.get_number_of_edge_labels()
+        );
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        for_each_edge_graphlet(self, src, dst, |graphlet_kind, nodes| {
+            let is_adjacent = |i: usize, j: usize| {
+                i < nodes.len() && j < nodes.len() && self.is_neighbour(nodes[i], nodes[j])
+            };
+            let node_labels: [Graphlet; 4] = core::array::from_fn(|i| {
+                nodes.get(i).map_or(number_of_node_labels, |&node| {
+                    Graphlet::convert(self.get_node_label(node))
+                })
+            });
+            let edge_labels: [[Graphlet; 4]; 4] = core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    if is_adjacent(i, j) {
+                        Graphlet::convert(self.get_edge_label(nodes[i], nodes[j]))
+                    } else {
+                        number_of_edge_labels
+                    }
+                })
+            });
+
+            let orderings: &[[usize; 4]] = if nodes.len() == 3 {
+                &[[0, 1, 2, 3], [1, 0, 2, 3]]
+            } else {
+                &[[0, 1, 2, 3], [1, 0, 2, 3], [0, 1, 3, 2], [1, 0, 3, 2]]
+            };
+            let (node_labels, edge_labels) = orderings
+                .iter()
+                .map(|ordering| {
+                    (
+                        ordering.map(|i| node_labels[i]),
+                        [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]
+                            .map(|(i, j)| edge_labels[ordering[i]][ordering[j]]),
+                    )
+                })
+                .min()
+                .unwrap();
+            graphlet_counter.insert(encode_edge_typed(
+                graphlet_kind,
+                node_labels,
+                edge_labels,
+                number_of_node_labels,
+                number_of_edge_labels,
+            ));
+        });
+
+        graphlet_counter
+    }
+
+    /// Returns the edge-typed graphlet counts of the whole graph.
+    ///
+    /// # Implementation details
+    /// Each undirected edge is visited once, from its node with the smaller ID, and
+    /// the counts of [`get_edge_typed_graphlet`](HeterogeneousGraphlets::get_edge_typed_graphlet)
+    /// are summed.
+    ///
+    fn count_all_edge_typed_graphlets(&self) -> Self::GraphLetCounter
+    where
+        Self: EdgeTypedGraph,
+        Graphlet: Primitive<<Self as EdgeTypedGraph>::EdgeLabel>,
+    {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                for (graphlet, count) in self
+                    .get_edge_typed_graphlet(src, dst)
+                    .iter_graphlets_and_counts()
+                {
+                    graphlet_counter.insert_count(graphlet, count);
+                }
+            }
+        }
+        graphlet_counter
+    }
+
+    /// Returns the weighted graphlet counts of the provided edge.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `aggregation` - How the edge weights of each graphlet are combined.
+    ///
+    /// # Implementation details
+    /// Each graphlet contributes the aggregation of the weights of its edges, instead
+    /// of one, to the count of its hash, so that with unit weights and the product
+    /// aggregation the counts are those of
+    /// [`get_heterogeneous_graphlet`](HeterogeneousGraphlets::get_heterogeneous_graphlet).
+    /// As the orbit formulas cannot account for the weights, the graphlets including
+    /// the edge are enumerated as done by
+    /// [`get_edge_typed_graphlet`](HeterogeneousGraphlets::get_edge_typed_graphlet),
+    /// and the hash of each of them is the one found by counting the graphlets of its
+    /// kind on the [`InducedSubgraph`] of its nodes. The counting is therefore much
+    /// more expensive than the unweighted one.
+    ///
+    fn get_weighted_graphlet(
+        &self,
+        src: usize,
+        dst: usize,
+        aggregation: WeightAggregation,
+    ) -> Self::GraphLetCounter
+    where
+        Self: WeightedGraph<Weight = Count>,
+    {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut induced_graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+        for_each_edge_graphlet(self, src, dst, |graphlet_kind, nodes| {
+            InducedSubgraph::new(self, nodes.to_vec()).get_heterogeneous_graphlet_masked_into(
+                src,
+                dst,
+                graphlet_kind.into(),
+                &mut scratch,
+                &mut induced_graphlet_counter,
+            );
+            let weight = aggregation.aggregate(nodes.iter().enumerate().flat_map(|(i, &node)| {
+                nodes[i + 1..]
+                    .iter()
+                    .filter(move |&&other| self.is_neighbour(node, other))
+                    .map(move |&other| self.get_edge_weight(node, other))
+            }));
+            // The induced subgraph has a single graphlet of the kind including the edge.
+            for (graphlet, _) in induced_graphlet_counter.iter_graphlets_and_counts() {
+                graphlet_counter.insert_count(graphlet, weight);
+            }
+        });
+        graphlet_counter
+    }
+
+    /// Returns the weighted graphlet counts of the whole graph.
+    ///
+    /// # Arguments
+    /// * `aggregation` - How the edge weights of each graphlet are combined.
+    ///
+    /// # Implementation details
+    /// Each undirected edge is visited once, from its node with the smaller ID, and
+    /// the counts of [`get_weighted_graphlet`](HeterogeneousGraphlets::get_weighted_graphlet)
+    /// are summed.
+    ///
+    fn count_all_weighted_graphlets(&self, aggregation: WeightAggregation) -> Self::GraphLetCounter
+    where
+        Self: WeightedGraph<Weight = Count>,
+    {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                for (graphlet, count) in self
+                    .get_weighted_graphlet(src, dst, aggregation)
+                    .iter_graphlets_and_counts()
+                {
+                    graphlet_counter.insert_count(graphlet, count);
+                }
+            }
+        }
+        graphlet_counter
+    }
+
+    /// Returns the graphlets of the provided edge within the subgraph induced by a node subset.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `subset` - Whether each node is in the subset, where the i-th entry refers to node i.
+    ///
+    /// # Raises
+    /// * If the subset does not have an entry for each node of the graph.
+    /// * If the source or the destination node is not in the subset.
+    ///
+    /// # Implementation details
+    /// The nodes outside of the subset are treated as absent, so that only the graphlets
+    /// whose nodes are all in the subset are counted, as done by counting the graphlets
+    /// of the edge on the [`InducedSubgraph`] of the subset.
+    ///
+    fn get_heterogeneous_graphlet_in_subset(
+        &self,
+        src: usize,
+        dst: usize,
+        subset: &[bool],
+    ) -> Self::GraphLetCounter {
+        assert_eq!(
+            subset.len(),
+            self.get_number_of_nodes(),
+            "The subset must have an entry for each node of the graph."
+        );
+        assert!(
+            subset[src] && subset[dst],
+            "The source node {} and the destination node {} must be in the subset.",
+            src,
+            dst
+        );
+        let nodes = subset
+            .iter()
+            .enumerate()
+            .filter(|&(_, &is_in_subset)| is_in_subset)
+            .map(|(node, _)| node)
+            .collect();
+        HeterogeneousGraphlets::<Graphlet, Count, Layout>::get_heterogeneous_graphlet(
+            &InducedSubgraph::new(self, nodes),
+            src,
+            dst,
+        )
+    }
+
+    /// Returns the graphlet counts of the whole graph.
+    ///
+    /// # Implementation details
+    /// Each undirected edge is visited once, from its node with the smaller ID,
+    /// and the graphlet counts of the edges are summed, as are their examined node
+    /// quadruples. The scratch buffers and the counter of the single edges are reused
+    /// across the edges.
+    ///
+    fn count_all_graphlets(&self) -> Self::GraphLetCounter {
+        self.count_all_graphlets_with_progress(|_, _| {})
+    }
+
+    /// Returns the graphlet counts of the whole graph, reporting each processed edge.
+    ///
+    /// # Arguments
+    /// * `on_edge` - The callback invoked with the source and destination nodes of each
+    ///   edge, once its graphlets have been counted.
+    ///
+    /// # Implementation details
+    /// The edges are visited as in [`count_all_graphlets`](HeterogeneousGraphlets::count_all_graphlets),
+    /// i.e. once from their node with the smaller ID, so that the callback is invoked
+    /// once per undirected edge, and can drive a progress bar over half the number of edges.
+    ///
+    fn count_all_graphlets_with_progress(
+        &self,
+        mut on_edge: impl FnMut(usize, usize),
+    ) -> Self::GraphLetCounter {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut edge_graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                self.get_heterogeneous_graphlet_into(
+                    src,
+                    dst,
+                    &mut scratch,
+                    &mut edge_graphlet_counter,
+                );
+                for (graphlet, count) in edge_graphlet_counter.iter_graphlets_and_counts() {
+                    graphlet_counter.insert_count(graphlet, count);
+                }
+                graphlet_counter.record_examined(edge_graphlet_counter.examined());
+                on_edge(src, dst);
+            }
+        }
+        graphlet_counter
+    }
+
+    /// Returns the graphlet counts of the whole graph, with the labels of each graphlet in their canonical order.
+    ///
+    /// # Implementation details
+    /// The graphlets are counted as in [`count_all_graphlets`](HeterogeneousGraphlets::count_all_graphlets),
+    /// and the labels of each of them are then reordered with [`canonical_labels`], as done by
+    /// [`GraphLetCounter::canonicalize`] with the layout of the graph, summing the counts of
+    /// the label quadruples describing the same motif. The labels cannot be reordered while
+    /// counting, as the orbit formulas look the graphlets up by their labels in the order of
+    /// the traversal. As done by
+    /// [`get_heterogeneous_graphlet_reduced`](HeterogeneousGraphlets::get_heterogeneous_graphlet_reduced),
+    /// the graphlets are decoded from their hashes, and the layout must be able to tell
+    /// apart the graphlet kinds for the number of node labels of the graph.
+    ///
+    fn count_all_canonical_graphlets(&self) -> Self::GraphLetCounter {
+        self.count_all_graphlets()
+            .canonicalize::<_, Layout>(self.get_number_of_node_labels())
+    }
+
+    /// Returns the graphlet counts of the whole graph, processing the edges in parallel.
+    ///
+    /// # Implementation details
+    /// Each rayon task sums the graphlet counts of the edges it visits into its own
+    /// counter, reusing its scratch buffers across the edges as done by
+    /// [`HeterogeneousGraphlets::count_all_graphlets`], and the counters of the tasks
+    /// are then merged, so that the counts and the examined node quadruples match
+    /// the sequential ones.
+    ///
+    #[cfg(feature = "rayon")]
+    fn par_count_all_graphlets(&self) -> Self::GraphLetCounter
+    where
+        Self: ParallelGraph + Sync + Sized,
+        Self::GraphLetCounter: Send,
+        Count: Send,
+    {
+        let new_counter =
+            || <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        self.par_iter_edges()
+            .filter(|&(src, dst)| src < dst)
+            .fold(
+                || {
+                    (
+                        new_counter(),
+                        new_counter(),
+                        GraphletScratch::new(self.get_number_of_node_labels_usize()),
+                    )
+                },
+                |(mut graphlet_counter, mut edge_graphlet_counter, mut scratch), (src, dst)| {
+                    self.get_heterogeneous_graphlet_into(
+                        src,
+                        dst,
+                        &mut scratch,
+                        &mut edge_graphlet_counter,
+                    );
+                    graphlet_counter.merge(&edge_graphlet_counter);
+                    (graphlet_counter, edge_graphlet_counter, scratch)
+                },
+            )
+            .map(|(graphlet_counter, _, _)| graphlet_counter)
+            .reduce(new_counter, |mut left, right| {
+                left.merge(&right);
+                left
+            })
+    }
+
+    /// Returns the graphlet counts of the whole graph, processing the edges in parallel chunks.
+    ///
+    /// # Arguments
+    /// * `chunk_size` - The number of edges processed by each rayon task.
+    ///
+    /// # Raises
+    /// * If the provided chunk size is zero.
+    ///
+    /// # Implementation details
+    /// The edges are first collected, and each chunk is counted as done by
+    /// [`par_count_all_graphlets`](HeterogeneousGraphlets::par_count_all_graphlets),
+    /// with a single counter and scratch buffers reused across the edges of the chunk.
+    /// Smaller chunks balance the load better, as the expensive edges are spread over
+    /// more tasks that idle workers can steal, but increase the scheduling overhead and the
+    /// number of partial counters to merge. Larger chunks amortize that overhead and keep the
+    /// edges of each task close in memory, which matters on NUMA machines, at the cost of a
+    /// coarser load balancing. With hyperthreading, slightly larger chunks reduce the contention
+    /// between the threads sharing a core.
+    ///
+    #[cfg(feature = "rayon")]
+    fn par_get_graph_graphlet_counts_with_chunk_size(
+        &self,
+        chunk_size: usize,
+    ) -> Self::GraphLetCounter
+    where
+        Self: ParallelGraph + Sync + Sized,
+        Self::GraphLetCounter: Send,
+        Count: Send,
+    {
+        assert!(chunk_size > 0, "The chunk size must be strictly positive.");
+        let new_counter =
+            || <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let edges = self
+            .par_iter_edges()
+            .filter(|&(src, dst)| src < dst)
+            .collect::<Vec<_>>();
+        edges
+            .par_chunks(chunk_size)
+            .map(|chunk| {
+                let mut graphlet_counter = new_counter();
+                let mut edge_graphlet_counter = new_counter();
+                let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+                for &(src, dst) in chunk {
+                    self.get_heterogeneous_graphlet_into(
+                        src,
+                        dst,
+                        &mut scratch,
+                        &mut edge_graphlet_counter,
+                    );
+                    graphlet_counter.merge(&edge_graphlet_counter);
+                }
+                graphlet_counter
+            })
+            .reduce(new_counter, |mut left, right| {
+                left.merge(&right);
+                left
+            })
+    }
+
+    /// Counts the graphlets of the whole graph in parallel into the provided shared counter.
+    ///
+    /// # Arguments
+    /// * `graphlet_counter` - The counter shared by the threads, such as a [`DenseAtomicCounter`].
+    ///
+    /// # Implementation details
+    /// Each rayon task counts the graphlets of the edges it visits reusing its scratch
+    /// buffers and edge counter, as done by
+    /// [`par_count_all_graphlets`](HeterogeneousGraphlets::par_count_all_graphlets), and
+    /// inserts their counts directly into the shared counter, so that no counters are
+    /// merged. The counts and the examined node quadruples are added to those already
+    /// in the counter.
+    ///
+    #[cfg(feature = "rayon")]
+    fn par_count_all_graphlets_into<C>(&self, graphlet_counter: &C)
+    where
+        Self: ParallelGraph + Sync + Sized,
+        C: ConcurrentGraphLetCounter<Graphlet, Count>,
+    {
+        self.par_iter_edges()
+            .filter(|&(src, dst)| src < dst)
+            .for_each_init(
+                || {
+                    (
+                        <Self::GraphLetCounter>::with_number_of_elements(
+                            self.get_number_of_node_labels(),
+                        ),
+                        GraphletScratch::new(self.get_number_of_node_labels_usize()),
+                    )
+                },
+                |(edge_graphlet_counter, scratch), (src, dst)| {
+                    self.get_heterogeneous_graphlet_into(src, dst, scratch, edge_graphlet_counter);
+                    for (graphlet, count) in edge_graphlet_counter.iter_graphlets_and_counts() {
+                        graphlet_counter.insert_count(graphlet, count);
+                    }
+                    graphlet_counter.record_examined(edge_graphlet_counter.examined());
+                },
+            );
+    }
+
+    /// Returns a fixed-length feature vector of the graphlet counts of the whole graph.
+    ///
+    /// # Implementation details
+    /// With `n` node labels, the vector has a slot for each graphlet kind and ordered label
+    /// combination of its nodes, for a total of `2 * n^3 + 10 * n^4` slots. The kinds follow
+    /// the order of [`ExtendedGraphletType::all`], and the slots of each kind follow the
+    /// lexicographic order of the label indices, in the positions they are encoded with:
+    /// the labels `(l0, l1, l2)` of the three-node graphlets, `Triad` and `Triangle`, are
+    /// in slot `l0 * n^2 + l1 * n + l2` of their kind, and the labels `(l0, l1, l2, l3)` of
+    /// the four-node graphlets in slot `l0 * n^3 + l1 * n^2 + l2 * n + l3`. The combinations
+    /// not found in the graph are zero, so that the vectors of graphs with the same node
+    /// labels are aligned, whatever their graphlets.
+    ///
+    /// Each graphlet is decoded with the layout, and its slot is the offset of its kind
+    /// plus the index of its label combination, so that the slots are never materialized.
+    ///
+    fn graphlet_feature_vector(&self) -> Vec<Count> {
+        let number_of_elements = self.get_number_of_node_labels();
+        let number_of_labels = self.get_number_of_node_labels_usize();
+
+        let mut kind_offsets = [0; ExtendedGraphletType::COUNT];
+        let mut number_of_slots = 0;
+        for graphlet_kind in ExtendedGraphletType::all() {
+            kind_offsets[graphlet_kind.orbit_index()] = number_of_slots;
+            number_of_slots += number_of_labels.pow(graphlet_kind.node_count() as u32);
+        }
+
+        let mut features = vec![Count::ZERO; number_of_slots];
+        for (graphlet, count) in self.count_all_graphlets().iter_graphlets_and_counts() {
+            let (graphlet_kind, (first, second, third, fourth)) =
+                Layout::decode::<ExtendedGraphletType>(graphlet, number_of_elements);
+            let slot = [first, second, third, fourth][..graphlet_kind.node_count()]
+                .iter()
+                .fold(0, |slot, &label| {
+                    slot * number_of_labels + self.get_node_label_index(label)
+                });
+            features[kind_offsets[graphlet_kind.orbit_index()] + slot] += count;
+        }
+        features
+    }
+
+    /// Returns the graphlet counts of the edges incident to the provided node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose graphlets should be returned.
+    ///
+    /// # Implementation details
+    /// The graphlet counts of the edges between the node and each of its neighbours are
+    /// summed, so that a graphlet is counted once per edge of the node it includes.
+    /// The examined node quadruples of the edges are summed likewise.
+    ///
+    fn get_node_graphlets(&self, node: usize) -> Self::GraphLetCounter {
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut edge_graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+        for neighbour in self.iter_neighbours(node) {
+            self.get_heterogeneous_graphlet_into(
+                node.min(neighbour),
+                node.max(neighbour),
+                &mut scratch,
+                &mut edge_graphlet_counter,
+            );
+            for (graphlet, count) in edge_graphlet_counter.iter_graphlets_and_counts() {
+                graphlet_counter.insert_count(graphlet, count);
+            }
+            graphlet_counter.record_examined(edge_graphlet_counter.examined());
+        }
+        graphlet_counter
+    }
+
+    /// Returns the graphlet degree vector of the provided node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose graphlet degree vector should be returned.
+    ///
+    /// # Implementation details
+    /// The vector has an entry per graphlet kind, indexed as in the `u8` conversion of
+    /// [`ExtendedGraphletType`], holding the counts of
+    /// [`get_node_graphlets`](HeterogeneousGraphlets::get_node_graphlets) of that kind
+    /// summed over all the combinations of node labels.
+    ///
+    fn graphlet_degree_vector(&self, node: usize) -> Vec<Count> {
+        self.get_node_graphlets(node)
+            .get_kind_counts::<_, Layout>(self.get_number_of_node_labels())
+            .to_vec()
+    }
+
+    /// Returns the counts of the graphlets of the provided edge, summed over the node labels.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The i-th entry is the count of the graphlets of kind `ExtendedGraphletType::from(i as u8)`,
+    /// as the ones of [`graphlet_degree_vector`](HeterogeneousGraphlets::graphlet_degree_vector).
+    /// The graphlets are counted on a view of the graph with a single node label, so that
+    /// their hashes only encode their kinds and are counted in a small dense vector,
+    /// without hashing.
+    ///
+    fn get_edge_orbit_counts(&self, src: usize, dst: usize) -> [Count; 12] {
+        let mut orbit_counts = [Count::ZERO; 12];
+        let graphlet_counter = HeterogeneousGraphlets::<usize, Count>::get_heterogeneous_graphlet(
+            &UnlabeledGraph::new(self),
+            src,
+            dst,
+        );
+        for (graphlet, count) in graphlet_counter.iter_graphlets_and_counts() {
+            let kind = <PolynomialLayout as GraphletHashLayout<usize, u8>>::decode_graphlet_kind::<
+                ExtendedGraphletType,
+            >(graphlet, 1);
+            orbit_counts[kind.orbit_index()] += count;
+        }
+        orbit_counts
+    }
+
+    /// Returns whether the provided edge is part of at least one graphlet of the provided kind.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `kind` - The graphlet kind to look for.
+    ///
+    /// # Implementation details
+    /// The graphlet kinds that the traversal enumerates directly, i.e. triads, triangles,
+    /// four-cliques, chordal-cycle edges, tailed-triangle centers and tails, four-path edges
+    /// and four-cycles, are searched for in the neighbourhoods of the source and destination
+    /// nodes, and the search stops as soon as the first instance is found.
+    ///
+    /// The remaining kinds, i.e. four-path centers, four-stars, tailed-triangle edges and
+    /// chordal-cycle centers, are derived from the orbit formulas of the "Heterogeneous Graphlets"
+    /// paper, which require the complete counts of the directly enumerated kinds. For these
+    /// kinds the search cannot be short-circuited, and the method falls back to the full
+    /// computation of [`get_heterogeneous_graphlet`](HeterogeneousGraphlets::get_heterogeneous_graphlet).
+    ///
+    fn has_any_motif_of_kind(&self, src: usize, dst: usize, kind: ExtendedGraphletType) -> bool {
+        let is_src_neighbour =
+            |node: usize| self.iter_neighbours(src).any(|neighbour| neighbour == node);
+        let is_dst_neighbour =
+            |node: usize| self.iter_neighbours(dst).any(|neighbour| neighbour == node);
+        // The neighbours of the provided node, excluding the source and destination nodes.
+        let outer_neighbours = |node: usize| {
+            self.iter_neighbours(node)
+                .filter(move |&neighbour| neighbour != src && neighbour != dst)
+        };
+        // The nodes forming a triangle with the source and destination nodes.
+        let triangle_nodes = || outer_neighbours(src).filter(move |&node| is_dst_neighbour(node));
+        // The nodes that are neighbours solely of the source node.
+        let src_exclusive_neighbours =
+            || outer_neighbours(src).filter(move |&node| !is_dst_neighbour(node));
+        // The nodes that are neighbours solely of the destination node.
+        let dst_exclusive_neighbours =
+            || outer_neighbours(dst).filter(move |&node| !is_src_neighbour(node));
+
+        match kind {
+            ExtendedGraphletType::Triad => {
+                src_exclusive_neighbours().next().is_some()
+                    || dst_exclusive_neighbours().next().is_some()
+            }
+            ExtendedGraphletType::Triangle => triangle_nodes().next().is_some(),
+            ExtendedGraphletType::FourClique => triangle_nodes().any(|triangle_node| {
+                outer_neighbours(triangle_node).any(|second_order_neighbour| {
+                    is_src_neighbour(second_order_neighbour)
+                        && is_dst_neighbour(second_order_neighbour)
+                })
+            }),
+            ExtendedGraphletType::ChordalCycleEdge => triangle_nodes().any(|triangle_node| {
+                outer_neighbours(triangle_node).any(|second_order_neighbour| {
+                    is_src_neighbour(second_order_neighbour)
+                        != is_dst_neighbour(second_order_neighbour)
+                })
+            }),
+            ExtendedGraphletType::TailedTriCenter => triangle_nodes().any(|triangle_node| {
+                outer_neighbours(triangle_node).any(|second_order_neighbour| {
+                    !is_src_neighbour(second_order_neighbour)
+                        && !is_dst_neighbour(second_order_neighbour)
+                })
+            }),
+            ExtendedGraphletType::TailedTriTail => {
+                src_exclusive_neighbours().any(|root| {
+                    outer_neighbours(root).any(|second_order_neighbour| {
+                        is_src_neighbour(second_order_neighbour)
+                            && !is_dst_neighbour(second_order_neighbour)
+                    })
+                }) || dst_exclusive_neighbours().any(|root| {
+                    outer_neighbours(root).any(|second_order_neighbour| {
+                        is_dst_neighbour(second_order_neighbour)
+                            && !is_src_neighbour(second_order_neighbour)
+                    })
+                })
+            }
+            ExtendedGraphletType::FourPathEdge => src_exclusive_neighbours()
+                .chain(dst_exclusive_neighbours())
+                .any(|root| {
+                    outer_neighbours(root).any(|second_order_neighbour| {
+                        !is_src_neighbour(second_order_neighbour)
+                            && !is_dst_neighbour(second_order_neighbour)
+                    })
+                }),
+            ExtendedGraphletType::FourCycle => dst_exclusive_neighbours().any(|root| {
+                outer_neighbours(root).any(|second_order_neighbour| {
+                    is_src_neighbour(second_order_neighbour)
+                        && !is_dst_neighbour(second_order_neighbour)
+                })
+            }),
+            // The formula-derived kinds require the complete count.
+            ExtendedGraphletType::FourPathCenter
+            | ExtendedGraphletType::FourStar
+            | ExtendedGraphletType::TailedTriEdge
+            | ExtendedGraphletType::ChordalCycleCenter => {
+                let graphlet_counter = self.get_heterogeneous_graphlet(src, dst);
+                let has_motif =
+                    graphlet_counter
+                        .iter_graphlets_and_counts()
+                        .any(|(graphlet, count)| {
+                            count > Count::ZERO
+                                && Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                                    graphlet,
+                                    self.get_number_of_node_labels(),
+                                ) == kind
+                        });
+                has_motif
+            }
+        }
+    }
+
+    /// Returns the orbit signature of the provided edge.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The orbit signature is a dense vector with the count of each graphlet,
+    /// indexed by the graphlet hash. Its length is therefore the maximal hash
+    /// plus one, and it is the same for all the edges of the graph.
+    ///
+    fn get_orbit_signature(&self, src: usize, dst: usize) -> Vec<Count> {
+        let maximal_hash =
+            Layout::maximal_hash::<ExtendedGraphletType>(self.get_number_of_node_labels());
+        let mut signature = vec![Count::ZERO; u128::convert(maximal_hash) as usize + 1];
+        for (graphlet, count) in self
+            .get_heterogeneous_graphlet(src, dst)
+            .iter_graphlets_and_counts()
+        {
+            signature[u128::convert(graphlet) as usize] += count;
+        }
+        signature
+    }
+
+    /// Returns the edges of the graph grouped by the hash of their orbit signature.
+    ///
+    /// # Implementation details
+    /// Each undirected edge is considered once, with the smaller node as source, and the
+    /// edges in the same group share the same [`get_orbit_signature`](HeterogeneousGraphlets::get_orbit_signature),
+    /// i.e. they are locally structurally equivalent. Since the signature encodes the labels
+    /// of the source and destination nodes in order, an edge is equivalent to another one
+    /// only if their endpoints with the same role also have the same label.
+    ///
+    #[cfg(feature = "std")]
+    fn group_edges_by_signature(&self) -> HashMap<u64, Vec<(usize, usize)>>
+    where
+        Count: Hash,
+    {
+        let mut groups: HashMap<u64, Vec<(usize, usize)>> = HashMap::new();
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                let mut hasher = DefaultHasher::new();
+                self.get_orbit_signature(src, dst).hash(&mut hasher);
+                groups.entry(hasher.finish()).or_default().push((src, dst));
+            }
+        }
+        groups
+    }
+
+    /// Returns the signed differences between the graphlet counts of two edges.
+    ///
+    /// # Arguments
+    /// * `first_edge` - The source and destination nodes of the first edge.
+    /// * `second_edge` - The source and destination nodes of the second edge.
+    /// * `number_of_elements` - The number of elements used to decode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each entry is a decoded graphlet together with its count in the first edge minus
+    /// its count in the second edge. The graphlets with the same count in both edges are
+    /// omitted, and the entries are sorted by decreasing absolute difference, breaking
+    /// ties by increasing graphlet hash.
+    ///
+    fn edge_signature_diff(
+        &self,
+        first_edge: (usize, usize),
+        second_edge: (usize, usize),
+        number_of_elements: Self::NodeLabel,
+    ) -> Vec<(ExtendedGraphletType, [Self::NodeLabel; 4], i64)>
+    where
+        u128: Primitive<Count>,
+    {
+        let mut differences: BTreeMap<Graphlet, i64> = BTreeMap::new();
+        for ((src, dst), sign) in [(first_edge, 1), (second_edge, -1)] {
+            for (graphlet, count) in self
+                .get_heterogeneous_graphlet(src, dst)
+                .iter_graphlets_and_counts()
+            {
+                *differences.entry(graphlet).or_default() += sign * u128::convert(count) as i64;
+            }
+        }
+
+        let mut differences = differences
+            .into_iter()
+            .filter(|&(_, difference)| difference != 0)
+            .map(|(graphlet, difference)| {
+                let (graphlet_kind, (first, second, third, fourth)) =
+                    Layout::decode::<ExtendedGraphletType>(graphlet, number_of_elements);
+                (graphlet_kind, [first, second, third, fourth], difference)
+            })
+            .collect::<Vec<_>>();
+        differences.sort_by_key(|&(_, _, difference)| core::cmp::Reverse(difference.abs()));
+        differences
+    }
+
+    /// Returns the orbit signature of the provided edge joined with the features of its nodes.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    /// * `node_features` - The feature vectors of the nodes, where the i-th vector is the one of node i.
+    ///
+    /// # Implementation details
+    /// The returned vector is composed of, in this order:
+    /// * the orbit signature of the edge, as returned by [`get_orbit_signature`](HeterogeneousGraphlets::get_orbit_signature),
+    /// * the features of the source node,
+    /// * the features of the destination node.
+    ///
+    fn edge_features_with_node_attrs(
+        &self,
+        src: usize,
+        dst: usize,
+        node_features: &[Vec<f32>],
+    ) -> Vec<f32>
+    where
+        f32: Primitive<Count>,
+    {
+        let signature = self.get_orbit_signature(src, dst);
+        let mut features = Vec::with_capacity(
+            signature.len() + node_features[src].len() + node_features[dst].len(),
+        );
+        features.extend(signature.into_iter().map(f32::convert));
+        features.extend_from_slice(&node_features[src]);
+        features.extend_from_slice(&node_features[dst]);
+        features
+    }
+
+    /// Returns the graphlet counts of the graph approximated on sliding windows of node IDs.
+    ///
+    /// # Arguments
+    /// * `window_size` - The number of nodes in each window.
+    ///
+    /// # Raises
+    /// * If the provided window size is zero.
+    ///
+    /// # Implementation details
+    /// The windows slide over the node IDs with a stride of half of the window size,
+    /// and the graphlets of each edge are counted on the subgraph induced by the first
+    /// window containing both of its nodes. Each edge is therefore counted once, even
+    /// though it may appear in several overlapping windows.
+    ///
+    /// This is an approximation meant for graphs whose edges are local with respect to
+    /// the node IDs: the graphlets including nodes outside of the window of the edge are
+    /// missed. When the window is at least as large as the graph, the counts are exact.
+    ///
+    fn windowed_graphlet_counts(&self, window_size: usize) -> Self::GraphLetCounter {
+        assert!(
+            window_size > 0,
+            "The window size must be strictly positive."
+        );
+        let stride = (window_size / 2).max(1);
+
+        let mut graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut window_start = 0;
+
+        loop {
+            let window = WindowedGraph::new(self, window_start, window_size);
+            for src in window.iter_nodes() {
+                for dst in window.iter_neighbours(src) {
+                    // We skip the edges that were already counted in the previous window.
+                    if src >= dst
+                        || (window_start >= stride && dst < window_start - stride + window_size)
+                    {
+                        continue;
+                    }
+                    for (graphlet, count) in
+                        HeterogeneousGraphlets::<Graphlet, Count, Layout>::get_heterogeneous_graphlet(
+                            &window, src, dst,
+                        )
+                        .iter_graphlets_and_counts()
+                    {
+                        graphlet_counter.insert_count(graphlet, count);
+                    }
+                }
+            }
+            if window_start + window_size >= self.get_number_of_nodes() {
+                break;
+            }
+            window_start += stride;
+        }
+
+        graphlet_counter
+    }
+
+    /// Iterates over the graphlet counts of the graph grouped by source node.
+    ///
+    /// # Implementation details
+    /// The i-th element is the node i together with the merged graphlet counts of its
+    /// edges towards nodes with a larger ID, so that each edge is counted once. Nodes
+    /// without such edges are paired with an empty counter. Summing all the counters
+    /// yields the graphlet counts of the whole graph.
+    ///
+    fn iter_graphlets_by_source(
+        &self,
+    ) -> impl Iterator<Item = (usize, Self::GraphLetCounter)> + '_ {
+        (0..self.get_number_of_nodes()).map(move |src| {
+            let mut graphlet_counter =
+                <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                for (graphlet, count) in self
+                    .get_heterogeneous_graphlet(src, dst)
+                    .iter_graphlets_and_counts()
+                {
+                    graphlet_counter.insert_count(graphlet, count);
+                }
+            }
+            (src, graphlet_counter)
+        })
+    }
+
+    /// Updates the graphlet counts of the whole graph after the neighbours of a node changed.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose neighbours changed.
+    /// * `old_neighbours` - The previous neighbours of the node, in strictly ascending order.
+    /// * `graphlet_counter` - The graphlet counts of the whole graph before the change.
+    ///
+    /// # Implementation details
+    /// The graph must already reflect the new neighbours of the node, while the previous
+    /// graph is seen through a [`RewiredGraph`] with the provided neighbours. Since the
+    /// graphlets of an edge only include nodes up to two hops away from it, only the edges
+    /// with a node within two hops of the node, in either graph, may change their counts:
+    /// the counts of these edges in the previous graph are subtracted from the counter,
+    /// and their counts in the current graph are added, so that the rest of the graph is
+    /// never recounted. The examined node quadruples of the counter are left unchanged.
+    ///
+    fn recompute_node_motifs(
+        &self,
+        node: usize,
+        old_neighbours: &[usize],
+        graphlet_counter: &mut Self::GraphLetCounter,
+    ) {
+        let old_graph = RewiredGraph::new(self, node, old_neighbours);
+
+        let mut affected_nodes = vec![node];
+        let mut frontier = vec![node];
+        for _ in 0..2 {
+            let mut next_frontier = Vec::new();
+            for &src in frontier.iter() {
+                next_frontier.extend(self.iter_neighbours(src));
+                next_frontier.extend(old_graph.iter_neighbours(src));
+            }
+            next_frontier.sort_unstable();
+            next_frontier.dedup();
+            next_frontier.retain(|other| affected_nodes.binary_search(other).is_err());
+            affected_nodes.extend(next_frontier.iter().copied());
+            affected_nodes.sort_unstable();
+            frontier = next_frontier;
+        }
+        let is_affected = |other: usize| affected_nodes.binary_search(&other).is_ok();
+
+        let mut edge_graphlet_counter =
+            <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+        let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+        for &src in affected_nodes.iter() {
+            // The edges between two affected nodes are visited from their smaller node.
+            for dst in old_graph
+                .iter_neighbours(src)
+                .filter(|&dst| src < dst || !is_affected(dst))
+            {
+                HeterogeneousGraphlets::<Graphlet, Count, Layout>::get_heterogeneous_graphlet_into(
+                    &old_graph,
+                    src.min(dst),
+                    src.max(dst),
+                    &mut scratch,
+                    &mut edge_graphlet_counter,
+                );
+                for (graphlet, count) in edge_graphlet_counter.iter_graphlets_and_counts() {
+                    let total = graphlet_counter.remove(graphlet);
+                    graphlet_counter.insert_count(graphlet, total - count);
+                }
+            }
+            for dst in self
+                .iter_neighbours(src)
+                .filter(|&dst| src < dst || !is_affected(dst))
+            {
+                self.get_heterogeneous_graphlet_into(
+                    src.min(dst),
+                    src.max(dst),
+                    &mut scratch,
+                    &mut edge_graphlet_counter,
+                );
+                for (graphlet, count) in edge_graphlet_counter.iter_graphlets_and_counts() {
+                    graphlet_counter.insert_count(graphlet, count);
+                }
+            }
+        }
+    }
+
+    /// Returns the adjacency of the graph in CSR format, weighted by the count of the provided kind.
+    ///
+    /// # Arguments
+    /// * `kind` - The graphlet kind whose counts are used as edge weights.
+    ///
+    /// # Implementation details
+    /// The returned tuple contains the offsets, the destinations and the weights of the edges,
+    /// where the neighbours of node i are `edges[offsets[i]..offsets[i + 1]]`, in the same order
+    /// as [`iter_neighbours`](Graph::iter_neighbours). The weight of an edge is the total count,
+    /// over all node labels, of the graphlets of the provided kind including it. The graphlets
+    /// are counted once per undirected edge, and both directions of the edge share the weight.
+    ///
+    fn motif_weighted_adjacency(
+        &self,
+        kind: ExtendedGraphletType,
+    ) -> (Vec<usize>, Vec<usize>, Vec<Count>) {
+        let mut offsets = Vec::with_capacity(self.get_number_of_nodes() + 1);
+        let mut edges = Vec::new();
+        let mut weights = Vec::new();
+        offsets.push(0);
+
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src) {
+                let weight = if src < dst {
+                    let mut weight = Count::ZERO;
+                    for (graphlet, count) in self
+                        .get_heterogeneous_graphlet(src, dst)
+                        .iter_graphlets_and_counts()
+                    {
+                        if Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                            graphlet,
+                            self.get_number_of_node_labels(),
+                        ) == kind
+                        {
+                            weight += count;
+                        }
+                    }
+                    weight
+                } else {
+                    // The weight of the reverse edge was already computed
+                    // in the row of the destination node.
+                    let position = edges[offsets[dst]..offsets[dst + 1]]
+                        .iter()
+                        .position(|&neighbour| neighbour == src)
+                        .expect("The graph must be undirected.");
+                    weights[offsets[dst] + position]
+                };
+                edges.push(dst);
+                weights.push(weight);
+            }
+            offsets.push(edges.len());
+        }
+
+        (offsets, edges, weights)
+    }
+
+    /// Returns the number of four-cliques of the graph for each combination of node labels.
+    ///
+    /// # Implementation details
+    /// The four-cliques are enumerated directly, each one once, as the quadruples of
+    /// pairwise adjacent nodes in increasing order: the triangle nodes of each edge towards
+    /// a larger node are intersected with the neighbours of the triangle nodes. The keys
+    /// are the sorted node labels of the four-cliques, so that the same combination of
+    /// labels is tallied together regardless of the roles of the nodes.
+    ///
+    #[cfg(feature = "std")]
+    fn four_clique_label_breakdown(&self) -> HashMap<[Self::NodeLabel; 4], u64>
+    where
+        Self::NodeLabel: Hash,
+    {
+        let mut breakdown = HashMap::new();
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                let triangle_nodes = self
+                    .iter_neighbours(dst)
+                    .filter(|&node| dst < node && self.is_neighbour(src, node))
+                    .collect::<Vec<usize>>();
+                for (i, &first) in triangle_nodes.iter().enumerate() {
+                    for &second in triangle_nodes[i + 1..].iter() {
+                        if self.is_neighbour(first, second) {
+                            let mut labels =
+                                [src, dst, first, second].map(|node| self.get_node_label(node));
+                            labels.sort_unstable();
+                            *breakdown.entry(labels).or_insert(0) += 1;
+                        }
+                    }
+                }
+            }
+        }
+        breakdown
+    }
+
+    /// Returns the edges whose graphlets include the provided labeled motif.
+    ///
+    /// # Arguments
+    /// * `kind` - The graphlet kind of the motif.
+    /// * `labels` - The node labels of the motif, in the order used by the graphlet hashes.
+    ///
+    /// # Implementation details
+    /// The labels follow the order of the encoded graphlets, where the first two are the labels
+    /// of the source and destination nodes of the edge, and the remaining ones are the labels of
+    /// the other nodes of the motif in the roles defined by its kind. For the three-node graphlet
+    /// kinds, the fourth label is ignored and replaced by the dummy label of the layout. Each
+    /// undirected edge is considered once, with the smaller node as source, so the labels of
+    /// the source and destination nodes must be provided accordingly.
+    ///
+    fn edges_in_labeled_motif(
+        &self,
+        kind: ExtendedGraphletType,
+        labels: [Self::NodeLabel; 4],
+    ) -> Vec<(usize, usize)> {
+        let fourth_label = match kind {
+            ExtendedGraphletType::Triad | ExtendedGraphletType::Triangle => {
+                Layout::dummy_label(self.get_number_of_node_labels())
+            }
+            _ => labels[3],
+        };
+        let motif = Layout::encode(
+            (labels[0], labels[1], labels[2], fourth_label),
+            kind,
+            self.get_number_of_node_labels(),
+        );
+
+        let mut edges = Vec::new();
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                if self
+                    .get_heterogeneous_graphlet(src, dst)
+                    .get_number_of_graphlets(motif)
+                    > Count::ZERO
+                {
+                    edges.push((src, dst));
+                }
+            }
+        }
+        edges
+    }
+
+    /// Returns how many edges include graphlets of each pair of graphlet kinds.
+    ///
+    /// # Implementation details
+    /// The entry `(i, j)` of the returned matrix is the number of undirected edges that are
+    /// included both in graphlets of the kind i and in graphlets of the kind j, where the kinds
+    /// are indexed as in the `u8` conversion of [`ExtendedGraphletType`]. The matrix is therefore
+    /// symmetric, and its diagonal holds the number of edges including each graphlet kind.
+    ///
+    fn kind_cooccurrence_matrix(&self) -> [[u64; 12]; 12] {
+        let mut matrix = [[0; 12]; 12];
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                let mut present_kinds = [false; 12];
+                for (graphlet, count) in self
+                    .get_heterogeneous_graphlet(src, dst)
+                    .iter_graphlets_and_counts()
+                {
+                    if count > Count::ZERO {
+                        let kind = Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                            graphlet,
+                            self.get_number_of_node_labels(),
+                        );
+                        present_kinds[u8::from(kind) as usize] = true;
+                    }
+                }
+                for (first, &first_present) in present_kinds.iter().enumerate() {
+                    for (second, &second_present) in present_kinds.iter().enumerate() {
+                        if first_present && second_present {
+                            matrix[first][second] += 1;
+                        }
+                    }
+                }
+            }
+        }
+        matrix
+    }
+
+    /// Returns an approximation of the graphlet degree vectors of the provided seed nodes.
+    ///
+    /// # Arguments
+    /// * `seed_nodes` - The nodes whose graphlet degree vectors should be approximated.
+    /// * `hops` - The number of hops the frontier of each seed node is expanded for.
+    /// * `max_frontier_size` - The maximal number of nodes kept in the frontier at each hop,
+    ///   and of edges of each seed node whose graphlets are counted.
+    /// * `rng` - The random number generator used to sample the frontiers.
+    ///
+    /// # Raises
+    /// * If the provided maximal frontier size is zero.
+    ///
+    /// # Implementation details
+    /// The graphlet degree vector of a node is the total count of each graphlet kind over
+    /// the edges incident to the node, indexed as in the `u8` conversion of
+    /// [`ExtendedGraphletType`], as returned by
+    /// [`graphlet_degree_vector`](HeterogeneousGraphlets::graphlet_degree_vector).
+    ///
+    /// For each seed node, the frontier is expanded breadth-first for the provided number of
+    /// hops. All the neighbours of the seed node are explored, while at the following hops,
+    /// whenever the newly reached nodes exceed the maximal frontier size, a uniform sample of
+    /// them is kept. The graphlets of the edges between the seed node and a uniform sample of
+    /// at most the maximal frontier size of its neighbours are then counted on the subgraph
+    /// induced by the explored nodes, and scaled by the inverse of the fraction of sampled edges.
+    /// The scaling is computed in `f64` and rounded to the nearest count, so that it neither
+    /// truncates the estimates nor overflows the intermediate products, and estimates larger
+    /// than the largest count saturate to it.
+    ///
+    /// The maximal frontier size is the budget of the approximation, and the number of hops
+    /// alone cannot bound it: in small-world graphs, a few hops already reach most of the
+    /// nodes, and the approximation would cost as much as the exact graphlet degree vectors.
+    ///
+    /// The scaling makes the estimator unbiased with respect to the sampling of the edges
+    /// of the seed node only: the graphlets including nodes that were not sampled at the
+    /// following hops, or that are farther than the provided number of hops, are missed, so
+    /// the estimates are biased downwards. Since all the graphlets of an edge lie within three
+    /// hops of its nodes, with at least three hops and a frontier large enough to never be
+    /// sampled, the graphlet degree vectors are exact.
+    ///
+    #[cfg(feature = "rand")]
+    fn approximate_gdv(
+        &self,
+        seed_nodes: &[usize],
+        hops: usize,
+        max_frontier_size: usize,
+        rng: &mut impl Rng,
+    ) -> HashMap<usize, [Count; 12]>
+    where
+        Count: Primitive<f64>,
+        f64: Primitive<Count>,
+    {
+        assert!(
+            max_frontier_size > 0,
+            "The maximal frontier size must be strictly positive."
+        );
+
+        // Keeps a uniform sample of at most the maximal frontier size of the provided nodes,
+        // with a partial Fisher-Yates shuffle.
+        let mut sample = |nodes: &mut Vec<usize>| {
+            if nodes.len() > max_frontier_size {
+                for i in 0..max_frontier_size {
+                    let j = rng.gen_range(i..nodes.len());
+                    nodes.swap(i, j);
+                }
+                nodes.truncate(max_frontier_size);
+            }
+        };
+
+        let mut gdvs = HashMap::with_capacity(seed_nodes.len());
+        for &seed_node in seed_nodes {
+            let mut explored_nodes = HashSet::from([seed_node]);
+            let mut frontier = vec![seed_node];
+
+            for hop in 0..hops {
+                let mut next_frontier = frontier
+                    .iter()
+                    .flat_map(|&node| self.iter_neighbours(node))
+                    .filter(|node| !explored_nodes.contains(node))
+                    .collect::<Vec<usize>>();
+                next_frontier.sort_unstable();
+                next_frontier.dedup();
+                if hop > 0 {
+                    sample(&mut next_frontier);
+                }
+                explored_nodes.extend(next_frontier.iter().copied());
+                frontier = next_frontier;
+            }
+
+            let subgraph = InducedSubgraph::new(self, explored_nodes.into_iter().collect());
+            let mut sampled_neighbours = subgraph.iter_neighbours(seed_node).collect::<Vec<_>>();
+            let number_of_neighbours = sampled_neighbours.len();
+            sample(&mut sampled_neighbours);
+
+            let mut graphlet_counter =
+                <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+            let mut edge_graphlet_counter =
+                <Self::GraphLetCounter>::with_number_of_elements(self.get_number_of_node_labels());
+            let mut scratch = GraphletScratch::new(self.get_number_of_node_labels_usize());
+            for &neighbour in sampled_neighbours.iter() {
+                HeterogeneousGraphlets::<Graphlet, Count, Layout>::get_heterogeneous_graphlet_into(
+                    &subgraph,
+                    seed_node.min(neighbour),
+                    seed_node.max(neighbour),
+                    &mut scratch,
+                    &mut edge_graphlet_counter,
+                );
+                graphlet_counter.merge(&edge_graphlet_counter);
+            }
+            let mut gdv =
+                graphlet_counter.get_kind_counts::<_, Layout>(self.get_number_of_node_labels());
+
+            if sampled_neighbours.len() < number_of_neighbours {
+                let scale = number_of_neighbours as f64 / sampled_neighbours.len() as f64;
+                for count in gdv.iter_mut() {
+                    *count = Count::convert((f64::convert(*count) * scale).round());
+                }
+            }
+
+            gdvs.insert(seed_node, gdv);
+        }
+
+        gdvs
+    }
+
+    /// Writes the node orbit counts of the graph in the output format of ORCA.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to stream the orbit counts to.
+    ///
+    /// # Implementation details
+    /// Each node is written on its own line, in node order, as the 15 space-separated counts
+    /// of the orbits of the graphlets with up to four nodes, numbered as in ORCA. The node
+    /// labels are ignored. The orbit counts are derived from the graphlet degree vector of the
+    /// node, that is the per-kind counts summed over its incident edges, and from its degree d:
+    ///
+    /// | ORCA orbit | Node position               | Derivation                                   |
+    /// |------------|-----------------------------|----------------------------------------------|
+    /// | 0          | Edge                        | d                                            |
+    /// | 1          | Three-path end              | `Triad` - 2 * o2                             |
+    /// | 2          | Three-path center           | d(d-1)/2 - o3                                |
+    /// | 3          | Triangle                    | `Triangle` / 2                               |
+    /// | 4          | Four-path end               | `FourPathEdge` - o5                          |
+    /// | 5          | Four-path center            | `FourPathCenter`                             |
+    /// | 6          | Four-star leaf              | `FourStar` - 3 * o7                          |
+    /// | 7          | Four-star center            | d(d-1)(d-2)/6 - o11 - o13 - o14              |
+    /// | 8          | Four-cycle                  | `FourCycle` / 2                              |
+    /// | 9          | Tailed triangle tail        | `TailedTriTail` - o11                        |
+    /// | 10         | Tailed triangle non-center  | `TailedTriCenter`                            |
+    /// | 11         | Tailed triangle center      | (`TailedTriEdge` - o10) / 2                  |
+    /// | 12         | Chordal cycle, degree two   | `ChordalCycleEdge` / 2 - o13                 |
+    /// | 13         | Chordal cycle, degree three | `ChordalCycleCenter`                         |
+    /// | 14         | Four-clique                 | `FourClique` / 3                             |
+    ///
+    #[cfg(feature = "std")]
+    fn write_orca_format<W: Write>(&self, mut writer: W) -> std::io::Result<()> {
+        for node in 0..self.get_number_of_nodes() {
+            let mut gdv = [Count::ZERO; 12];
+            let degree = self.get_node_degree(node);
+            for neighbour in self.iter_neighbours(node) {
+                for (graphlet, count) in self
+                    .get_heterogeneous_graphlet(node.min(neighbour), node.max(neighbour))
+                    .iter_graphlets_and_counts()
+                {
+                    let kind = Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                        graphlet,
+                        self.get_number_of_node_labels(),
+                    );
+                    gdv[u8::from(kind) as usize] += count;
+                }
+            }
+            let kind_total = |kind: ExtendedGraphletType| gdv[u8::from(kind) as usize];
+            let three = Count::TWO + Count::ONE;
+            let neighbour_pairs = Count::convert(degree * degree.saturating_sub(1) / 2);
+            let neighbour_triples =
+                Count::convert(degree * degree.saturating_sub(1) * degree.saturating_sub(2) / 6);
+
+            let mut orbits = [Count::ZERO; 15];
+            orbits[0] = Count::convert(degree);
+            orbits[3] = kind_total(ExtendedGraphletType::Triangle) / Count::TWO;
+            orbits[2] = neighbour_pairs - orbits[3];
+            orbits[1] = kind_total(ExtendedGraphletType::Triad) - Count::TWO * orbits[2];
+            orbits[5] = kind_total(ExtendedGraphletType::FourPathCenter);
+            orbits[4] = kind_total(ExtendedGraphletType::FourPathEdge) - orbits[5];
+            orbits[8] = kind_total(ExtendedGraphletType::FourCycle) / Count::TWO;
+            orbits[10] = kind_total(ExtendedGraphletType::TailedTriCenter);
+            orbits[11] =
+                (kind_total(ExtendedGraphletType::TailedTriEdge) - orbits[10]) / Count::TWO;
+            orbits[9] = kind_total(ExtendedGraphletType::TailedTriTail) - orbits[11];
+            orbits[13] = kind_total(ExtendedGraphletType::ChordalCycleCenter);
+            orbits[12] =
+                kind_total(ExtendedGraphletType::ChordalCycleEdge) / Count::TWO - orbits[13];
+            orbits[14] = kind_total(ExtendedGraphletType::FourClique) / three;
+            orbits[7] = neighbour_triples - orbits[11] - orbits[13] - orbits[14];
+            orbits[6] = kind_total(ExtendedGraphletType::FourStar) - three * orbits[7];
+
+            writeln!(
+                writer,
+                "{}",
+                orbits
+                    .iter()
+                    .map(|count| format!("{:?}", count))
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            )?;
+        }
+        writer.flush()
+    }
+
+    /// Returns, for each node label, the distribution of the graphlet kinds it takes part in.
+    ///
+    /// # Implementation details
+    /// The graphlets of each undirected edge are attributed to the labels of both of its
+    /// endpoints, so that the vector of a label sums the per-kind counts of the edges incident
+    /// to the nodes with that label, with edges between two nodes of the same label counted twice.
+    /// Each vector is then normalized to sum to one, and is indexed as in the `u8` conversion
+    /// of [`ExtendedGraphletType`]. Labels whose nodes have no edges are not included, and
+    /// labels whose edges take part in no graphlet are paired with an all-zero vector.
+    ///
+    #[cfg(feature = "std")]
+    fn conditional_kind_distribution(&self) -> HashMap<Self::NodeLabel, [f64; 12]>
+    where
+        Self::NodeLabel: Hash,
+        f64: Primitive<Count>,
+    {
+        let mut distributions: HashMap<Self::NodeLabel, [f64; 12]> = HashMap::new();
+        for src in 0..self.get_number_of_nodes() {
+            for dst in self.iter_neighbours(src).filter(|&dst| src < dst) {
+                let mut kind_counts = [0.0; 12];
+                for (graphlet, count) in self
+                    .get_heterogeneous_graphlet(src, dst)
+                    .iter_graphlets_and_counts()
+                {
+                    let kind = Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                        graphlet,
+                        self.get_number_of_node_labels(),
+                    );
+                    kind_counts[u8::from(kind) as usize] += f64::convert(count);
+                }
+                for node in [src, dst] {
+                    let distribution = distributions
+                        .entry(self.get_node_label(node))
+                        .or_insert([0.0; 12]);
+                    for (total, count) in distribution.iter_mut().zip(kind_counts) {
+                        *total += count;
+                    }
+                }
+            }
+        }
+
+        for distribution in distributions.values_mut() {
+            let total = distribution.iter().sum::<f64>();
+            if total > 0.0 {
+                for probability in distribution.iter_mut() {
+                    *probability /= total;
+                }
+            }
+        }
+
+        distributions
+    }
 }
@@ -1,4 +1,16 @@
-use std::fmt::Debug;
+use alloc::vec::Vec;
+use core::fmt::Debug;
+use core::ops::{Add, Mul};
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::compact_labeled_graph::CompactLabeledGraph;
+
+#[cfg(feature = "csr")]
+pub mod builder;
+#[cfg(feature = "csr")]
+pub mod csr;
 
 pub trait Graph {
     type Node;
@@ -17,7 +29,197 @@ pub trait Graph {
     ///
     /// # Arguments
     /// * `node` - The node whose neighbours should be iterated over.
+    ///
+    /// # Implementation details
+    /// The neighbours must be iterated in strictly ascending order, i.e. sorted and
+    /// without duplicates, as the graphlet counting merges the neighbourhoods of the
+    /// nodes of each edge. This is a hard precondition: unsorted neighbours silently
+    /// yield wrong graphlet counts in release builds, while debug builds check the
+    /// neighbours of the nodes of each edge whose graphlets are counted. The whole
+    /// graph can be checked with [`Graph::validate_sorted_neighbours`].
     fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_>;
+
+    /// Returns whether the neighbours of the provided node are strictly increasing.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose neighbours should be checked.
+    fn has_sorted_neighbours(&self, node: usize) -> bool {
+        let mut neighbours = self.iter_neighbours(node);
+        let Some(mut previous) = neighbours.next() else {
+            return true;
+        };
+        neighbours.all(|neighbour| {
+            let is_sorted = previous < neighbour;
+            previous = neighbour;
+            is_sorted
+        })
+    }
+
+    /// Checks that the neighbours of every node are strictly increasing.
+    ///
+    /// # Raises
+    /// * The first node whose neighbours are not strictly increasing.
+    fn validate_sorted_neighbours(&self) -> Result<(), usize> {
+        match (0..self.get_number_of_nodes()).find(|&node| !self.has_sorted_neighbours(node)) {
+            Some(node) => Err(node),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the degree of the provided node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose degree should be returned.
+    ///
+    /// # Implementation details
+    /// The default implementation counts the neighbours of the node, and graphs
+    /// storing their degrees, such as the CSR ones, should return them directly.
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.iter_neighbours(node).count()
+    }
+
+    /// Whether the graph supports fast neighbourhood membership checks.
+    ///
+    /// # Implementation details
+    /// When enabled, the neighbours of the triangle nodes are intersected with the
+    /// neighbourhoods of the source and destination nodes using [`Graph::is_neighbour`]
+    /// instead of merging their sorted neighbours.
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = false;
+
+    /// Returns whether the second node is a neighbour of the first node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose neighbours should be checked.
+    /// * `other` - The node to look for.
+    ///
+    /// # Implementation details
+    /// The default implementation scans the neighbours of the node and stops at the
+    /// first one larger than the node looked for, which is correct only because the
+    /// neighbours are iterated in ascending order, as the graphlet counting requires.
+    /// Graphs storing the neighbours in a sorted slice, such as the CSR ones, should
+    /// override it with a binary search.
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.iter_neighbours(node)
+            .take_while(|&neighbour| neighbour <= other)
+            .any(|neighbour| neighbour == other)
+    }
+
+    /// Returns whether the graph has an edge from the source to the destination node.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// This is the same query as [`Graph::is_neighbour`], so that graphs overriding
+    /// the latter answer it with their faster lookup.
+    fn has_edge(&self, src: usize, dst: usize) -> bool {
+        self.is_neighbour(src, dst)
+    }
+}
+
+/// The direction of an edge, with respect to the order in which its nodes are provided.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeDirection {
+    /// The edge goes from the first node to the second one only.
+    Forward,
+    /// The edge goes from the second node to the first one only.
+    Backward,
+    /// The edge goes in both directions.
+    Reciprocal,
+}
+
+/// A graph whose edges have a direction.
+///
+/// # Implementation details
+/// The neighbours iterated by [`Graph::iter_neighbours`] must be those of the
+/// underlying undirected graph, i.e. the union of the out and in neighbours of each
+/// node, as the graphlet counting intersects the neighbourhoods of both nodes of each
+/// edge. The out and in neighbours are only used to tell apart the orbit positions by
+/// the direction of the edges, as done by
+/// [`get_directed_heterogeneous_graphlet`](crate::prelude::HeterogeneousGraphlets::get_directed_heterogeneous_graphlet).
+pub trait DirectedGraph: Graph {
+    type OutNeighbourIter<'a>: Iterator<Item = usize> + 'a
+    where
+        Self: 'a;
+
+    type InNeighbourIter<'a>: Iterator<Item = usize> + 'a
+    where
+        Self: 'a;
+
+    /// Iterates over the nodes the edges leaving the given node point to.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose out neighbours should be iterated over.
+    ///
+    /// # Implementation details
+    /// As for [`Graph::iter_neighbours`], the out neighbours must be iterated in
+    /// strictly ascending order.
+    fn iter_out_neighbours(&self, node: usize) -> Self::OutNeighbourIter<'_>;
+
+    /// Iterates over the nodes whose edges point to the given node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose in neighbours should be iterated over.
+    ///
+    /// # Implementation details
+    /// As for [`Graph::iter_neighbours`], the in neighbours must be iterated in
+    /// strictly ascending order.
+    fn iter_in_neighbours(&self, node: usize) -> Self::InNeighbourIter<'_>;
+
+    /// Returns whether the graph has an edge leaving the source and pointing to the destination.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// As done by [`Graph::is_neighbour`], the default implementation scans the out
+    /// neighbours of the source node up to the destination node.
+    fn has_directed_edge(&self, src: usize, dst: usize) -> bool {
+        self.iter_out_neighbours(src)
+            .take_while(|&neighbour| neighbour <= dst)
+            .any(|neighbour| neighbour == dst)
+    }
+
+    /// Returns the direction of the edge between the provided nodes, if any.
+    ///
+    /// # Arguments
+    /// * `src` - The first node of the edge.
+    /// * `dst` - The second node of the edge.
+    fn get_edge_direction(&self, src: usize, dst: usize) -> Option<EdgeDirection> {
+        match (
+            self.has_directed_edge(src, dst),
+            self.has_directed_edge(dst, src),
+        ) {
+            (true, true) => Some(EdgeDirection::Reciprocal),
+            (true, false) => Some(EdgeDirection::Forward),
+            (false, true) => Some(EdgeDirection::Backward),
+            (false, false) => None,
+        }
+    }
+}
+
+/// A graph whose nodes and edges can be iterated over in parallel.
+///
+/// # Implementation details
+/// Generic code, such as the parallel graphlet counting of
+/// [`HeterogeneousGraphlets`](crate::prelude::HeterogeneousGraphlets), relies on
+/// this trait instead of the parallel iterators of a specific graph type.
+#[cfg(feature = "rayon")]
+pub trait ParallelGraph: Graph {
+    /// Iterates in parallel over the nodes.
+    fn par_iter_nodes(&self) -> impl IndexedParallelIterator<Item = usize> + '_ {
+        (0..self.get_number_of_nodes()).into_par_iter()
+    }
+
+    /// Iterates in parallel over the edges.
+    ///
+    /// # Implementation details
+    /// Each undirected edge must be returned in both directions, as the neighbours
+    /// iterated by [`Graph::iter_neighbours`], so that the parallel counting can
+    /// visit it once from its node with the smaller ID.
+    fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_;
 }
 
 pub trait TypedGraph: Graph {
@@ -39,5 +241,96 @@ pub trait TypedGraph: Graph {
     ///
     /// # Arguments
     /// * `node` - The node whose label should be returned.
+    ///
+    /// # Implementation details
+    /// The node labels are used as the digits of the graphlet hashes, and must
+    /// therefore be lower than the number of node labels. Graphs with labels
+    /// drawn from a non-contiguous set should map them to a dense range with
+    /// a [`LabelMap`](crate::prelude::LabelMap), as done by
+    /// [`InternedGraph`](crate::prelude::InternedGraph).
     fn get_node_label(&self, node: usize) -> Self::NodeLabel;
-}
\ No newline at end of file
+
+    /// Returns a view of the graph with the node labels remapped to a dense range.
+    ///
+    /// # Implementation details
+    /// The second element of the returned tuple maps the compacted node labels,
+    /// used as indices, to the original node labels.
+    fn compact_labels(&self) -> (CompactLabeledGraph<'_, Self>, Vec<Self::NodeLabel>)
+    where
+        Self: Sized,
+    {
+        CompactLabeledGraph::new(self)
+    }
+}
+
+/// A graph whose edges have labels, such as the relations of a knowledge graph.
+pub trait EdgeTypedGraph: Graph {
+    type EdgeLabel: Eq + Debug + Copy;
+
+    /// Returns the number of edge labels in the graph.
+    fn get_number_of_edge_labels(&self) -> Self::EdgeLabel;
+
+    /// Returns the edge label of the edge between the provided nodes.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The edges are undirected, so that the label must not depend on the order of
+    /// the nodes, and, as for the node labels, it must be lower than the number of
+    /// edge labels. It is only queried for the pairs of neighbouring nodes.
+    fn get_edge_label(&self, src: usize, dst: usize) -> Self::EdgeLabel;
+}
+
+/// A graph whose edges have weights, such as the confidences of a biological network.
+pub trait WeightedGraph: Graph {
+    type Weight: Copy;
+
+    /// Returns the weight of the edge between the provided nodes.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Implementation details
+    /// The edges are undirected, so that the weight must not depend on the order of
+    /// the nodes. It is only queried for the pairs of neighbouring nodes.
+    fn get_edge_weight(&self, src: usize, dst: usize) -> Self::Weight;
+}
+
+/// How the edge weights of a graphlet are combined into its contribution to the counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum WeightAggregation {
+    /// The product of the edge weights, so that unit weights count each graphlet once.
+    #[default]
+    Product,
+    /// The sum of the edge weights.
+    Sum,
+    /// The smallest edge weight.
+    Min,
+}
+
+impl WeightAggregation {
+    /// Returns the aggregation of the provided weights.
+    ///
+    /// # Arguments
+    /// * `weights` - The weights to aggregate.
+    ///
+    /// # Raises
+    /// * If there are no weights.
+    pub fn aggregate<Weight>(&self, weights: impl IntoIterator<Item = Weight>) -> Weight
+    where
+        Weight: Copy + PartialOrd + Add<Weight, Output = Weight> + Mul<Weight, Output = Weight>,
+    {
+        weights
+            .into_iter()
+            .reduce(|aggregated, weight| match self {
+                WeightAggregation::Product => aggregated * weight,
+                WeightAggregation::Sum => aggregated + weight,
+                WeightAggregation::Min if weight < aggregated => weight,
+                WeightAggregation::Min => aggregated,
+            })
+            .expect("At least a weight must be provided to be aggregated.")
+    }
+}
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// Builder of undirected [`CSRGraph`]s from nodes and edges added programmatically.
+///
+/// # Implementation details
+/// The nodes are assigned consecutive IDs in the order they are added. The edges
+/// may be added in either direction, as many times as desired, and are symmetrized
+/// and deduplicated when the graph is built, so that the neighbours of each node
+/// are sorted and unique.
+#[derive(Debug, Clone, Default)]
+pub struct GraphBuilder {
+    /// The label of each node added so far.
+    node_labels: Vec<u8>,
+    /// The edges added so far, as provided.
+    edges: Vec<(usize, usize)>,
+}
+
+impl GraphBuilder {
+    /// Returns a new builder without nodes and edges.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node with the provided label, returning its ID.
+    ///
+    /// # Arguments
+    /// * `label` - The label of the node.
+    pub fn add_node(&mut self, label: u8) -> usize {
+        self.node_labels.push(label);
+        self.node_labels.len() - 1
+    }
+
+    /// Adds an undirected edge between the provided nodes.
+    ///
+    /// # Arguments
+    /// * `src` - The source node of the edge.
+    /// * `dst` - The destination node of the edge.
+    ///
+    /// # Raises
+    /// * If the edge is a self-loop.
+    ///
+    /// # Implementation details
+    /// The endpoints may be nodes not added yet, and are checked when the graph is built.
+    pub fn add_edge(&mut self, src: usize, dst: usize) -> Result<(), String> {
+        if src == dst {
+            return Err(format!(
+                "Self-loops are not supported, found one on node {}.",
+                src
+            ));
+        }
+        self.edges.push((src, dst));
+        Ok(())
+    }
+
+    /// Returns the number of nodes added so far.
+    pub fn get_number_of_nodes(&self) -> usize {
+        self.node_labels.len()
+    }
+
+    /// Returns the graph with the nodes and edges added so far.
+    ///
+    /// # Raises
+    /// * See [`CSRGraph::from_edges`].
+    pub fn build(self) -> Result<CSRGraph, String> {
+        CSRGraph::from_directed_edges(self.node_labels, self.edges)
+    }
+}
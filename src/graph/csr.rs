@@ -0,0 +1,861 @@
+use std::collections::HashMap;
+use std::io::Read;
+
+use crate::prelude::*;
+use rayon::prelude::*;
+
+/// Compressed Sparse Row Graph
+///
+/// # Implementation details
+/// The neighbours of each node are stored in a contiguous slice, sorted and without
+/// duplicates, as required by [`HeterogeneousGraphlets::get_heterogeneous_graphlet`].
+/// The graph is expected to be undirected, i.e. every edge should be provided in
+/// both directions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CSRGraph {
+    /// The number of nodes in the graph.
+    number_of_nodes: usize,
+    /// The number of edges in the graph.
+    number_of_edges: usize,
+    /// The number of node labels in the graph.
+    number_of_node_labels: u8,
+    /// The node labels of the graph.
+    node_labels: Vec<u8>,
+    /// The offsets of the graph.
+    offsets: Vec<usize>,
+    /// The edges of the graph.
+    edges: Vec<usize>,
+}
+
+/// Returns a reader over the provided file, decompressing it if gzipped.
+///
+/// # Arguments
+/// * `path` - The path to the file.
+///
+/// # Implementation details
+/// With the `gzip` feature, the files with a `.gz` extension are decompressed
+/// while being read, so that they never need to be decompressed to disk.
+fn open_file(path: &str) -> Result<Box<dyn Read>, String> {
+    let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+    #[cfg(feature = "gzip")]
+    if path.ends_with(".gz") {
+        return Ok(Box::new(flate2::read::MultiGzDecoder::new(
+            std::io::BufReader::new(file),
+        )));
+    }
+    Ok(Box::new(std::io::BufReader::new(file)))
+}
+
+/// Iterates over the rows of the provided headerless csv file, parsed as numbers.
+///
+/// # Arguments
+/// * `path` - The path to the csv file, which may be gzipped as described in [`open_file`].
+///
+/// # Implementation details
+/// The rows are read one at a time, so that the file is never fully loaded into memory.
+fn iter_csv(path: &str) -> Result<impl Iterator<Item = Result<Vec<usize>, String>>, String> {
+    let reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .from_reader(open_file(path)?);
+    Ok(reader.into_records().map(|record| {
+        record
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .map(|value| value.parse::<usize>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<usize>, String>>()
+    }))
+}
+
+/// Returns the rows of the provided headerless csv file, parsed as numbers.
+///
+/// # Arguments
+/// * `path` - The path to the csv file, which may be gzipped as described in [`open_file`].
+fn read_csv(path: &str) -> Result<Vec<Vec<usize>>, String> {
+    iter_csv(path)?.collect()
+}
+
+/// Returns the provided numeric node label, checking that it fits a `u8`.
+///
+/// # Arguments
+/// * `node_label` - The node label to convert.
+fn to_node_label(node_label: usize) -> Result<u8, String> {
+    u8::try_from(node_label)
+        .ok()
+        .filter(|&node_label| node_label < u8::MAX)
+        .ok_or_else(|| {
+            format!(
+                "The node label {} is not supported, as node labels must be smaller than {}.",
+                node_label,
+                u8::MAX
+            )
+        })
+}
+
+/// Checks that the provided node labels are all smaller than `u8::MAX`.
+///
+/// # Arguments
+/// * `node_labels` - The node labels to check.
+fn check_node_labels(node_labels: &[u8]) -> Result<(), String> {
+    match node_labels.iter().find(|&&label| label == u8::MAX) {
+        Some(&node_label) => Err(format!(
+            "The node label {} is not supported, as node labels must be smaller than {}.",
+            node_label,
+            u8::MAX
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Returns the source and destination of the provided edge list row.
+///
+/// # Arguments
+/// * `row` - The edge list row to convert.
+fn to_edge(row: Vec<usize>) -> Result<(usize, usize), String> {
+    if row.len() != 2 {
+        return Err(format!(
+            "Expected the edge list rows to have two columns, source and destination, but found {:?}.",
+            row
+        ));
+    }
+    Ok((row[0], row[1]))
+}
+
+/// Returns the node labels read from the provided node list, with a label per row.
+///
+/// # Arguments
+/// * `node_list_path` - The path to the node list.
+fn read_node_labels(node_list_path: &str) -> Result<Vec<u8>, String> {
+    read_csv(node_list_path)?
+        .into_iter()
+        .map(|row| {
+            if row.len() != 1 {
+                return Err(format!(
+                    "Expected the node list rows to have a single column, the label, but found {:?}.",
+                    row
+                ));
+            }
+            to_node_label(row[0])
+        })
+        .collect()
+}
+
+/// Returns the edges read from the provided edge list, with a source and destination per row.
+///
+/// # Arguments
+/// * `edge_list_path` - The path to the edge list.
+fn read_edges(edge_list_path: &str) -> Result<Vec<(usize, usize)>, String> {
+    read_csv(edge_list_path)?.into_iter().map(to_edge).collect()
+}
+
+/// Returns the edges read from the provided Matrix Market file, with 0-based node IDs.
+///
+/// # Arguments
+/// * `matrix_path` - The path to the Matrix Market file, which may be gzipped as described in [`open_file`].
+/// * `number_of_nodes` - The expected number of rows and columns of the matrix.
+fn read_mtx_edges(
+    matrix_path: &str,
+    number_of_nodes: usize,
+) -> Result<Vec<(usize, usize)>, String> {
+    let mut content = String::new();
+    open_file(matrix_path)?
+        .read_to_string(&mut content)
+        .map_err(|e| e.to_string())?;
+    let mut lines = content.lines();
+
+    let header = lines
+        .next()
+        .ok_or_else(|| format!("The Matrix Market file {} is empty.", matrix_path))?
+        .to_lowercase();
+    let header = header.split_whitespace().collect::<Vec<&str>>();
+    if header.len() != 5 || header[0] != "%%matrixmarket" || header[1] != "matrix" {
+        return Err(format!(
+            "Expected the Matrix Market header `%%MatrixMarket matrix coordinate <field> <symmetry>`, but found {:?}.",
+            header
+        ));
+    }
+    if header[2] != "coordinate" {
+        return Err(format!(
+            "Only the coordinate Matrix Market format is supported, but found {}.",
+            header[2]
+        ));
+    }
+    let is_symmetric = match header[4] {
+        "general" => false,
+        "symmetric" | "skew-symmetric" | "hermitian" => true,
+        symmetry => {
+            return Err(format!(
+                "The Matrix Market symmetry {} is not supported.",
+                symmetry
+            ))
+        }
+    };
+
+    let mut rows = lines
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('%'))
+        .map(|line| {
+            line.split_whitespace()
+                .take(2)
+                .map(|value| value.parse::<usize>().map_err(|e| e.to_string()))
+                .collect::<Result<Vec<usize>, String>>()
+        });
+
+    let size = rows
+        .next()
+        .ok_or_else(|| format!("The Matrix Market file {} has no size line.", matrix_path))??;
+    if size.len() != 2 || size[0] != number_of_nodes || size[1] != number_of_nodes {
+        return Err(format!(
+            "Expected a {} by {} matrix, one row and column per node, but found the size {:?}.",
+            number_of_nodes, number_of_nodes, size
+        ));
+    }
+
+    let mut edges = Vec::new();
+    for row in rows {
+        let (src, dst) = to_edge(row?)?;
+        if src == 0 || dst == 0 {
+            return Err(format!(
+                "The Matrix Market entries are 1-based, but found the entry ({}, {}).",
+                src, dst
+            ));
+        }
+        edges.push((src - 1, dst - 1));
+        if is_symmetric {
+            edges.push((dst - 1, src - 1));
+        }
+    }
+    Ok(edges)
+}
+
+impl CSRGraph {
+    /// Create a new CSRGraph from the provided node list and edge list.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// We expect the node list to be a csv file containing a single column
+    /// with the NUMERIC node labels. The length of the column must be equal
+    /// to the number of nodes in the graph. An example of the node list is
+    /// the following:
+    ///
+    /// ```csv
+    /// 0
+    /// 1
+    /// 2
+    /// 1
+    /// 4
+    /// ```
+    ///
+    /// The edge list must be a csv file containing two columns with the NUMERIC
+    /// source and destination node IDs. An example of the edge list is the
+    /// following:
+    ///
+    /// ```csv
+    /// 0,1
+    /// 1,2
+    /// 2,3
+    /// 1,4
+    /// 4,5
+    /// ```
+    ///
+    /// With the `gzip` feature, the files ending in `.gz` are decompressed while
+    /// being read. See [`CSRGraph::from_edges`] for how the edges are validated.
+    pub fn from_csv(node_list_path: &str, edge_list_path: &str) -> Result<Self, String> {
+        let node_labels = read_node_labels(node_list_path)?;
+        let edges = read_edges(edge_list_path)?;
+
+        Self::from_edges(node_labels, edges)
+    }
+
+    /// Create a new CSRGraph from the provided node list and sorted edge list, streaming the edges.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// The files follow the same format of [`CSRGraph::from_csv`], but the edges must
+    /// be sorted by source and then by destination. The edges are then appended to the
+    /// CSR as they are read, without ever buffering the edge list, so that the memory
+    /// peak is that of the graph itself. The duplicated edges are skipped.
+    ///
+    /// # Raises
+    /// * If an edge is smaller than the edge preceding it.
+    /// * See [`CSRGraph::from_edges`].
+    pub fn from_sorted_csv_streaming(
+        node_list_path: &str,
+        edge_list_path: &str,
+    ) -> Result<Self, String> {
+        let node_labels = read_node_labels(node_list_path)?;
+        check_node_labels(&node_labels)?;
+
+        let number_of_nodes = node_labels.len();
+        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
+        offsets.push(0);
+        let mut destinations = Vec::new();
+        let mut last_edge = None;
+        for row in iter_csv(edge_list_path)? {
+            let (src, dst) = to_edge(row?)?;
+            if src >= number_of_nodes || dst >= number_of_nodes {
+                return Err(format!(
+                    "The edge ({}, {}) has an endpoint not smaller than the number of nodes {}.",
+                    src, dst, number_of_nodes
+                ));
+            }
+            if src == dst {
+                return Err(format!(
+                    "Self-loops are not supported, found one on node {}.",
+                    src
+                ));
+            }
+            match last_edge {
+                Some(last_edge) if last_edge == (src, dst) => continue,
+                Some(last_edge) if last_edge > (src, dst) => {
+                    return Err(format!(
+                        "The edge list is not sorted, as the edge ({}, {}) follows the edge {:?}.",
+                        src, dst, last_edge
+                    ));
+                }
+                _ => {}
+            }
+            last_edge = Some((src, dst));
+            while offsets.len() <= src {
+                offsets.push(destinations.len());
+            }
+            destinations.push(dst);
+        }
+        // We insert the offsets relative to eventual trailing singleton nodes.
+        while offsets.len() <= number_of_nodes {
+            offsets.push(destinations.len());
+        }
+        destinations.shrink_to_fit();
+
+        Ok(Self {
+            number_of_nodes,
+            number_of_edges: destinations.len(),
+            number_of_node_labels: node_labels.iter().max().map_or(0, |&label| label + 1),
+            node_labels,
+            offsets,
+            edges: destinations,
+        })
+    }
+
+    /// Create a new CSRGraph from the provided Matrix Market adjacency matrix and node list.
+    ///
+    /// # Arguments
+    /// * `matrix_path` - The path to the Matrix Market file.
+    /// * `node_list_path` - The path to the node list.
+    ///
+    /// # Implementation details
+    /// The node list follows the same format of [`CSRGraph::from_csv`]. The matrix
+    /// must be in the Matrix Market coordinate format, with a row and a column per
+    /// node and an entry per edge, whose 1-based row and column are the source and
+    /// destination nodes. An example of the matrix is the following:
+    ///
+    /// ```text
+    /// %%MatrixMarket matrix coordinate pattern symmetric
+    /// % Comments start with a percent sign.
+    /// 3 3 2
+    /// 2 1
+    /// 3 2
+    /// ```
+    ///
+    /// The values of the entries, if any, are ignored, as the graph is not weighted.
+    /// Symmetric matrices only store one triangle, and their entries are added in
+    /// both directions, while general matrices should store each edge in both directions.
+    ///
+    /// # Raises
+    /// * If the header is not that of a coordinate Matrix Market file.
+    /// * If the matrix size does not match the number of nodes.
+    /// * See [`CSRGraph::from_edges`].
+    pub fn from_mtx(matrix_path: &str, node_list_path: &str) -> Result<Self, String> {
+        let node_labels = read_node_labels(node_list_path)?;
+        let edges = read_mtx_edges(matrix_path, node_labels.len())?;
+
+        Self::from_edges(node_labels, edges)
+    }
+
+    /// Create a new CSRGraph from the provided edge list, with all the nodes sharing a label.
+    ///
+    /// # Arguments
+    /// * `edge_list_path` - The path to the edge list.
+    /// * `default_label` - The label of every node.
+    ///
+    /// # Implementation details
+    /// The edge list follows the same format of [`CSRGraph::from_csv`], and the number
+    /// of nodes is the largest node ID plus one, so that the nodes without edges that
+    /// follow the largest node ID are lost. With a single label, the heterogeneous
+    /// graphlets reduce to the classic homogeneous ones.
+    ///
+    /// # Raises
+    /// * See [`CSRGraph::from_edges`].
+    pub fn from_edge_list(edge_list_path: &str, default_label: u8) -> Result<Self, String> {
+        let edges = read_edges(edge_list_path)?;
+        let number_of_nodes = edges
+            .iter()
+            .map(|&(src, dst)| src.max(dst) + 1)
+            .max()
+            .unwrap_or(0);
+
+        Self::from_edges(vec![default_label; number_of_nodes], edges)
+    }
+
+    /// Create a new CSRGraph from the provided node list and edge list, dropping the self-loops.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// The files follow the same format of [`CSRGraph::from_csv`], and the self-loops
+    /// are dropped as done by [`CSRGraph::from_edges_dropping_self_loops`].
+    pub fn from_csv_dropping_self_loops(
+        node_list_path: &str,
+        edge_list_path: &str,
+    ) -> Result<Self, String> {
+        let node_labels = read_node_labels(node_list_path)?;
+        let edges = read_edges(edge_list_path)?;
+
+        Self::from_edges_dropping_self_loops(node_labels, edges)
+    }
+
+    /// Create a new CSRGraph from the provided node list with explicit node IDs and edge list.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// We expect the node list to be a csv file containing two columns with the
+    /// NUMERIC node IDs and the NUMERIC node labels. The node IDs do not need to be
+    /// dense nor sorted: they are remapped to the range `0..number_of_nodes` following
+    /// their sorted order. An example of the node list is the following:
+    ///
+    /// ```csv
+    /// 7,1
+    /// 2,0
+    /// 40,2
+    /// ```
+    ///
+    /// The edge list must be a csv file containing two columns with the NUMERIC
+    /// source and destination node IDs, as they appear in the node list.
+    ///
+    pub fn from_csv_with_node_id_column(
+        node_list_path: &str,
+        edge_list_path: &str,
+    ) -> Result<Self, String> {
+        let mut node_list = read_csv(node_list_path)?
+            .into_iter()
+            .map(|row| {
+                if row.len() != 2 {
+                    return Err(format!(
+                        "Expected the node list rows to have two columns, node ID and label, but found {:?}.",
+                        row
+                    ));
+                }
+                Ok((row[0], to_node_label(row[1])?))
+            })
+            .collect::<Result<Vec<(usize, u8)>, String>>()?;
+        node_list.sort_unstable();
+
+        let node_ids = node_list
+            .iter()
+            .enumerate()
+            .map(|(node, (node_id, _))| (*node_id, node))
+            .collect::<HashMap<usize, usize>>();
+
+        if node_ids.len() != node_list.len() {
+            return Err("The node list contains duplicated node IDs.".to_string());
+        }
+
+        let to_node = |node_id: usize| {
+            node_ids
+                .get(&node_id)
+                .copied()
+                .ok_or_else(|| format!("The node ID {} does not appear in the node list.", node_id))
+        };
+
+        let edges = read_csv(edge_list_path)?
+            .into_iter()
+            .map(|row| {
+                let (src, dst) = to_edge(row)?;
+                Ok((to_node(src)?, to_node(dst)?))
+            })
+            .collect::<Result<Vec<(usize, usize)>, String>>()?;
+
+        Self::from_edges(
+            node_list.into_iter().map(|(_, label)| label).collect(),
+            edges,
+        )
+    }
+
+    /// Create a new CSRGraph from the provided node list and directed edge list.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// The files follow the same format of [`CSRGraph::from_csv`], and the edges
+    /// are symmetrized as done by [`CSRGraph::from_directed_edges`].
+    pub fn from_directed_csv(node_list_path: &str, edge_list_path: &str) -> Result<Self, String> {
+        let graph = Self::from_csv(node_list_path, edge_list_path)?;
+        Self::from_directed_edges(graph.node_labels.clone(), graph.iter_edges().collect())
+    }
+
+    /// Create a new undirected CSRGraph from the provided node labels and directed edges.
+    ///
+    /// # Arguments
+    /// * `node_labels` - The label of each node, where the i-th label is the label of node i.
+    /// * `edges` - The directed edges, as pairs of source and destination node IDs.
+    ///
+    /// # Implementation details
+    /// The graphlet counting treats the graph as undirected, intersecting the
+    /// neighbourhoods of both nodes of each edge, and therefore requires each edge
+    /// to appear in both directions. Directed graphs, such as citation graphs, are
+    /// thus symmetrized by adding the reverse of each edge, while the edges already
+    /// provided in both directions are kept once. The direction of the edges is lost,
+    /// and the graphlets are those of the underlying undirected graph: graphs that
+    /// need the direction of the edges should be loaded as a [`DirectedCSRGraph`].
+    ///
+    /// # Raises
+    /// * See [`CSRGraph::from_edges`].
+    pub fn from_directed_edges(
+        node_labels: Vec<u8>,
+        edges: Vec<(usize, usize)>,
+    ) -> Result<Self, String> {
+        let edges = edges
+            .into_iter()
+            .flat_map(|(src, dst)| [(src, dst), (dst, src)])
+            .collect();
+        Self::from_edges(node_labels, edges)
+    }
+
+    /// Create a new CSRGraph from the provided node labels and edges, dropping the self-loops.
+    ///
+    /// # Arguments
+    /// * `node_labels` - The label of each node, where the i-th label is the label of node i.
+    /// * `edges` - The directed edges, as pairs of source and destination node IDs.
+    ///
+    /// # Implementation details
+    /// The graphlets do not include self-loops, so that dropping them yields the same
+    /// counts of the graph with its self-loops skipped, as done by [`SelfLoopFreeGraph`].
+    ///
+    /// # Raises
+    /// * See [`CSRGraph::from_edges`], except for the self-loops.
+    pub fn from_edges_dropping_self_loops(
+        node_labels: Vec<u8>,
+        mut edges: Vec<(usize, usize)>,
+    ) -> Result<Self, String> {
+        edges.retain(|(src, dst)| src != dst);
+        Self::from_edges(node_labels, edges)
+    }
+
+    /// Create a new CSRGraph from the provided node labels and edges.
+    ///
+    /// # Arguments
+    /// * `node_labels` - The label of each node, where the i-th label is the label of node i.
+    /// * `edges` - The directed edges, as pairs of source and destination node IDs.
+    ///
+    /// # Implementation details
+    /// The edges are sorted and deduplicated, so that the neighbours of each node
+    /// are sorted and unique. Undirected graphs should provide each edge in both
+    /// directions, and directed graphs should be loaded with
+    /// [`CSRGraph::from_directed_edges`].
+    ///
+    /// # Raises
+    /// * If a node label is not smaller than `u8::MAX`.
+    /// * If an edge endpoint is not smaller than the number of nodes.
+    /// * If an edge is a self-loop.
+    pub fn from_edges(
+        node_labels: Vec<u8>,
+        mut edges: Vec<(usize, usize)>,
+    ) -> Result<Self, String> {
+        check_node_labels(&node_labels)?;
+
+        let number_of_nodes = node_labels.len();
+        for &(src, dst) in edges.iter() {
+            if src >= number_of_nodes || dst >= number_of_nodes {
+                return Err(format!(
+                    "The edge ({}, {}) has an endpoint not smaller than the number of nodes {}.",
+                    src, dst, number_of_nodes
+                ));
+            }
+            if src == dst {
+                return Err(format!(
+                    "Self-loops are not supported, found one on node {}.",
+                    src
+                ));
+            }
+        }
+
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
+        offsets.push(0);
+        let mut destinations = Vec::with_capacity(edges.len());
+        for (src, dst) in edges {
+            while offsets.len() <= src {
+                offsets.push(destinations.len());
+            }
+            destinations.push(dst);
+        }
+        // We insert the offsets relative to eventual trailing singleton nodes.
+        while offsets.len() <= number_of_nodes {
+            offsets.push(destinations.len());
+        }
+
+        let csr = Self {
+            number_of_nodes,
+            number_of_edges: destinations.len(),
+            number_of_node_labels: node_labels.iter().max().map_or(0, |&label| label + 1),
+            node_labels,
+            offsets,
+            edges: destinations,
+        };
+
+        // We check the invariant the graphlet counting relies upon.
+        for node in 0..number_of_nodes {
+            let neighbours = csr.get_neighbours(node);
+            if !neighbours.windows(2).all(|window| window[0] < window[1]) {
+                return Err(format!(
+                    "The neighbours of node {} are not sorted and unique: {:?}.",
+                    node, neighbours
+                ));
+            }
+        }
+
+        Ok(csr)
+    }
+
+    /// Returns the sorted neighbours of the provided node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose neighbours should be returned.
+    pub fn get_neighbours(&self, node: usize) -> &[usize] {
+        &self.edges[self.offsets[node]..self.offsets[node + 1]]
+    }
+
+    /// Iterates over the edges.
+    pub fn iter_edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.number_of_nodes).flat_map(move |node| {
+            self.get_neighbours(node)
+                .iter()
+                .map(move |dst| (node, *dst))
+        })
+    }
+
+    /// Iterates in parallel over the edges, starting from the most expensive ones.
+    ///
+    /// # Implementation details
+    /// The cost of an edge is estimated as the product of the degrees of its
+    /// source and destination nodes. The edges are sorted by decreasing cost
+    /// and each edge is a separate rayon task: idle workers steal the remaining
+    /// edges, so that a few expensive edges do not stall the worker that would
+    /// have received them as part of an equally-sized chunk.
+    pub fn par_iter_edges_by_cost(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
+        let mut edges = self.iter_edges().collect::<Vec<_>>();
+        edges.par_sort_unstable_by_key(|&(src, dst)| {
+            std::cmp::Reverse(self.get_node_degree(src) * self.get_node_degree(dst))
+        });
+        edges.into_par_iter().with_max_len(1)
+    }
+}
+
+impl Graph for CSRGraph {
+    type Node = usize;
+    type NeighbourIter<'a> = std::iter::Copied<std::slice::Iter<'a, usize>>;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.number_of_nodes
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.number_of_edges
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.get_neighbours(node).iter().copied()
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.get_neighbours(node).binary_search(&other).is_ok()
+    }
+}
+
+impl ParallelGraph for CSRGraph {
+    fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
+        self.par_iter_nodes().flat_map(move |node| {
+            self.get_neighbours(node)
+                .par_iter()
+                .map(move |dst| (node, *dst))
+        })
+    }
+}
+
+impl TypedGraph for CSRGraph {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.number_of_node_labels
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels as usize
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index as u8
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label as usize
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl HeterogeneousGraphlets<u16, u32> for CSRGraph {
+    type GraphLetCounter = HashMap<u16, u32>;
+}
+
+/// Compressed Sparse Row Graph whose edges have a direction.
+///
+/// # Implementation details
+/// The graphlets are counted on the underlying undirected graph, stored as a
+/// [`CSRGraph`] with the edges symmetrized as done by [`CSRGraph::from_directed_edges`],
+/// while the out and in neighbours of each node are stored in two further graphs
+/// holding the edges in their own direction and reversed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DirectedCSRGraph {
+    /// The underlying undirected graph.
+    graph: CSRGraph,
+    /// The graph with the edges in their direction, holding the out neighbours.
+    out_neighbours: CSRGraph,
+    /// The graph with the edges reversed, holding the in neighbours.
+    in_neighbours: CSRGraph,
+}
+
+impl DirectedCSRGraph {
+    /// Create a new DirectedCSRGraph from the provided node list and directed edge list.
+    ///
+    /// # Arguments
+    /// * `node_list_path` - The path to the node list.
+    /// * `edge_list_path` - The path to the edge list.
+    ///
+    /// # Implementation details
+    /// The files follow the same format of [`CSRGraph::from_csv`], with each edge
+    /// going from the node in the first column to the node in the second one.
+    pub fn from_csv(node_list_path: &str, edge_list_path: &str) -> Result<Self, String> {
+        let graph = CSRGraph::from_csv(node_list_path, edge_list_path)?;
+        Self::from_edges(graph.node_labels.clone(), graph.iter_edges().collect())
+    }
+
+    /// Create a new DirectedCSRGraph from the provided node labels and directed edges.
+    ///
+    /// # Arguments
+    /// * `node_labels` - The label of each node, where the i-th label is the label of node i.
+    /// * `edges` - The directed edges, as pairs of source and destination node IDs.
+    ///
+    /// # Raises
+    /// * See [`CSRGraph::from_edges`].
+    pub fn from_edges(node_labels: Vec<u8>, edges: Vec<(usize, usize)>) -> Result<Self, String> {
+        let reversed_edges = edges.iter().map(|&(src, dst)| (dst, src)).collect();
+        Ok(Self {
+            graph: CSRGraph::from_directed_edges(node_labels.clone(), edges.clone())?,
+            out_neighbours: CSRGraph::from_edges(node_labels.clone(), edges)?,
+            in_neighbours: CSRGraph::from_edges(node_labels, reversed_edges)?,
+        })
+    }
+
+    /// Returns the underlying undirected graph.
+    pub fn as_undirected(&self) -> &CSRGraph {
+        &self.graph
+    }
+}
+
+impl Graph for DirectedCSRGraph {
+    type Node = usize;
+    type NeighbourIter<'a> = <CSRGraph as Graph>::NeighbourIter<'a>;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    /// Returns the number of edges of the underlying undirected graph, in both directions.
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl DirectedGraph for DirectedCSRGraph {
+    type OutNeighbourIter<'a> = <CSRGraph as Graph>::NeighbourIter<'a>;
+    type InNeighbourIter<'a> = <CSRGraph as Graph>::NeighbourIter<'a>;
+
+    fn iter_out_neighbours(&self, node: usize) -> Self::OutNeighbourIter<'_> {
+        self.out_neighbours.iter_neighbours(node)
+    }
+
+    fn iter_in_neighbours(&self, node: usize) -> Self::InNeighbourIter<'_> {
+        self.in_neighbours.iter_neighbours(node)
+    }
+
+    fn has_directed_edge(&self, src: usize, dst: usize) -> bool {
+        self.out_neighbours.is_neighbour(src, dst)
+    }
+}
+
+impl ParallelGraph for DirectedCSRGraph {
+    fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
+        self.graph.par_iter_edges()
+    }
+}
+
+impl TypedGraph for DirectedCSRGraph {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl HeterogeneousGraphlets<u16, u32> for DirectedCSRGraph {
+    type GraphLetCounter = HashMap<u16, u32>;
+}
@@ -1,15 +1,53 @@
-use std::{
-    collections::HashMap,
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use core::marker::PhantomData;
+use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Mul},
 };
+#[cfg(feature = "std")]
+use std::{collections::HashMap, io::Write};
 
 use crate::{
-    graphlet_set::GraphletSet,
+    graphlet_set::{canonical_labels, ExtendedGraphletType, GraphletSet, ReducedGraphletType},
     numbers::{One, Primitive, Zero},
     perfect_graphlet_hash::*,
 };
 
+#[cfg(feature = "serde")]
+use crate::graphlet_report::GraphletReport;
+
+/// Iterator copying the graphlets and counts yielded by an iterator over their references.
+pub type OwnedGraphletsIter<'a, I, Graphlet, Count> =
+    core::iter::Map<I, fn((&'a Graphlet, &'a Count)) -> (Graphlet, Count)>;
+
+/// A graphlet decoded into its kind and label quadruple, paired with its count.
+pub type DecodedGraphlet<Element, Count> = (
+    ExtendedGraphletType,
+    (Element, Element, Element, Element),
+    Count,
+);
+
+/// The canonical graphlet counter, a sparse map from the graphlets to their counts.
+///
+/// This is the counter to use in the implementations of
+/// [`HeterogeneousGraphlets`](crate::prelude::HeterogeneousGraphlets), unless the
+/// graphs have few enough node labels for a [`DenseGraphletCounter`].
+#[cfg(feature = "std")]
+pub type DefaultCounter<Graphlet = usize, Count = usize> = HashMap<Graphlet, Count>;
+
+/// The canonical graphlet counter, a sparse map from the graphlets to their counts.
+///
+/// Without the `std` feature, the hash maps are not available, and the counter
+/// is an ordered map instead.
+#[cfg(not(feature = "std"))]
+pub type DefaultCounter<Graphlet = usize, Count = usize> = BTreeMap<Graphlet, Count>;
+
 /// Trait defining characteristics of a set of graphlets.
 ///
 /// Many implementations are possible for this trait depending
@@ -19,9 +57,10 @@ where
     Count: Debug + One,
     Graphlet: Debug + Copy + Mul<Output = Graphlet> + Add<Output = Graphlet>,
 {
-    type Iter<'a>: Iterator<Item = (Graphlet, Count)> + 'a
+    type RefIter<'a>: Iterator<Item = (&'a Graphlet, &'a Count)> + 'a
     where
         Self: 'a,
+        Graphlet: 'a,
         Count: 'a;
 
     /// Inserts the provided graphlet into the graphlet set.
@@ -45,12 +84,209 @@ where
     /// * `graphlet` - The graphlet whose number of occurrences should be returned.
     fn get_number_of_graphlets(&self, graphlet: Graphlet) -> Count;
 
-    /// Iterate over the graphlets and their counts.
-    fn iter_graphlets_and_counts<'a>(&'a self) -> Self::Iter<'a>
+    /// Removes the provided graphlet from the graphlet set, returning its count.
+    ///
+    /// # Arguments
+    /// * `graphlet` - The graphlet to remove from the graphlet set.
+    fn remove(&mut self, graphlet: Graphlet) -> Count;
+
+    /// Removes all the graphlets from the graphlet set, keeping the allocated memory.
+    ///
+    /// # Implementation details
+    /// The capacity of the `HashMap` counter is preserved, and the entries of the
+    /// [`DenseGraphletCounter`] are set to zero without shrinking its vector, so that
+    /// a counter reused across edges, as done together with a [`GraphletScratch`](crate::prelude::GraphletScratch)
+    /// by [`HeterogeneousGraphlets::get_heterogeneous_graphlet_into`](crate::prelude::HeterogeneousGraphlets::get_heterogeneous_graphlet_into),
+    /// does not allocate once it has grown to the graphlets of the edges. The `BTreeMap`
+    /// counter has no capacity, and frees its nodes.
+    fn clear(&mut self);
+
+    /// Adds the counts of the provided counter to the ones of this counter.
+    ///
+    /// # Arguments
+    /// * `other` - The counter whose counts should be added.
+    ///
+    /// # Implementation details
+    /// The counts of the graphlets found in both counters are summed, as are the
+    /// examined node quadruples. This differs from `HashMap::extend`, which replaces
+    /// the counts of the graphlets found in both counters with the ones of `other`.
+    fn merge(&mut self, other: &Self)
+    where
+        Self: Sized,
+        Count: Clone + Zero,
+    {
+        for (graphlet, count) in other.iter_graphlets_and_counts() {
+            self.insert_count(graphlet, count);
+        }
+        self.record_examined(other.examined());
+    }
+
+    /// Adds the provided number to the node quadruples examined while counting.
+    ///
+    /// # Arguments
+    /// * `n` - The number of newly examined node quadruples.
+    ///
+    /// # Implementation details
+    /// Tracking the examined quadruples is optional, and by default they are discarded.
+    fn record_examined(&mut self, _n: Count) {}
+
+    /// Returns the number of node quadruples examined while counting.
+    ///
+    /// # Implementation details
+    /// This is the denominator of the frequency of the four-node graphlet kinds.
+    /// Counters that do not track the examined quadruples return zero.
+    fn examined(&self) -> Count
+    where
+        Count: Zero,
+    {
+        Count::ZERO
+    }
+
+    /// Iterate over references to the graphlets and their counts.
+    ///
+    /// # Implementation details
+    /// Differently from [`iter_graphlets_and_counts`](GraphLetCounter::iter_graphlets_and_counts),
+    /// the counts are not copied, which is preferable when the counts are large aggregates.
+    fn iter_graphlets_and_counts_ref<'a>(&'a self) -> Self::RefIter<'a>
     where
         Self: 'a,
+        Graphlet: 'a,
         Count: 'a;
 
+    /// Iterate over the graphlets and their counts.
+    fn iter_graphlets_and_counts<'a>(
+        &'a self,
+    ) -> OwnedGraphletsIter<'a, Self::RefIter<'a>, Graphlet, Count>
+    where
+        Self: 'a,
+        Graphlet: 'a,
+        Count: Clone + 'a,
+    {
+        self.iter_graphlets_and_counts_ref()
+            .map(|(graphlet, count)| (*graphlet, count.clone()))
+    }
+
+    /// Returns the sum of the counts of all the graphlets.
+    fn total_count(&self) -> Count
+    where
+        Count: Zero + AddAssign + Copy,
+    {
+        let mut total = Count::ZERO;
+        for (_, &count) in self.iter_graphlets_and_counts_ref() {
+            total += count;
+        }
+        total
+    }
+
+    /// Returns the sum of the counts of the graphlets of the provided kind, over all their labels.
+    ///
+    /// # Arguments
+    /// * `graphlet_kind` - The graphlet kind whose count should be returned.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Solely the graphlet kind of each hash is decoded, with the
+    /// [`decode_graphlet_kind`](GraphletHashLayout::decode_graphlet_kind) of the provided
+    /// layout, which must be the one the graphlets were encoded with.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "csr")]
+    /// # {
+    /// use heterogeneous_graphlets::prelude::*;
+    /// use heterogeneous_graphlets::perfect_graphlet_hash::PolynomialLayout;
+    /// use std::collections::HashMap;
+    ///
+    /// let graph = CSRGraph::from_csv(
+    ///     "tests/data/four_path/node_list.csv",
+    ///     "tests/data/four_path/edge_list.csv",
+    /// )
+    /// .unwrap();
+    /// let counts: HashMap<u16, u32> = graph.count_all_graphlets();
+    /// let number_of_elements = graph.get_number_of_node_labels();
+    ///
+    /// assert_eq!(counts.total_count(), 7);
+    /// assert_eq!(counts.total_for_kind::<_, PolynomialLayout>(ExtendedGraphletType::Triad, number_of_elements), 4);
+    /// assert_eq!(counts.total_for_kind::<_, PolynomialLayout>(ExtendedGraphletType::FourClique, number_of_elements), 0);
+    /// # }
+    /// ```
+    fn total_for_kind<Element, Layout>(
+        &self,
+        graphlet_kind: ExtendedGraphletType,
+        number_of_elements: Element,
+    ) -> Count
+    where
+        Element: Copy,
+        Count: Zero + AddAssign + Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut total = Count::ZERO;
+        for (&graphlet, &count) in self.iter_graphlets_and_counts_ref() {
+            if Layout::decode_graphlet_kind::<ExtendedGraphletType>(graphlet, number_of_elements)
+                == graphlet_kind
+            {
+                total += count;
+            }
+        }
+        total
+    }
+
+    /// Returns the sum of the counts of the graphlets of each kind, over all their labels.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// The i-th entry is the count of the graphlets of kind `ExtendedGraphletType::from(i as u8)`,
+    /// and, as for [`total_for_kind`](GraphLetCounter::total_for_kind), solely the graphlet
+    /// kind of each hash is decoded with the provided layout.
+    fn get_kind_counts<Element, Layout>(
+        &self,
+        number_of_elements: Element,
+    ) -> [Count; ExtendedGraphletType::COUNT]
+    where
+        Element: Copy,
+        Count: Zero + AddAssign + Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut kind_counts = [Count::ZERO; ExtendedGraphletType::COUNT];
+        for (&graphlet, &count) in self.iter_graphlets_and_counts_ref() {
+            let graphlet_kind =
+                Layout::decode_graphlet_kind::<ExtendedGraphletType>(graphlet, number_of_elements);
+            kind_counts[graphlet_kind.orbit_index()] += count;
+        }
+        kind_counts
+    }
+
+    /// Returns whether the counts of the two counters are equal within the provided tolerance.
+    ///
+    /// # Arguments
+    /// * `other` - The counter to compare against.
+    /// * `epsilon` - The largest absolute difference between two counts deemed equal.
+    ///
+    /// # Implementation details
+    /// The graphlets missing from one of the counters are treated as having a zero count.
+    /// This is meant for floating-point counts, such as normalized frequencies, while for
+    /// integer counts a zero tolerance is equivalent to exact equality.
+    fn counts_approx_equal(&self, other: &Self, epsilon: f64) -> bool
+    where
+        Count: Copy,
+        f64: Primitive<Count>,
+    {
+        let is_close = |count: Count, other_count: Count| {
+            (f64::convert(count) - f64::convert(other_count)).abs() <= epsilon
+        };
+        self.iter_graphlets_and_counts_ref()
+            .all(|(&graphlet, &count)| is_close(count, other.get_number_of_graphlets(graphlet)))
+            && other
+                .iter_graphlets_and_counts_ref()
+                .all(|(&graphlet, &count)| is_close(count, self.get_number_of_graphlets(graphlet)))
+    }
+
     /// Create new counter object with given number of elements.
     ///
     /// # Arguments
@@ -59,60 +295,514 @@ where
     fn with_number_of_elements<Element>(number_of_elements: Element) -> Self;
 
     /// Returns extensive report describing the graphlet set.
-    fn get_report<GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>, Element>(
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each line of the report contains the graphlet kind, its label quadruple and
+    /// its count, as in `FourClique(0, 1, 1, 2): 12`. As for
+    /// [`iter_decoded`](GraphLetCounter::iter_decoded), the graphlets are decoded with
+    /// the provided layout, which must be the one they were encoded with, and the label
+    /// quadruples of the three-node graphlets include the dummy label used to encode
+    /// them. The counts summed over the labels are reported by
+    /// [`get_report_by_type`](GraphLetCounter::get_report_by_type).
+    fn get_report<GraphletKind, Element, Layout>(
         &self,
         number_of_elements: Element,
     ) -> Result<String, String>
     where
-        Element: Add<Element, Output = Element>
-            + Mul<Output = Element>
-            + Debug
-            + Copy
-            + One
-            + Zero
-            + Ord,
+        GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>,
+        Element: Debug + Copy,
+        Graphlet: From<GraphletKind>,
+        Count: Clone,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        self.get_report_with_label_names::<GraphletKind, Element, Layout, _>(
+            number_of_elements,
+            |label| format!("{:?}", label),
+        )
+    }
 
-        Graphlet: From<GraphletKind> + Primitive<Element>,
-        (Element, Element, Element, Element): PerfectGraphletHash<Graphlet, Element>,
+    /// Returns extensive report describing the graphlet set, with the provided label names.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    /// * `label_name` - The function rendering each label of the quadruples.
+    ///
+    /// # Implementation details
+    /// The report follows the format of [`get_report`](GraphLetCounter::get_report),
+    /// with the graphlets decoded with the provided layout and the labels rendered by
+    /// the provided function, which also receives the dummy label of the three-node
+    /// graphlets, as returned by [`GraphletHashLayout::dummy_label`].
+    fn get_report_with_label_names<GraphletKind, Element, Layout, F>(
+        &self,
+        number_of_elements: Element,
+        label_name: F,
+    ) -> Result<String, String>
+    where
+        GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>,
+        Element: Debug + Copy,
+        F: Fn(Element) -> String,
+        Graphlet: From<GraphletKind>,
+        Count: Clone,
+        Layout: GraphletHashLayout<Graphlet, Element>,
     {
         let mut report = String::new();
         for (graphlet, count) in self.iter_graphlets_and_counts() {
-            let graphlet_kind: GraphletKind =
-                <(Element, Element, Element, Element)>::decode_graphlet_kind::<GraphletKind>(
-                    graphlet,
-                    number_of_elements,
-                );
-            let graphlet_name = graphlet_kind.to_string();
-            report.push_str(&format!("{}: {:?}\n", graphlet_name, count));
+            let (graphlet_kind, (first, second, third, fourth)) =
+                Layout::decode::<GraphletKind>(graphlet, number_of_elements);
+            report.push_str(&format!(
+                "{}({}): {:?}\n",
+                graphlet_kind.to_string(),
+                [first, second, third, fourth].map(&label_name).join(", "),
+                count
+            ));
         }
         Ok(report)
     }
 
+    /// Returns a report with the counts of each graphlet kind, summed over the labels.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each line of the report contains the graphlet kind and its total count, as in
+    /// `FourClique: 12`, and the lines are sorted by the name of the graphlet kind. Solely
+    /// the graphlet kind of each hash is decoded, with the provided layout.
+    fn get_report_by_type<GraphletKind, Element, Layout>(
+        &self,
+        number_of_elements: Element,
+    ) -> Result<String, String>
+    where
+        GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>,
+        Element: Copy,
+        Graphlet: From<GraphletKind>,
+        Count: Clone + AddAssign,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut totals: BTreeMap<String, Count> = BTreeMap::new();
+        for (graphlet, count) in self.iter_graphlets_and_counts() {
+            let graphlet_name =
+                Layout::decode_graphlet_kind::<GraphletKind>(graphlet, number_of_elements)
+                    .to_string();
+            match totals.get_mut(&graphlet_name) {
+                Some(total) => *total += count,
+                None => {
+                    totals.insert(graphlet_name, count);
+                }
+            }
+        }
+        Ok(totals
+            .into_iter()
+            .map(|(graphlet_name, total)| format!("{}: {:?}\n", graphlet_name, total))
+            .collect())
+    }
+
+    /// Returns a serializable report of the graphlet set.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Differently from [`get_report`](GraphLetCounter::get_report), the graphlets
+    /// are decoded into their kinds and label quadruples, and the original counter
+    /// can be rebuilt with [`GraphletReport::to_counter`].
+    #[cfg(feature = "serde")]
+    fn get_graphlet_report<Element>(
+        &self,
+        number_of_elements: Element,
+    ) -> GraphletReport<Element, Count>
+    where
+        Self: Sized,
+        Element: Add<Element, Output = Element> + Mul<Output = Element> + Debug + Copy + Ord,
+        Count: Copy,
+        Graphlet: From<ExtendedGraphletType> + Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        [Element; 4]: NTuplePerfectHash<4, Graphlet, Element>,
+    {
+        GraphletReport::from_counter(self, number_of_elements)
+    }
+
+    /// Iterate over the graphlet counts with the graphlets decoded into their kind and labels.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// The keys are decoded lazily with the provided layout, which must be the one
+    /// they were encoded with. The label quadruples of the three-node graphlets
+    /// include the dummy label used to encode them.
+    fn iter_decoded<'a, GraphletKind, Element, Layout>(
+        &'a self,
+        number_of_elements: Element,
+    ) -> impl Iterator<Item = (GraphletKind, (Element, Element, Element, Element), Count)> + 'a
+    where
+        GraphletKind: GraphletSet<Graphlet> + From<Graphlet>,
+        Element: Copy,
+        Graphlet: From<GraphletKind>,
+        Count: Clone,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+        Element: 'a,
+        Graphlet: 'a,
+        Count: 'a,
+    {
+        self.iter_graphlets_and_counts()
+            .map(move |(graphlet, count)| {
+                let (graphlet_kind, labels) =
+                    Layout::decode::<GraphletKind>(graphlet, number_of_elements);
+                (graphlet_kind, labels, count)
+            })
+    }
+
+    /// Returns the `k` most frequent graphlets, decoded into their kind and labels.
+    ///
+    /// # Arguments
+    /// * `k` - The maximal number of graphlets to return.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// The graphlets are sorted by decreasing count, and the graphlets with the same
+    /// count by increasing hash, so that the order does not depend on the iteration
+    /// order of the counter. Incomparable counts, such as NaN, are deemed equal.
+    /// Only the returned graphlets are decoded, with the provided layout, which must
+    /// be the one they were encoded with.
+    fn top_k<Element, Layout>(
+        &self,
+        k: usize,
+        number_of_elements: Element,
+    ) -> Vec<DecodedGraphlet<Element, Count>>
+    where
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType> + Ord,
+        Count: Clone + PartialOrd,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut graphlets = self.iter_graphlets_and_counts().collect::<Vec<_>>();
+        graphlets.sort_by(|(graphlet, count), (other_graphlet, other_count)| {
+            other_count
+                .partial_cmp(count)
+                .unwrap_or(core::cmp::Ordering::Equal)
+                .then_with(|| graphlet.cmp(other_graphlet))
+        });
+        graphlets.truncate(k);
+        graphlets
+            .into_iter()
+            .map(|(graphlet, count)| {
+                let (graphlet_kind, labels) =
+                    Layout::decode::<ExtendedGraphletType>(graphlet, number_of_elements);
+                (graphlet_kind, labels, count)
+            })
+            .collect()
+    }
+
+    /// Returns the counts with the orbits of each graphlet merged into the reduced graphlets.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each key is decoded as an [`ExtendedGraphletType`] with the provided layout,
+    /// which must be the one it was encoded with, mapped to its graphlet with
+    /// [`ExtendedGraphletType::to_reduced`], and re-encoded with the same layout and
+    /// labels as a [`ReducedGraphletType`], summing the counts of the orbits that
+    /// collapse onto the same key.
+    fn fold_to_reduced<Element, Layout>(&self, number_of_elements: Element) -> Self
+    where
+        Self: Sized,
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType> + From<ReducedGraphletType>,
+        Count: Clone,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut reduced = Self::with_number_of_elements(number_of_elements);
+        for (graphlet_kind, labels, count) in
+            self.iter_decoded::<ExtendedGraphletType, Element, Layout>(number_of_elements)
+        {
+            reduced.insert_count(
+                Layout::encode(labels, graphlet_kind.to_reduced(), number_of_elements),
+                count,
+            );
+        }
+        reduced
+    }
+
+    /// Returns the counts with the labels of each graphlet in their canonical order.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each key is decoded as an [`ExtendedGraphletType`] with the provided layout,
+    /// which must be the one it was encoded with, its labels are reordered with
+    /// [`canonical_labels`], and it is re-encoded with the same layout, summing the
+    /// counts of the keys that describe the same motif. The examined node quadruples
+    /// are carried over.
+    fn canonicalize<Element, Layout>(&self, number_of_elements: Element) -> Self
+    where
+        Self: Sized,
+        Element: Copy + Ord,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone + Zero,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut canonical = Self::with_number_of_elements(number_of_elements);
+        for (graphlet_kind, labels, count) in
+            self.iter_decoded::<ExtendedGraphletType, Element, Layout>(number_of_elements)
+        {
+            canonical.insert_count(
+                Layout::encode(
+                    canonical_labels(graphlet_kind, labels),
+                    graphlet_kind,
+                    number_of_elements,
+                ),
+                count,
+            );
+        }
+        canonical.record_examined(self.examined());
+        canonical
+    }
+
+    /// Iterate over the graphlet counts as the triples of a sparse matrix in COO format.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each triple is `(row, column, count)`, where the row is the index of the
+    /// graphlet kind and the column is the label quadruple of the graphlet, encoded
+    /// as `l0 * (n+1)^3 + l1 * (n+1)^2 + l2 * (n+1) + l3`. The keys are decoded lazily
+    /// with the provided layout, and the missing fourth label of the three-node graphlets
+    /// is written as `n` whatever the dummy label of the layout, so that the matrix
+    /// always has `(n+1)^4` columns.
+    fn iter_coo<'a, Element, Layout>(
+        &'a self,
+        number_of_elements: Element,
+    ) -> impl Iterator<Item = (usize, usize, Count)> + 'a
+    where
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone,
+        usize: Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+        Element: 'a,
+        Graphlet: 'a,
+        Count: 'a,
+    {
+        let dummy_label = usize::convert(number_of_elements);
+        let base = dummy_label + 1;
+        self.iter_decoded::<ExtendedGraphletType, Element, Layout>(number_of_elements)
+            .map(move |(graphlet_kind, labels, count)| {
+                let fourth = if graphlet_kind.node_count() == 3 {
+                    dummy_label
+                } else {
+                    usize::convert(labels.3)
+                };
+                let column = [
+                    usize::convert(labels.0),
+                    usize::convert(labels.1),
+                    usize::convert(labels.2),
+                    fourth,
+                ]
+                .into_iter()
+                .fold(0, |column, label| column * base + label);
+                (u8::from(graphlet_kind) as usize, column, count)
+            })
+    }
+
+    /// Returns the graphlet counts as the triples of a sparse matrix in COO format.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// See [`iter_coo`](GraphLetCounter::iter_coo) for the layout of the triples.
+    fn to_coo<Element, Layout>(&self, number_of_elements: Element) -> Vec<(usize, usize, Count)>
+    where
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone,
+        usize: Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        self.iter_coo::<Element, Layout>(number_of_elements)
+            .collect()
+    }
+
+    /// Writes the graphlet counts as the triples of a sparse matrix in COO format.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to stream the triples to.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Each triple is written on its own line as `row,column,count` as soon as
+    /// its key is decoded, so that the triples are never held in memory.
+    /// See [`iter_coo`](GraphLetCounter::iter_coo) for the layout of the triples.
+    #[cfg(feature = "std")]
+    fn write_coo<W: Write, Element, Layout>(
+        &self,
+        mut writer: W,
+        number_of_elements: Element,
+    ) -> std::io::Result<()>
+    where
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone,
+        usize: Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        for (row, column, count) in self.iter_coo::<Element, Layout>(number_of_elements) {
+            writeln!(writer, "{},{},{:?}", row, column, count)?;
+        }
+        writer.flush()
+    }
+
+    /// Returns the relative frequency of each graphlet kind.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// The counts of the graphlets of the same kind but with different labels are summed
+    /// first, reading their kind with the provided [`GraphletHashLayout`], and each of these
+    /// totals is then divided by the sum of the counts of all the graphlets, so that the
+    /// frequencies of graphs of different sizes can be compared.
+    /// All the graphlet kinds are returned, in the order of [`ExtendedGraphletType::all`],
+    /// and their frequencies are all zero when the counter holds no graphlets.
+    ///
+    /// # Example
+    /// ```
+    /// # #[cfg(feature = "csr")]
+    /// # {
+    /// use heterogeneous_graphlets::perfect_graphlet_hash::PolynomialLayout;
+    /// use heterogeneous_graphlets::prelude::*;
+    /// use std::collections::HashMap;
+    ///
+    /// let graph = CSRGraph::from_csv(
+    ///     "tests/data/four_path/node_list.csv",
+    ///     "tests/data/four_path/edge_list.csv",
+    /// )
+    /// .unwrap();
+    /// let counts: HashMap<u16, u32> = graph.count_all_graphlets();
+    /// let frequencies =
+    ///     counts.get_normalized_report::<_, PolynomialLayout>(graph.get_number_of_node_labels());
+    ///
+    /// // The triads of the three edges and the four path seen from its end and central edges.
+    /// for (graphlet_kind, frequency) in frequencies {
+    ///     let expected_frequency = match graphlet_kind {
+    ///         ExtendedGraphletType::Triad => 4.0 / 7.0,
+    ///         ExtendedGraphletType::FourPathEdge => 2.0 / 7.0,
+    ///         ExtendedGraphletType::FourPathCenter => 1.0 / 7.0,
+    ///         _ => 0.0,
+    ///     };
+    ///     assert!((frequency - expected_frequency).abs() < 1e-12);
+    /// }
+    /// # }
+    /// ```
+    fn get_normalized_report<Element, Layout>(
+        &self,
+        number_of_elements: Element,
+    ) -> Vec<(ExtendedGraphletType, f64)>
+    where
+        Element: Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone,
+        f64: Primitive<Count>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        let mut kind_totals = [0.0_f64; ExtendedGraphletType::COUNT];
+        for (graphlet, count) in self.iter_graphlets_and_counts() {
+            let graphlet_kind =
+                Layout::decode_graphlet_kind::<ExtendedGraphletType>(graphlet, number_of_elements);
+            kind_totals[graphlet_kind.orbit_index()] += f64::convert(count);
+        }
+        let total = kind_totals.iter().sum::<f64>();
+        ExtendedGraphletType::all()
+            .map(|graphlet_kind| {
+                let kind_total = kind_totals[graphlet_kind.orbit_index()];
+                (
+                    graphlet_kind,
+                    if total > 0.0 { kind_total / total } else { 0.0 },
+                )
+            })
+            .collect()
+    }
+
+    /// Writes the graphlet counts as a csv file, with a row for each decoded graphlet.
+    ///
+    /// # Arguments
+    /// * `writer` - The writer to stream the rows to.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// The file starts with the header `graphlet_type,label_0,label_1,label_2,label_3,count`,
+    /// and each row is written as soon as its key is decoded with
+    /// [`iter_decoded`](GraphLetCounter::iter_decoded) and the provided layout, so that the
+    /// rows are never held in memory. As there, the fourth label of the three-node graphlets
+    /// is the dummy label used to encode them.
+    #[cfg(feature = "std")]
+    fn write_report_csv<W: Write, Element, Layout>(
+        &self,
+        mut writer: W,
+        number_of_elements: Element,
+    ) -> std::io::Result<()>
+    where
+        Element: Debug + Copy,
+        Graphlet: From<ExtendedGraphletType>,
+        Count: Clone,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, Element>,
+    {
+        writeln!(
+            writer,
+            "graphlet_type,label_0,label_1,label_2,label_3,count"
+        )?;
+        for (graphlet_kind, (first, second, third, fourth), count) in
+            self.iter_decoded::<ExtendedGraphletType, Element, Layout>(number_of_elements)
+        {
+            writeln!(
+                writer,
+                "{},{:?},{:?},{:?},{:?},{:?}",
+                graphlet_kind, first, second, third, fourth, count
+            )?;
+        }
+        writer.flush()
+    }
+
     /// Returns a map from graphlet names to their counts.
-    fn to_graphlet_names<GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>, Element>(
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    ///
+    /// # Implementation details
+    /// Solely the graphlet kind of each hash is decoded, with the provided layout.
+    #[cfg(feature = "std")]
+    fn to_graphlet_names<GraphletKind, Element, Layout>(
         &self,
         number_of_elements: Element,
     ) -> HashMap<String, Count>
     where
-        Element: Add<Element, Output = Element>
-            + Mul<Output = Element>
-            + Debug
-            + Copy
-            + One
-            + Zero
-            + Ord,
-
-        Graphlet: From<GraphletKind> + Primitive<Element>,
-        (Element, Element, Element, Element): PerfectGraphletHash<Graphlet, Element>,
+        GraphletKind: GraphletSet<Graphlet> + ToString + From<Graphlet>,
+        Element: Copy,
+        Graphlet: From<GraphletKind>,
+        Count: Clone,
+        Layout: GraphletHashLayout<Graphlet, Element>,
     {
         self.iter_graphlets_and_counts()
             .map(|(graphlet, count)| {
                 (
-                    <(Element, Element, Element, Element)>::decode_graphlet_kind::<GraphletKind>(
-                        graphlet,
-                        number_of_elements,
-                    )
-                    .to_string(),
+                    Layout::decode_graphlet_kind::<GraphletKind>(graphlet, number_of_elements)
+                        .to_string(),
                     count,
                 )
             })
@@ -120,12 +810,13 @@ where
     }
 }
 
+#[cfg(feature = "std")]
 impl<Graphlet, Count> GraphLetCounter<Graphlet, Count> for HashMap<Graphlet, Count>
 where
-    Count: Debug + Zero + One + Ord + AddAssign + Copy,
+    Count: Debug + Zero + One + PartialOrd + AddAssign + Copy,
     Graphlet: Debug + Copy + Eq + std::hash::Hash + Mul<Output = Graphlet> + Add<Output = Graphlet>,
 {
-    type Iter<'a> = std::iter::Map<std::collections::hash_map::Iter<'a, Graphlet, Count>, fn((&Graphlet, &Count)) -> (Graphlet, Count)> where Self: 'a;
+    type RefIter<'a> = std::collections::hash_map::Iter<'a, Graphlet, Count> where Self: 'a;
 
     fn with_number_of_elements<Element>(_number_of_elements: Element) -> Self {
         HashMap::new()
@@ -141,11 +832,363 @@ where
         *self.get(&graphlet).unwrap_or(&Count::ZERO)
     }
 
-    fn iter_graphlets_and_counts<'a>(&'a self) -> Self::Iter<'a>
+    fn remove(&mut self, graphlet: Graphlet) -> Count {
+        HashMap::remove(self, &graphlet).unwrap_or(Count::ZERO)
+    }
+
+    fn clear(&mut self) {
+        HashMap::clear(self);
+    }
+
+    fn iter_graphlets_and_counts_ref<'a>(&'a self) -> Self::RefIter<'a>
     where
         Self: 'a,
+        Graphlet: 'a,
         Count: 'a,
     {
-        self.iter().map(|(graphlet, count)| (*graphlet, *count))
+        self.iter()
     }
 }
+
+/// A sparse graphlet counter iterating over the graphlets in ascending order of their hashes.
+///
+/// Differently from the `HashMap` counter, whose iteration order changes across runs,
+/// the reports and exports of this counter are reproducible, at the cost of
+/// logarithmic insertions.
+impl<Graphlet, Count> GraphLetCounter<Graphlet, Count> for BTreeMap<Graphlet, Count>
+where
+    Count: Debug + Zero + One + PartialOrd + AddAssign + Copy,
+    Graphlet: Debug + Copy + Ord + Mul<Output = Graphlet> + Add<Output = Graphlet>,
+{
+    type RefIter<'a>
+        = alloc::collections::btree_map::Iter<'a, Graphlet, Count>
+    where
+        Self: 'a;
+
+    fn with_number_of_elements<Element>(_number_of_elements: Element) -> Self {
+        BTreeMap::new()
+    }
+
+    fn insert_count(&mut self, graphlet: Graphlet, count: Count) {
+        if count > Count::ZERO {
+            *self.entry(graphlet).or_insert(Count::ZERO) += count;
+        }
+    }
+
+    fn get_number_of_graphlets(&self, graphlet: Graphlet) -> Count {
+        *self.get(&graphlet).unwrap_or(&Count::ZERO)
+    }
+
+    fn remove(&mut self, graphlet: Graphlet) -> Count {
+        BTreeMap::remove(self, &graphlet).unwrap_or(Count::ZERO)
+    }
+
+    fn clear(&mut self) {
+        BTreeMap::clear(self);
+    }
+
+    fn iter_graphlets_and_counts_ref<'a>(&'a self) -> Self::RefIter<'a>
+    where
+        Self: 'a,
+        Graphlet: 'a,
+        Count: 'a,
+    {
+        self.iter()
+    }
+}
+
+/// Iterator over references to the non-zero entries of a [`DenseGraphletCounter`].
+pub type DenseGraphletsIter<'a, Graphlet, Count> = core::iter::Filter<
+    core::iter::Map<
+        core::slice::Iter<'a, (Graphlet, Count)>,
+        fn(&'a (Graphlet, Count)) -> (&'a Graphlet, &'a Count),
+    >,
+    fn(&(&'a Graphlet, &'a Count)) -> bool,
+>;
+
+/// A graphlet counter storing the counts in a vector indexed by the graphlet hashes.
+///
+/// Differently from the `HashMap` counter, insertions do not require hashing,
+/// at the cost of a memory footprint proportional to the largest inserted graphlet
+/// hash. It is therefore best suited for graphs with few node labels.
+///
+/// # Implementation details
+/// The vector is grown on demand, and each entry stores the graphlet hash alongside
+/// its count so that the counter can be iterated over by reference. Differently from
+/// the `HashMap` counter, the number of examined node quadruples is also tracked.
+#[derive(Debug, Clone, Default)]
+pub struct DenseGraphletCounter<Graphlet, Count> {
+    /// The graphlet hashes and their counts, where the i-th entry refers to the graphlet i.
+    counts: Vec<(Graphlet, Count)>,
+    /// The number of node quadruples examined while counting.
+    examined: Count,
+}
+
+impl<Graphlet, Count> GraphLetCounter<Graphlet, Count> for DenseGraphletCounter<Graphlet, Count>
+where
+    Count: Debug + Zero + One + PartialOrd + AddAssign + Copy,
+    Graphlet: Debug + Copy + Primitive<usize> + Mul<Output = Graphlet> + Add<Output = Graphlet>,
+    usize: Primitive<Graphlet>,
+{
+    type RefIter<'a>
+        = DenseGraphletsIter<'a, Graphlet, Count>
+    where
+        Self: 'a,
+        Graphlet: 'a,
+        Count: 'a;
+
+    fn with_number_of_elements<Element>(_number_of_elements: Element) -> Self {
+        Self {
+            counts: Vec::new(),
+            examined: Count::ZERO,
+        }
+    }
+
+    fn insert_count(&mut self, graphlet: Graphlet, count: Count) {
+        if count > Count::ZERO {
+            let index = usize::convert(graphlet);
+            if index >= self.counts.len() {
+                let start = self.counts.len();
+                self.counts
+                    .extend((start..=index).map(|index| (Graphlet::convert(index), Count::ZERO)));
+            }
+            self.counts[index].1 += count;
+        }
+    }
+
+    fn get_number_of_graphlets(&self, graphlet: Graphlet) -> Count {
+        self.counts
+            .get(usize::convert(graphlet))
+            .map_or(Count::ZERO, |(_, count)| *count)
+    }
+
+    fn remove(&mut self, graphlet: Graphlet) -> Count {
+        self.counts
+            .get_mut(usize::convert(graphlet))
+            .map_or(Count::ZERO, |(_, count)| {
+                core::mem::replace(count, Count::ZERO)
+            })
+    }
+
+    fn clear(&mut self) {
+        for (_, count) in self.counts.iter_mut() {
+            *count = Count::ZERO;
+        }
+        self.examined = Count::ZERO;
+    }
+
+    fn record_examined(&mut self, n: Count) {
+        self.examined += n;
+    }
+
+    fn examined(&self) -> Count {
+        self.examined
+    }
+
+    fn iter_graphlets_and_counts_ref<'a>(&'a self) -> Self::RefIter<'a>
+    where
+        Self: 'a,
+        Graphlet: 'a,
+        Count: 'a,
+    {
+        self.counts
+            .iter()
+            .map((|(graphlet, count)| (graphlet, count)) as fn(&'a (Graphlet, Count)) -> _)
+            .filter(|(_, count)| **count > Count::ZERO)
+    }
+}
+
+/// A graphlet counter paired with the number of elements used as base of its graphlet hashes.
+///
+/// Since the graphlet hashes depend on the number of node labels of the graph they
+/// were computed on, counters coming from different graphs can only be compared
+/// once they are rebased to a common number of elements. The graphlets are decoded
+/// and encoded with the provided [`GraphletHashLayout`], which must be the one the
+/// counter was built with.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone)]
+pub struct CounterWithBase<Graphlet, Count, Element, Layout = PolynomialLayout> {
+    /// The graphlet counter.
+    counter: HashMap<Graphlet, Count>,
+    /// The number of elements, i.e. the node labels, used to encode the graphlets.
+    number_of_elements: Element,
+    /// The layout used to encode the graphlets.
+    layout: PhantomData<Layout>,
+}
+
+#[cfg(feature = "std")]
+impl<Graphlet, Count, Element> CounterWithBase<Graphlet, Count, Element> {
+    /// Create a new counter with base, whose graphlets are encoded with the [`PolynomialLayout`].
+    ///
+    /// # Arguments
+    /// * `counter` - The graphlet counter.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets of the counter.
+    pub fn new(counter: HashMap<Graphlet, Count>, number_of_elements: Element) -> Self {
+        Self::with_layout(counter, number_of_elements)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Graphlet, Count, Element, Layout> CounterWithBase<Graphlet, Count, Element, Layout> {
+    /// Create a new counter with base, whose graphlets are encoded with the provided layout.
+    ///
+    /// # Arguments
+    /// * `counter` - The graphlet counter.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets of the counter.
+    pub fn with_layout(counter: HashMap<Graphlet, Count>, number_of_elements: Element) -> Self {
+        Self {
+            counter,
+            number_of_elements,
+            layout: PhantomData,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Graphlet, Count, Element, Layout> CounterWithBase<Graphlet, Count, Element, Layout>
+where
+    Count: Debug + Zero + One + Ord + AddAssign + Copy,
+    Graphlet: Debug
+        + Copy
+        + Eq
+        + core::hash::Hash
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + From<ExtendedGraphletType>,
+    Element: Copy + Debug + Ord,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, Element>,
+{
+    /// Returns the graphlet counter.
+    pub fn counter(&self) -> &HashMap<Graphlet, Count> {
+        &self.counter
+    }
+
+    /// Returns the number of elements used to encode the graphlets of the counter.
+    pub fn number_of_elements(&self) -> Element {
+        self.number_of_elements
+    }
+
+    /// Returns the counter with the graphlets encoded using the provided number of elements.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements to encode the graphlets with.
+    ///
+    /// # Raises
+    /// * If the provided number of elements is smaller than the current one.
+    ///
+    /// # Implementation details
+    /// The dummy label used to fill the fourth position of three-node graphlets
+    /// depends on the number of elements, and is therefore replaced with the dummy
+    /// label of the layout for the new one, while the labels of the nodes are kept
+    /// as decoded.
+    pub fn rebase(&self, number_of_elements: Element) -> Result<Self, String> {
+        if number_of_elements < self.number_of_elements {
+            return Err(format!(
+                concat!(
+                    "The provided number of elements {:?} is smaller than the number ",
+                    "of elements {:?} of the counter, and it cannot be rebased."
+                ),
+                number_of_elements, self.number_of_elements
+            ));
+        }
+        let mut counter = HashMap::with_capacity(self.counter.len());
+        for (graphlet, count) in self.counter.iter_graphlets_and_counts() {
+            let (graphlet_kind, (first, second, third, mut fourth)) =
+                Layout::decode::<ExtendedGraphletType>(graphlet, self.number_of_elements);
+            if graphlet_kind.node_count() == 3 {
+                fourth = Layout::dummy_label(number_of_elements);
+            }
+            counter.insert_count(
+                Layout::encode(
+                    (first, second, third, fourth),
+                    graphlet_kind,
+                    number_of_elements,
+                ),
+                count,
+            );
+        }
+        Ok(Self::with_layout(counter, number_of_elements))
+    }
+
+    /// Returns the total count of each graphlet kind.
+    pub fn get_kind_totals(&self) -> HashMap<ExtendedGraphletType, Count> {
+        let mut totals = HashMap::new();
+        for (graphlet, count) in self.counter.iter_graphlets_and_counts() {
+            let graphlet_kind = Layout::decode_graphlet_kind::<ExtendedGraphletType>(
+                graphlet,
+                self.number_of_elements,
+            );
+            *totals.entry(graphlet_kind).or_insert(Count::ZERO) += count;
+        }
+        totals
+    }
+}
+
+/// Returns a report combining the graphlet counts of several graphs.
+///
+/// # Arguments
+/// * `counters` - The counters of the graphs to combine.
+///
+/// # Implementation details
+/// The total count of each graphlet kind is computed for each counter with its own
+/// number of elements, so that the counters need not be rebased to a common one.
+/// The report contains a row for each graphlet kind, in the order of
+/// [`ExtendedGraphletType::all`], with the total count over all graphs, followed
+/// by a column with the count of each graph.
+#[cfg(feature = "std")]
+pub fn combined_report<'a, Graphlet, Count, Element, Layout, I>(counters: I) -> String
+where
+    I: IntoIterator<Item = &'a CounterWithBase<Graphlet, Count, Element, Layout>>,
+    Count: Debug + Zero + One + Ord + AddAssign + Copy + 'a,
+    Graphlet: Debug
+        + Copy
+        + Eq
+        + core::hash::Hash
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + From<ExtendedGraphletType>
+        + 'a,
+    Element: Copy + Debug + Ord + 'a,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, Element> + 'a,
+{
+    let totals = counters
+        .into_iter()
+        .map(CounterWithBase::get_kind_totals)
+        .collect::<Vec<_>>();
+    let mut report = String::new();
+
+    if totals.is_empty() {
+        return report;
+    }
+
+    report.push_str("Graphlet\tTotal");
+    for graph_number in 0..totals.len() {
+        report.push_str(&format!("\tGraph {}", graph_number));
+    }
+    report.push('\n');
+
+    for graphlet_kind in ExtendedGraphletType::all() {
+        let graph_totals = totals
+            .iter()
+            .map(|graph_totals| {
+                graph_totals
+                    .get(&graphlet_kind)
+                    .copied()
+                    .unwrap_or(Count::ZERO)
+            })
+            .collect::<Vec<_>>();
+        let mut total = Count::ZERO;
+        for &graph_total in graph_totals.iter() {
+            total += graph_total;
+        }
+        report.push_str(&format!("{}\t{:?}", graphlet_kind, total));
+        for graph_total in graph_totals {
+            report.push_str(&format!("\t{:?}", graph_total));
+        }
+        report.push('\n');
+    }
+
+    report
+}
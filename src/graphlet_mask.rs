@@ -0,0 +1,131 @@
+use crate::graphlet_set::ExtendedGraphletType;
+
+/// A set of graphlet types, used to select the orbits to count.
+///
+/// # Implementation details
+/// The mask is a bitset with the bit of each graphlet type at its
+/// [`ExtendedGraphletType::orbit_index`]. The orbits of an edge are found by three
+/// pieces of work of very different cost, so that the speedup of a mask depends
+/// on which of them it allows to skip:
+///
+/// * The neighbours of the source and destination nodes are merged in any case,
+///   which yields the `Triad` and `Triangle` orbits and the per-label neighbour counts.
+/// * The neighbours of the nodes adjacent solely to the source or destination node
+///   are traversed for the `FourPathEdge`, `TailedTriTail` and `FourCycle` orbits.
+///   The traversal is skipped when none of them is counted.
+/// * The neighbours of the triangle nodes are traversed for the `FourClique`,
+///   `ChordalCycleEdge` and `TailedTriCenter` orbits. The traversal is skipped when
+///   none of them is counted.
+///
+/// The `FourPathCenter`, `FourStar`, `TailedTriEdge` and `ChordalCycleCenter` orbits
+/// are instead derived from the per-label neighbour counts and respectively from
+/// the `FourCycle`, `TailedTriTail`, `ChordalCycleEdge` and `FourClique` orbits,
+/// which are therefore counted, though not returned, whenever the derived orbit is
+/// selected. See [`GraphletMask::with_dependencies`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GraphletMask(u16);
+
+impl GraphletMask {
+    /// Returns the mask without any graphlet type.
+    pub const fn empty() -> Self {
+        Self(0)
+    }
+
+    /// Returns the mask with all the graphlet types.
+    pub const fn all() -> Self {
+        Self((1 << ExtendedGraphletType::COUNT) - 1)
+    }
+
+    /// Returns the mask with the provided graphlet type added.
+    ///
+    /// # Arguments
+    /// * `graphlet_type` - The graphlet type to add.
+    pub fn with(self, graphlet_type: ExtendedGraphletType) -> Self {
+        Self(self.0 | (1 << graphlet_type.orbit_index()))
+    }
+
+    /// Returns the mask with the provided graphlet type removed.
+    ///
+    /// # Arguments
+    /// * `graphlet_type` - The graphlet type to remove.
+    pub fn without(self, graphlet_type: ExtendedGraphletType) -> Self {
+        Self(self.0 & !(1 << graphlet_type.orbit_index()))
+    }
+
+    /// Returns whether the mask contains the provided graphlet type.
+    ///
+    /// # Arguments
+    /// * `graphlet_type` - The graphlet type to check.
+    pub fn contains(&self, graphlet_type: ExtendedGraphletType) -> bool {
+        self.0 & (1 << graphlet_type.orbit_index()) != 0
+    }
+
+    /// Returns whether the mask contains at least one of the provided graphlet types.
+    ///
+    /// # Arguments
+    /// * `graphlet_types` - The graphlet types to check.
+    pub fn contains_any(&self, graphlet_types: &[ExtendedGraphletType]) -> bool {
+        graphlet_types
+            .iter()
+            .any(|&graphlet_type| self.contains(graphlet_type))
+    }
+
+    /// Returns whether the mask does not contain any graphlet type.
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns an iterator over the graphlet types of the mask, in their numeric order.
+    pub fn iter(&self) -> impl Iterator<Item = ExtendedGraphletType> + '_ {
+        ExtendedGraphletType::all().filter(|&graphlet_type| self.contains(graphlet_type))
+    }
+
+    /// Returns the mask with the graphlet types required to count the ones of the mask.
+    ///
+    /// # Implementation details
+    /// The orbits derived by the orbit formulas require the counts of another orbit
+    /// with the same labels: the four-path centers require the four-cycles, the
+    /// four-stars the tailed-triangle tails, the tailed-triangle edges the chordal-cycle
+    /// edges and the chordal-cycle centers the four-cliques.
+    pub fn with_dependencies(self) -> Self {
+        [
+            (
+                ExtendedGraphletType::FourPathCenter,
+                ExtendedGraphletType::FourCycle,
+            ),
+            (
+                ExtendedGraphletType::FourStar,
+                ExtendedGraphletType::TailedTriTail,
+            ),
+            (
+                ExtendedGraphletType::TailedTriEdge,
+                ExtendedGraphletType::ChordalCycleEdge,
+            ),
+            (
+                ExtendedGraphletType::ChordalCycleCenter,
+                ExtendedGraphletType::FourClique,
+            ),
+        ]
+        .into_iter()
+        .filter(|&(derived, _)| self.contains(derived))
+        .fold(self, |mask, (_, dependency)| mask.with(dependency))
+    }
+}
+
+impl Default for GraphletMask {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+impl From<ExtendedGraphletType> for GraphletMask {
+    fn from(graphlet_type: ExtendedGraphletType) -> Self {
+        Self::empty().with(graphlet_type)
+    }
+}
+
+impl FromIterator<ExtendedGraphletType> for GraphletMask {
+    fn from_iter<I: IntoIterator<Item = ExtendedGraphletType>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::empty(), Self::with)
+    }
+}
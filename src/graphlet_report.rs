@@ -0,0 +1,111 @@
+use std::fmt::Debug;
+use std::ops::{Add, Mul};
+
+use serde::{Deserialize, Serialize};
+
+use crate::numbers::{One, Primitive};
+use crate::perfect_graphlet_hash::NTuplePerfectHash;
+use crate::prelude::*;
+
+/// The count of the graphlets of a kind with a given label quadruple.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphletEntry<Element, Count> {
+    /// The kind of the graphlets.
+    pub kind: ExtendedGraphletType,
+    /// The labels of the nodes of the graphlets, including the dummy label of the three-node graphlets.
+    pub labels: (Element, Element, Element, Element),
+    /// The number of graphlets.
+    pub count: Count,
+}
+
+/// A serializable report of the graphlet counts, with the graphlets decoded.
+///
+/// # Implementation details
+/// The entries are sorted by graphlet kind and label quadruple, so that the
+/// serialization of a report does not depend on the iteration order of the
+/// counter it was built from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GraphletReport<Element, Count> {
+    /// The number of elements used to encode the graphlets.
+    pub number_of_elements: Element,
+    /// The decoded graphlet counts.
+    pub entries: Vec<GraphletEntry<Element, Count>>,
+}
+
+impl<Element, Count> GraphletReport<Element, Count>
+where
+    Element: Add<Element, Output = Element> + Mul<Output = Element> + Debug + Copy + Ord,
+    Count: Debug + Copy + One,
+{
+    /// Returns the report of the provided graphlet counter.
+    ///
+    /// # Arguments
+    /// * `counter` - The graphlet counter to report.
+    /// * `number_of_elements` - The number of elements used to encode the graphlets.
+    pub fn from_counter<Graphlet, Counter>(counter: &Counter, number_of_elements: Element) -> Self
+    where
+        Counter: GraphLetCounter<Graphlet, Count>,
+        Graphlet: Debug
+            + Copy
+            + Mul<Output = Graphlet>
+            + Add<Output = Graphlet>
+            + From<ExtendedGraphletType>
+            + Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        [Element; 4]: NTuplePerfectHash<4, Graphlet, Element>,
+    {
+        let mut entries = counter
+            .iter_graphlets_and_counts_ref()
+            .map(|(&graphlet, &count)| {
+                let (kind, [first, second, third, fourth]) = <[Element; 4]>::decode_with_graphlet::<
+                    ExtendedGraphletType,
+                >(
+                    graphlet, number_of_elements
+                );
+                GraphletEntry {
+                    kind,
+                    labels: (first, second, third, fourth),
+                    count,
+                }
+            })
+            .collect::<Vec<_>>();
+        entries.sort_unstable_by_key(|entry| (u8::from(entry.kind), entry.labels));
+        Self {
+            number_of_elements,
+            entries,
+        }
+    }
+
+    /// Returns a graphlet counter with the counts of the report.
+    ///
+    /// # Implementation details
+    /// The graphlets are encoded with the number of elements of the report,
+    /// so that the counter matches the one the report was built from.
+    pub fn to_counter<Graphlet, Counter>(&self) -> Counter
+    where
+        Counter: GraphLetCounter<Graphlet, Count>,
+        Graphlet: Debug
+            + Copy
+            + Mul<Output = Graphlet>
+            + Add<Output = Graphlet>
+            + From<ExtendedGraphletType>
+            + Primitive<Element>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        [Element; 4]: NTuplePerfectHash<4, Graphlet, Element>,
+    {
+        let mut counter = Counter::with_number_of_elements(self.number_of_elements);
+        for entry in self.entries.iter() {
+            counter.insert_count(
+                [
+                    entry.labels.0,
+                    entry.labels.1,
+                    entry.labels.2,
+                    entry.labels.3,
+                ]
+                .encode_with_graphlet(entry.kind, self.number_of_elements),
+                entry.count,
+            );
+        }
+        counter
+    }
+}
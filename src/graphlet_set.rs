@@ -1,3 +1,11 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ExtendedGraphletType {
     FourClique,
     ChordalCycleCenter,
@@ -13,6 +21,8 @@ pub enum ExtendedGraphletType {
     Triad,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ReducedGraphletType {
     FourClique,
     ChordalCycle,
@@ -29,6 +39,33 @@ pub trait GraphletSet<C> {
     fn get_number_of_graphlets() -> C;
 }
 
+/// Error returned when a value is not associated to any graphlet type.
+///
+/// # Implementation details
+/// The graphlet types are associated to the values from zero to their number,
+/// which is excluded and stored alongside the invalid value to report the valid range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidGraphletType {
+    /// The value that is not associated to any graphlet type.
+    pub value: u8,
+    /// The number of graphlet types of the set the value was converted to.
+    pub number_of_graphlet_types: u8,
+}
+
+impl core::fmt::Display for InvalidGraphletType {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            formatter,
+            "Invalid graphlet type: {} (should be between 0 and {})",
+            self.value,
+            self.number_of_graphlet_types - 1
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidGraphletType {}
+
 impl GraphletSet<u8> for ExtendedGraphletType {
     fn get_number_of_graphlets() -> u8 {
         12
@@ -95,17 +132,21 @@ impl GraphletSet<u128> for ExtendedGraphletType {
     }
 }
 
-impl ToString for ExtendedGraphletType {
-    fn to_string(&self) -> String {
-        let name: &str = self.into();
-        name.to_string()
+impl GraphletSet<u128> for ReducedGraphletType {
+    fn get_number_of_graphlets() -> u128 {
+        8
     }
 }
 
-impl ToString for ReducedGraphletType {
-    fn to_string(&self) -> String {
-        let name: &str = self.into();
-        name.to_string()
+impl core::fmt::Display for ExtendedGraphletType {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.pad(self.into())
+    }
+}
+
+impl core::fmt::Display for ReducedGraphletType {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.pad(self.into())
     }
 }
 
@@ -143,45 +184,336 @@ impl From<&ReducedGraphletType> for &str {
     }
 }
 
+impl core::str::FromStr for ExtendedGraphletType {
+    type Err = String;
+
+    /// Returns the graphlet type with the provided PascalCase name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the graphlet type, as returned by `to_string`.
+    ///
+    /// # Raises
+    /// * If the name does not match any graphlet type, listing the valid names.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ExtendedGraphletType::all()
+            .find(|graphlet_type| <&str>::from(graphlet_type) == name)
+            .ok_or_else(|| {
+                format!(
+                    "The provided name `{}` is not a valid ExtendedGraphletType. The valid names are: {}.",
+                    name,
+                    ExtendedGraphletType::all()
+                        .map(|graphlet_type| graphlet_type.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl core::str::FromStr for ReducedGraphletType {
+    type Err = String;
+
+    /// Returns the graphlet type with the provided PascalCase name.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the graphlet type, as returned by `to_string`.
+    ///
+    /// # Raises
+    /// * If the name does not match any graphlet type, listing the valid names.
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ReducedGraphletType::all()
+            .find(|graphlet_type| <&str>::from(graphlet_type) == name)
+            .ok_or_else(|| {
+                format!(
+                    "The provided name `{}` is not a valid ReducedGraphletType. The valid names are: {}.",
+                    name,
+                    ReducedGraphletType::all()
+                        .map(|graphlet_type| graphlet_type.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+            })
+    }
+}
+
+impl ExtendedGraphletType {
+    /// The number of graphlet types.
+    pub const COUNT: usize = 12;
+
+    /// Returns an iterator over all the graphlet types, in their numeric order.
+    ///
+    /// # Implementation details
+    /// The graphlet types are yielded from `Triad` to `FourClique`, following the
+    /// `u8` conversion, which is the reverse of their declaration order.
+    pub fn all() -> impl Iterator<Item = ExtendedGraphletType> {
+        (0..Self::COUNT as u8).map(ExtendedGraphletType::from)
+    }
+
+    /// Returns the index of the graphlet type, between zero and [`ExtendedGraphletType::COUNT`].
+    pub fn orbit_index(&self) -> usize {
+        u8::from(*self) as usize
+    }
+
+    /// Returns the number of nodes of the graphlet, three for the triads and triangles and four otherwise.
+    pub fn node_count(&self) -> usize {
+        match self {
+            ExtendedGraphletType::Triad | ExtendedGraphletType::Triangle => 3,
+            _ => 4,
+        }
+    }
+
+    /// Returns the number of the orbit in the g1 to g12 numbering of the "Heterogeneous Graphlets" paper.
+    ///
+    /// # Implementation details
+    /// The paper numbers the orbits from the triad, `g1`, to the four-clique, `g12`,
+    /// so that the number is the [`ExtendedGraphletType::orbit_index`] plus one,
+    /// and not the position of the variant in the enum, which is reversed.
+    pub fn paper_orbit(&self) -> u8 {
+        match self {
+            ExtendedGraphletType::Triad => 1,
+            ExtendedGraphletType::Triangle => 2,
+            ExtendedGraphletType::FourPathEdge => 3,
+            ExtendedGraphletType::FourPathCenter => 4,
+            ExtendedGraphletType::FourStar => 5,
+            ExtendedGraphletType::FourCycle => 6,
+            ExtendedGraphletType::TailedTriTail => 7,
+            ExtendedGraphletType::TailedTriCenter => 8,
+            ExtendedGraphletType::TailedTriEdge => 9,
+            ExtendedGraphletType::ChordalCycleEdge => 10,
+            ExtendedGraphletType::ChordalCycleCenter => 11,
+            ExtendedGraphletType::FourClique => 12,
+        }
+    }
+
+    /// Returns the description of the orbit used in the paper, such as `"four-clique (g12)"`.
+    pub fn paper_description(&self) -> &'static str {
+        match self {
+            ExtendedGraphletType::Triad => "triad (g1)",
+            ExtendedGraphletType::Triangle => "triangle (g2)",
+            ExtendedGraphletType::FourPathEdge => "four-path-edge (g3)",
+            ExtendedGraphletType::FourPathCenter => "four-path-center (g4)",
+            ExtendedGraphletType::FourStar => "four-star (g5)",
+            ExtendedGraphletType::FourCycle => "four-cycle (g6)",
+            ExtendedGraphletType::TailedTriTail => "tailed-triangle-tail (g7)",
+            ExtendedGraphletType::TailedTriCenter => "tailed-triangle-center (g8)",
+            ExtendedGraphletType::TailedTriEdge => "tailed-triangle-edge (g9)",
+            ExtendedGraphletType::ChordalCycleEdge => "chordal-cycle-edge (g10)",
+            ExtendedGraphletType::ChordalCycleCenter => "chordal-cycle-center (g11)",
+            ExtendedGraphletType::FourClique => "four-clique (g12)",
+        }
+    }
+
+    /// Returns the graphlet type associated to the provided value.
+    ///
+    /// # Arguments
+    /// * `value` - The value to convert, as returned by the `u8` conversion of the graphlet type.
+    ///
+    /// # Raises
+    /// * If the provided value is not associated to any graphlet type.
+    ///
+    /// # Implementation details
+    /// Differently from the `From<u8>` conversion, which panics on invalid values,
+    /// this method allows to handle graphlet types decoded from corrupted data.
+    /// A `TryFrom<u8>` implementation cannot be provided: the standard library
+    /// implements `TryFrom<U>` for every type implementing `From<U>`, with an
+    /// infallible error, so that it would conflict with the `From<u8>` conversion
+    /// required by the graphlet hash layouts. As a consequence, `try_from` on a
+    /// `u8` compiles but panics on invalid values, as the `From<u8>` conversion.
+    ///
+    pub fn try_from_u8(value: u8) -> Result<Self, InvalidGraphletType> {
+        match value {
+            11 => Ok(ExtendedGraphletType::FourClique),
+            10 => Ok(ExtendedGraphletType::ChordalCycleCenter),
+            9 => Ok(ExtendedGraphletType::ChordalCycleEdge),
+            8 => Ok(ExtendedGraphletType::TailedTriEdge),
+            7 => Ok(ExtendedGraphletType::TailedTriCenter),
+            6 => Ok(ExtendedGraphletType::TailedTriTail),
+            5 => Ok(ExtendedGraphletType::FourCycle),
+            4 => Ok(ExtendedGraphletType::FourStar),
+            3 => Ok(ExtendedGraphletType::FourPathCenter),
+            2 => Ok(ExtendedGraphletType::FourPathEdge),
+            1 => Ok(ExtendedGraphletType::Triangle),
+            0 => Ok(ExtendedGraphletType::Triad),
+            _ => Err(InvalidGraphletType {
+                value,
+                number_of_graphlet_types: 12,
+            }),
+        }
+    }
+
+    /// Returns the graphlet associated to the orbit.
+    ///
+    /// # Implementation details
+    /// The orbits are merged into their graphlets as follows:
+    /// * `FourClique` -> `FourClique`
+    /// * `ChordalCycleCenter`, `ChordalCycleEdge` -> `ChordalCycle`
+    /// * `TailedTriEdge`, `TailedTriCenter`, `TailedTriTail` -> `TailedTri`
+    /// * `FourCycle` -> `FourCycle`
+    /// * `FourStar` -> `FourStar`
+    /// * `FourPathCenter`, `FourPathEdge` -> `FourPath`
+    /// * `Triangle` -> `Triangle`
+    /// * `Triad` -> `Triad`
+    ///
+    /// A `From<ExtendedGraphletType>` implementation is not provided, as it would
+    /// make the conversions from the graphlet hashes ambiguous in generic code.
+    ///
+    pub fn to_reduced(self) -> ReducedGraphletType {
+        match self {
+            ExtendedGraphletType::FourClique => ReducedGraphletType::FourClique,
+            ExtendedGraphletType::ChordalCycleCenter | ExtendedGraphletType::ChordalCycleEdge => {
+                ReducedGraphletType::ChordalCycle
+            }
+            ExtendedGraphletType::TailedTriEdge
+            | ExtendedGraphletType::TailedTriCenter
+            | ExtendedGraphletType::TailedTriTail => ReducedGraphletType::TailedTri,
+            ExtendedGraphletType::FourCycle => ReducedGraphletType::FourCycle,
+            ExtendedGraphletType::FourStar => ReducedGraphletType::FourStar,
+            ExtendedGraphletType::FourPathCenter | ExtendedGraphletType::FourPathEdge => {
+                ReducedGraphletType::FourPath
+            }
+            ExtendedGraphletType::Triangle => ReducedGraphletType::Triangle,
+            ExtendedGraphletType::Triad => ReducedGraphletType::Triad,
+        }
+    }
+}
+
+/// Returns the canonical order of the node labels of the provided orbit.
+///
+/// # Arguments
+/// * `kind` - The orbit of the labels.
+/// * `labels` - The node labels, in the order used by the graphlet hashes.
+///
+/// # Implementation details
+/// The first two labels are those of the endpoints of the edge, and the last two
+/// are those of the other nodes, in the roles defined by the orbit. Since each edge
+/// is visited from its endpoint with the smaller node ID, the same motif may be
+/// encoded with its labels in different positions. The positions that can be exchanged
+/// without changing the motif are therefore sorted, so that the equivalent label
+/// quadruples share a key:
+///
+/// * `Triad`, `Triangle`, `FourPathEdge`, `ChordalCycleEdge`, `TailedTriCenter`:
+///   the endpoints are exchanged.
+/// * `FourClique`, `ChordalCycleCenter`, `TailedTriEdge`, `TailedTriTail`, `FourStar`,
+///   `FourPathCenter`: the endpoints and, independently, the other nodes are exchanged.
+/// * `FourCycle`: the endpoints and the other nodes are exchanged together.
+///
+/// For instance, the four-clique is symmetric under any permutation preserving the
+/// edge, while the four-path-edge is not, as its third node is the end of the path
+/// and its fourth node the one adjacent to the edge. In a four-cycle, the third node
+/// is adjacent to the first and the fourth to the second, so that `(A, B, A, B)`
+/// and `(B, A, B, A)` are the same motif. The dummy label of the three-node graphlets
+/// keeps its position.
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::prelude::*;
+///
+/// assert_eq!(
+///     canonical_labels(ExtendedGraphletType::FourCycle, (1, 0, 1, 0)),
+///     (0, 1, 0, 1)
+/// );
+/// assert_eq!(
+///     canonical_labels(ExtendedGraphletType::FourPathEdge, (1, 0, 1, 0)),
+///     (0, 1, 1, 0)
+/// );
+/// assert_eq!(
+///     canonical_labels(ExtendedGraphletType::FourClique, (1, 0, 1, 0)),
+///     (0, 1, 0, 1)
+/// );
+/// ```
+pub fn canonical_labels<L: Ord + Copy>(
+    kind: ExtendedGraphletType,
+    labels: (L, L, L, L),
+) -> (L, L, L, L) {
+    let (first, second, third, fourth) = labels;
+    match kind {
+        ExtendedGraphletType::FourCycle => labels.min((second, first, fourth, third)),
+        ExtendedGraphletType::Triad
+        | ExtendedGraphletType::Triangle
+        | ExtendedGraphletType::FourPathEdge
+        | ExtendedGraphletType::ChordalCycleEdge
+        | ExtendedGraphletType::TailedTriCenter => {
+            (first.min(second), first.max(second), third, fourth)
+        }
+        ExtendedGraphletType::FourClique
+        | ExtendedGraphletType::ChordalCycleCenter
+        | ExtendedGraphletType::TailedTriEdge
+        | ExtendedGraphletType::TailedTriTail
+        | ExtendedGraphletType::FourStar
+        | ExtendedGraphletType::FourPathCenter => (
+            first.min(second),
+            first.max(second),
+            third.min(fourth),
+            third.max(fourth),
+        ),
+    }
+}
+
 impl From<u8> for ExtendedGraphletType {
     fn from(value: u8) -> Self {
+        ExtendedGraphletType::try_from_u8(value).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl ReducedGraphletType {
+    /// The number of graphlet types.
+    pub const COUNT: usize = 8;
+
+    /// Returns an iterator over all the graphlet types, in their numeric order.
+    ///
+    /// # Implementation details
+    /// The graphlet types are yielded from `Triad` to `FourClique`, following the
+    /// `u8` conversion, which is the reverse of their declaration order.
+    pub fn all() -> impl Iterator<Item = ReducedGraphletType> {
+        (0..Self::COUNT as u8).map(ReducedGraphletType::from)
+    }
+
+    /// Returns the index of the graphlet type, between zero and [`ReducedGraphletType::COUNT`].
+    pub fn orbit_index(&self) -> usize {
+        u8::from(*self) as usize
+    }
+
+    /// Returns the number of nodes of the graphlet, three for the triads and triangles and four otherwise.
+    pub fn node_count(&self) -> usize {
+        match self {
+            ReducedGraphletType::Triad | ReducedGraphletType::Triangle => 3,
+            _ => 4,
+        }
+    }
+
+    /// Returns the graphlet type associated to the provided value.
+    ///
+    /// # Arguments
+    /// * `value` - The value to convert, as returned by the `u8` conversion of the graphlet type.
+    ///
+    /// # Raises
+    /// * If the provided value is not associated to any graphlet type.
+    ///
+    /// # Implementation details
+    /// As for [`ExtendedGraphletType::try_from_u8`], a `TryFrom<u8>` implementation
+    /// would conflict with the `From<u8>` conversion.
+    ///
+    pub fn try_from_u8(value: u8) -> Result<Self, InvalidGraphletType> {
         match value {
-            11 => ExtendedGraphletType::FourClique,
-            10 => ExtendedGraphletType::ChordalCycleCenter,
-            9 => ExtendedGraphletType::ChordalCycleEdge,
-            8 => ExtendedGraphletType::TailedTriEdge,
-            7 => ExtendedGraphletType::TailedTriCenter,
-            6 => ExtendedGraphletType::TailedTriTail,
-            5 => ExtendedGraphletType::FourCycle,
-            4 => ExtendedGraphletType::FourStar,
-            3 => ExtendedGraphletType::FourPathCenter,
-            2 => ExtendedGraphletType::FourPathEdge,
-            1 => ExtendedGraphletType::Triangle,
-            0 => ExtendedGraphletType::Triad,
-            _ => panic!(
-                "Invalid graphlet type: {} (should be between 0 and 11)",
-                value
-            ),
+            7 => Ok(ReducedGraphletType::FourClique),
+            6 => Ok(ReducedGraphletType::ChordalCycle),
+            5 => Ok(ReducedGraphletType::TailedTri),
+            4 => Ok(ReducedGraphletType::FourCycle),
+            3 => Ok(ReducedGraphletType::FourStar),
+            2 => Ok(ReducedGraphletType::FourPath),
+            1 => Ok(ReducedGraphletType::Triangle),
+            0 => Ok(ReducedGraphletType::Triad),
+            _ => Err(InvalidGraphletType {
+                value,
+                number_of_graphlet_types: 8,
+            }),
         }
     }
 }
 
 impl From<u8> for ReducedGraphletType {
     fn from(value: u8) -> Self {
-        match value {
-            7 => ReducedGraphletType::FourClique,
-            6 => ReducedGraphletType::ChordalCycle,
-            5 => ReducedGraphletType::TailedTri,
-            4 => ReducedGraphletType::FourCycle,
-            3 => ReducedGraphletType::FourStar,
-            2 => ReducedGraphletType::FourPath,
-            1 => ReducedGraphletType::Triangle,
-            0 => ReducedGraphletType::Triad,
-            _ => panic!(
-                "Invalid graphlet type: {} (should be between 0 and 7)",
-                value
-            ),
-        }
+        ReducedGraphletType::try_from_u8(value).unwrap_or_else(|error| panic!("{}", error))
     }
 }
 
@@ -337,4 +669,199 @@ impl From<ExtendedGraphletType> for usize {
     fn from(value: ExtendedGraphletType) -> Self {
         u8::from(value) as usize
     }
-}
\ No newline at end of file
+}
+
+/// The edge orbits of the five-node graphlets counted by
+/// [`get_heterogeneous_5_graphlet`](crate::prelude::HeterogeneousGraphlets::get_heterogeneous_5_graphlet).
+///
+/// # Implementation details
+/// Differently from the four-node graphlets, only the orbits of the graphlets whose
+/// induced subgraph can be recognized directly from the traversal are available.
+/// The trees other than the five-path and the four-star, and the graphlets with
+/// cycles other than the five-cycle and the five-clique, are not counted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FiveNodeGraphletType {
+    /// The orbit where the edge is one of the edges of a five-clique.
+    FiveClique,
+    /// The orbit where the edge is one of the edges of a five-cycle.
+    FiveCycle,
+    /// The orbit where the edge is one of the four edges of a four-star centered in one of its nodes.
+    FiveStar,
+    /// The orbit where the edge is one of the two inner edges of a five-node path.
+    FivePathInner,
+    /// The orbit where the edge is at an end of a five-node path.
+    FivePathEdge,
+}
+
+impl FiveNodeGraphletType {
+    /// The number of graphlet types.
+    pub const COUNT: usize = 5;
+
+    /// Returns an iterator over all the graphlet types, in their numeric order.
+    pub fn all() -> impl Iterator<Item = FiveNodeGraphletType> {
+        (0..Self::COUNT as u8).map(FiveNodeGraphletType::from)
+    }
+
+    /// Returns the index of the graphlet type, between zero and [`FiveNodeGraphletType::COUNT`].
+    pub fn orbit_index(&self) -> usize {
+        u8::from(*self) as usize
+    }
+
+    /// Returns the graphlet type associated to the provided value.
+    ///
+    /// # Arguments
+    /// * `value` - The value to convert, as returned by the `u8` conversion of the graphlet type.
+    ///
+    /// # Raises
+    /// * If the provided value is not associated to any graphlet type.
+    ///
+    /// # Implementation details
+    /// As for [`ExtendedGraphletType::try_from_u8`], a `TryFrom<u8>` implementation
+    /// would conflict with the `From<u8>` conversion.
+    pub fn try_from_u8(value: u8) -> Result<Self, InvalidGraphletType> {
+        match value {
+            4 => Ok(FiveNodeGraphletType::FiveClique),
+            3 => Ok(FiveNodeGraphletType::FiveCycle),
+            2 => Ok(FiveNodeGraphletType::FiveStar),
+            1 => Ok(FiveNodeGraphletType::FivePathInner),
+            0 => Ok(FiveNodeGraphletType::FivePathEdge),
+            _ => Err(InvalidGraphletType {
+                value,
+                number_of_graphlet_types: 5,
+            }),
+        }
+    }
+}
+
+impl From<&FiveNodeGraphletType> for &str {
+    fn from(value: &FiveNodeGraphletType) -> Self {
+        match value {
+            FiveNodeGraphletType::FiveClique => "FiveClique",
+            FiveNodeGraphletType::FiveCycle => "FiveCycle",
+            FiveNodeGraphletType::FiveStar => "FiveStar",
+            FiveNodeGraphletType::FivePathInner => "FivePathInner",
+            FiveNodeGraphletType::FivePathEdge => "FivePathEdge",
+        }
+    }
+}
+
+impl core::fmt::Display for FiveNodeGraphletType {
+    fn fmt(&self, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.pad(self.into())
+    }
+}
+
+impl From<u8> for FiveNodeGraphletType {
+    fn from(value: u8) -> Self {
+        FiveNodeGraphletType::try_from_u8(value).unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+impl From<FiveNodeGraphletType> for u8 {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        match value {
+            FiveNodeGraphletType::FiveClique => 4,
+            FiveNodeGraphletType::FiveCycle => 3,
+            FiveNodeGraphletType::FiveStar => 2,
+            FiveNodeGraphletType::FivePathInner => 1,
+            FiveNodeGraphletType::FivePathEdge => 0,
+        }
+    }
+}
+
+impl GraphletSet<u8> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> u8 {
+        5
+    }
+}
+
+impl GraphletSet<u16> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> u16 {
+        5
+    }
+}
+
+impl From<u16> for FiveNodeGraphletType {
+    fn from(value: u16) -> Self {
+        FiveNodeGraphletType::from(value as u8)
+    }
+}
+
+impl From<FiveNodeGraphletType> for u16 {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        u8::from(value) as u16
+    }
+}
+
+impl GraphletSet<u32> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> u32 {
+        5
+    }
+}
+
+impl From<u32> for FiveNodeGraphletType {
+    fn from(value: u32) -> Self {
+        FiveNodeGraphletType::from(value as u8)
+    }
+}
+
+impl From<FiveNodeGraphletType> for u32 {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        u8::from(value) as u32
+    }
+}
+
+impl GraphletSet<u64> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> u64 {
+        5
+    }
+}
+
+impl From<u64> for FiveNodeGraphletType {
+    fn from(value: u64) -> Self {
+        FiveNodeGraphletType::from(value as u8)
+    }
+}
+
+impl From<FiveNodeGraphletType> for u64 {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        u8::from(value) as u64
+    }
+}
+
+impl GraphletSet<usize> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> usize {
+        5
+    }
+}
+
+impl From<usize> for FiveNodeGraphletType {
+    fn from(value: usize) -> Self {
+        FiveNodeGraphletType::from(value as u8)
+    }
+}
+
+impl From<FiveNodeGraphletType> for usize {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        u8::from(value) as usize
+    }
+}
+
+impl GraphletSet<u128> for FiveNodeGraphletType {
+    fn get_number_of_graphlets() -> u128 {
+        5
+    }
+}
+
+impl From<u128> for FiveNodeGraphletType {
+    fn from(value: u128) -> Self {
+        FiveNodeGraphletType::from(value as u8)
+    }
+}
+
+impl From<FiveNodeGraphletType> for u128 {
+    fn from(value: FiveNodeGraphletType) -> Self {
+        u8::from(value) as u128
+    }
+}
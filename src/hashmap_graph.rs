@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use crate::numbers::*;
+use crate::perfect_graphlet_hash::GraphletHashLayout;
+use crate::prelude::*;
+
+/// A typed graph built from an adjacency map with arbitrary node ids.
+///
+/// The node ids are mapped to the dense range `0..number_of_nodes` following
+/// their ascending order, and the neighbours of each node are sorted, so that
+/// adjacency maps with non-contiguous ids can be used to count graphlets.
+/// The original ids can be retrieved with [`HashMapGraph::get_node_id`].
+pub struct HashMapGraph<Id: Hash + Ord + Copy> {
+    /// The original id of each dense node.
+    ids: Vec<Id>,
+    /// The dense node associated to each original id.
+    nodes: HashMap<Id, usize>,
+    /// The offsets of the neighbours of each node.
+    offsets: Vec<usize>,
+    /// The sorted neighbours of each node.
+    neighbours: Vec<usize>,
+    /// The label of each dense node.
+    node_labels: Vec<usize>,
+    /// The number of node labels of the graph.
+    number_of_node_labels: usize,
+}
+
+impl<Id: Hash + Ord + Copy + Debug> HashMapGraph<Id> {
+    /// Returns a new graph from the provided adjacency map and node labels.
+    ///
+    /// # Arguments
+    /// * `adjacency` - The neighbours of each node id.
+    /// * `node_labels` - The label of each node id, which defines the nodes of the graph.
+    ///
+    /// # Raises
+    /// * If a node id of the adjacency map has no label.
+    ///
+    /// # Implementation details
+    /// The number of node labels is the largest node label plus one. Nodes without
+    /// an entry in the adjacency map have no neighbours, and duplicated neighbours
+    /// are discarded.
+    pub fn new(
+        adjacency: &HashMap<Id, Vec<Id>>,
+        node_labels: &HashMap<Id, usize>,
+    ) -> Result<Self, String> {
+        let mut ids = node_labels.keys().copied().collect::<Vec<Id>>();
+        ids.sort_unstable();
+        let nodes = ids
+            .iter()
+            .enumerate()
+            .map(|(node, &id)| (id, node))
+            .collect::<HashMap<Id, usize>>();
+
+        let get_node = |id: &Id| {
+            nodes
+                .get(id)
+                .copied()
+                .ok_or_else(|| format!("The node id {:?} has no node label.", id))
+        };
+
+        for id in adjacency.keys() {
+            get_node(id)?;
+        }
+
+        let mut offsets = Vec::with_capacity(ids.len() + 1);
+        let mut neighbours = Vec::new();
+        offsets.push(0);
+        for id in ids.iter() {
+            let mut node_neighbours = adjacency
+                .get(id)
+                .into_iter()
+                .flatten()
+                .map(get_node)
+                .collect::<Result<Vec<usize>, String>>()?;
+            node_neighbours.sort_unstable();
+            node_neighbours.dedup();
+            neighbours.extend(node_neighbours);
+            offsets.push(neighbours.len());
+        }
+
+        let node_labels = ids.iter().map(|id| node_labels[id]).collect::<Vec<usize>>();
+        let number_of_node_labels = node_labels.iter().max().map_or(0, |&label| label + 1);
+
+        Ok(Self {
+            ids,
+            nodes,
+            offsets,
+            neighbours,
+            node_labels,
+            number_of_node_labels,
+        })
+    }
+
+    /// Returns the original id of the provided dense node.
+    ///
+    /// # Arguments
+    /// * `node` - The dense node whose id should be returned.
+    pub fn get_node_id(&self, node: usize) -> Id {
+        self.ids[node]
+    }
+
+    /// Returns the dense node associated to the provided id, if any.
+    ///
+    /// # Arguments
+    /// * `id` - The id whose dense node should be returned.
+    pub fn get_node_from_id(&self, id: Id) -> Option<usize> {
+        self.nodes.get(&id).copied()
+    }
+}
+
+impl<Id: Hash + Ord + Copy> Graph for HashMapGraph<Id> {
+    type Node = usize;
+    type NeighbourIter<'a>
+        = std::iter::Copied<std::slice::Iter<'a, usize>>
+    where
+        Self: 'a;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.ids.len()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.neighbours[self.offsets[node]..self.offsets[node + 1]]
+            .iter()
+            .copied()
+    }
+}
+
+impl<Id: Hash + Ord + Copy> TypedGraph for HashMapGraph<Id> {
+    type NodeLabel = usize;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.number_of_node_labels
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl<Id, Graphlet, Count, Layout> HeterogeneousGraphlets<Graphlet, Count, Layout>
+    for HashMapGraph<Id>
+where
+    Id: Hash + Ord + Copy,
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+    Graphlet: Copy
+        + Debug
+        + Eq
+        + Hash
+        + Maximal
+        + Primitive<usize>
+        + From<ReducedGraphletType>
+        + From<ExtendedGraphletType>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + One
+        + Zero
+        + Ord,
+    u128: Primitive<Graphlet>,
+    ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, usize>,
+{
+    type GraphLetCounter = HashMap<Graphlet, Count>;
+}
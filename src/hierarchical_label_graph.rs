@@ -0,0 +1,135 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+use alloc::boxed::Box;
+
+/// A view of a typed graph whose node labels are coarsened along a label hierarchy.
+///
+/// The graph is structurally identical to the wrapped one, but the label of each
+/// node is replaced by its ancestor at the chosen level of the hierarchy, so that
+/// the graphlets can be counted at any granularity of the labels, e.g. counting
+/// "mammal" and "bird" nodes together as "animal" nodes.
+pub struct HierarchicalLabelGraph<'a, G: TypedGraph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The mapping from a label and a level of the hierarchy to the label at that level.
+    coarsen: Box<dyn Fn(G::NodeLabel, usize) -> G::NodeLabel + 'a>,
+    /// The level of the hierarchy at which the node labels are coarsened.
+    level: usize,
+    /// The number of node labels at the current level.
+    number_of_node_labels: usize,
+}
+
+impl<'a, G: TypedGraph> HierarchicalLabelGraph<'a, G> {
+    /// Returns a view of the provided graph with its node labels coarsened at the provided level.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose node labels should be coarsened.
+    /// * `coarsen` - The mapping from a label and a level of the hierarchy to the label at that level.
+    /// * `level` - The level of the hierarchy at which the node labels are coarsened.
+    ///
+    /// # Implementation details
+    /// The number of node labels at the provided level is the largest coarsened label,
+    /// over all the node labels of the wrapped graph, plus one. Coarsened labels are not
+    /// compacted: a [`CompactLabeledGraph`] can be built on top of this view to avoid
+    /// inflating the graphlet hash space with coarsened labels that do not appear.
+    pub fn new<F>(graph: &'a G, coarsen: F, level: usize) -> Self
+    where
+        F: Fn(G::NodeLabel, usize) -> G::NodeLabel + 'a,
+    {
+        let mut hierarchical_graph = Self {
+            graph,
+            coarsen: Box::new(coarsen),
+            level,
+            number_of_node_labels: 0,
+        };
+        hierarchical_graph.set_level(level);
+        hierarchical_graph
+    }
+
+    /// Returns the label at the provided level of the hierarchy of the provided label.
+    ///
+    /// # Arguments
+    /// * `label` - The label of the wrapped graph to coarsen.
+    /// * `level` - The level of the hierarchy of the returned label.
+    pub fn coarsen(&self, label: G::NodeLabel, level: usize) -> G::NodeLabel {
+        (self.coarsen)(label, level)
+    }
+
+    /// Returns the level of the hierarchy at which the node labels are coarsened.
+    pub fn level(&self) -> usize {
+        self.level
+    }
+
+    /// Sets the level of the hierarchy at which the node labels are coarsened.
+    ///
+    /// # Arguments
+    /// * `level` - The level of the hierarchy at which the node labels are coarsened.
+    pub fn set_level(&mut self, level: usize) {
+        self.level = level;
+        self.number_of_node_labels = (0..self.graph.get_number_of_node_labels_usize())
+            .map(|label_index| {
+                self.graph.get_node_label_index(
+                    self.coarsen(self.graph.get_node_label_from_usize(label_index), level),
+                ) + 1
+            })
+            .max()
+            .unwrap_or(0);
+    }
+}
+
+impl<'a, G: TypedGraph> Graph for HierarchicalLabelGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = G::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for HierarchicalLabelGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph
+            .get_node_label_from_usize(self.number_of_node_labels)
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.coarsen(self.graph.get_node_label(node), self.level)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(HierarchicalLabelGraph);
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use crate::numbers::*;
+use crate::perfect_graphlet_hash::GraphletHashLayout;
+use crate::prelude::*;
+
+/// A typed graph whose adjacency is defined by a predicate on pairs of nodes.
+///
+/// The graph has no explicit edge list: two distinct nodes are adjacent if the
+/// predicate holds for them, e.g. when their features are closer than a cutoff.
+/// The predicate must be symmetric, and it is never evaluated on a node and itself.
+///
+/// # Implementation details
+/// Iterating over the neighbours of a node evaluates the predicate on all the nodes
+/// of the graph, so each neighbourhood costs O(n) predicate evaluations, and counting
+/// the edges costs O(n^2) ones. Membership checks evaluate the predicate once.
+pub struct ImplicitGraph<F: Fn(usize, usize) -> bool> {
+    /// The predicate defining whether two distinct nodes are adjacent.
+    predicate: F,
+    /// The label of each node.
+    node_labels: Vec<usize>,
+    /// The number of node labels of the graph.
+    number_of_node_labels: usize,
+}
+
+impl<F: Fn(usize, usize) -> bool> ImplicitGraph<F> {
+    /// Returns a new graph from the provided adjacency predicate and node labels.
+    ///
+    /// # Arguments
+    /// * `predicate` - The predicate defining whether two distinct nodes are adjacent.
+    /// * `node_labels` - The label of each node, which defines the nodes of the graph.
+    ///
+    /// # Implementation details
+    /// The number of node labels is the largest node label plus one.
+    pub fn new(predicate: F, node_labels: Vec<usize>) -> Self {
+        let number_of_node_labels = node_labels.iter().max().map_or(0, |&label| label + 1);
+        Self {
+            predicate,
+            node_labels,
+            number_of_node_labels,
+        }
+    }
+}
+
+/// Iterator over the neighbours of a node of an implicit graph.
+pub struct ImplicitNeighbourIter<'a, F: Fn(usize, usize) -> bool> {
+    /// The predicate defining whether two distinct nodes are adjacent.
+    predicate: &'a F,
+    /// The node whose neighbours are iterated over.
+    node: usize,
+    /// The candidate neighbours that are yet to be checked.
+    candidates: std::ops::Range<usize>,
+}
+
+impl<'a, F: Fn(usize, usize) -> bool> Iterator for ImplicitNeighbourIter<'a, F> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node;
+        let predicate = self.predicate;
+        self.candidates
+            .by_ref()
+            .find(|&candidate| candidate != node && predicate(node, candidate))
+    }
+}
+
+impl<F: Fn(usize, usize) -> bool> Graph for ImplicitGraph<F> {
+    type Node = usize;
+    type NeighbourIter<'a>
+        = ImplicitNeighbourIter<'a, F>
+    where
+        Self: 'a;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.node_labels.len()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        (0..self.get_number_of_nodes())
+            .map(|node| self.get_node_degree(node))
+            .sum()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        ImplicitNeighbourIter {
+            predicate: &self.predicate,
+            node,
+            candidates: 0..self.get_number_of_nodes(),
+        }
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = true;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        node != other && (self.predicate)(node, other)
+    }
+}
+
+impl<F: Fn(usize, usize) -> bool> TypedGraph for ImplicitGraph<F> {
+    type NodeLabel = usize;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.number_of_node_labels
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl<F, Graphlet, Count, Layout> HeterogeneousGraphlets<Graphlet, Count, Layout>
+    for ImplicitGraph<F>
+where
+    F: Fn(usize, usize) -> bool,
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+    Graphlet: Copy
+        + Debug
+        + Eq
+        + Hash
+        + Maximal
+        + Primitive<usize>
+        + From<ReducedGraphletType>
+        + From<ExtendedGraphletType>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + One
+        + Zero
+        + Ord,
+    u128: Primitive<Graphlet>,
+    ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, usize>,
+{
+    type GraphLetCounter = HashMap<Graphlet, Count>;
+}
@@ -0,0 +1,128 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A view of a graph restricted to the subgraph induced by a set of nodes.
+///
+/// The nodes outside of the set have no neighbours, and the neighbours
+/// of the nodes inside of the set are restricted to the set.
+pub struct InducedSubgraph<'a, G: Graph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The sorted nodes of the subgraph.
+    nodes: Vec<usize>,
+}
+
+impl<'a, G: Graph> InducedSubgraph<'a, G> {
+    /// Returns a view of the provided graph restricted to the provided nodes.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph to restrict.
+    /// * `nodes` - The nodes inducing the subgraph, in any order and possibly repeated.
+    pub fn new(graph: &'a G, mut nodes: Vec<usize>) -> Self {
+        nodes.sort_unstable();
+        nodes.dedup();
+        Self { graph, nodes }
+    }
+
+    /// Returns whether the provided node is inside of the subgraph.
+    ///
+    /// # Arguments
+    /// * `node` - The node to check.
+    pub fn contains(&self, node: usize) -> bool {
+        self.nodes.binary_search(&node).is_ok()
+    }
+
+    /// Iterates over the sorted nodes inside of the subgraph.
+    pub fn iter_nodes(&self) -> core::iter::Copied<core::slice::Iter<'_, usize>> {
+        self.nodes.iter().copied()
+    }
+}
+
+/// Iterator over the neighbours of a node that fall within an induced subgraph.
+pub struct InducedNeighbourIter<'a, I> {
+    /// The iterator over all the neighbours of the node.
+    neighbours: I,
+    /// The sorted nodes of the subgraph that may still be yielded.
+    nodes: &'a [usize],
+}
+
+impl<'a, I: Iterator<Item = usize>> Iterator for InducedNeighbourIter<'a, I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Since both the neighbours and the nodes are sorted, we can merge them.
+        for neighbour in self.neighbours.by_ref() {
+            let position = self.nodes.partition_point(|&node| node < neighbour);
+            self.nodes = &self.nodes[position..];
+            match self.nodes.first() {
+                None => return None,
+                Some(&node) if node == neighbour => return Some(neighbour),
+                Some(_) => {}
+            }
+        }
+        None
+    }
+}
+
+impl<'a, G: Graph> Graph for InducedSubgraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = InducedNeighbourIter<'b, G::NeighbourIter<'b>>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.iter_nodes()
+            .map(|node| self.get_node_degree(node))
+            .sum()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        let nodes: &[usize] = if self.contains(node) {
+            &self.nodes
+        } else {
+            &[]
+        };
+        InducedNeighbourIter {
+            neighbours: self.graph.iter_neighbours(node),
+            nodes,
+        }
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.contains(node) && self.contains(other) && self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for InducedSubgraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(InducedSubgraph);
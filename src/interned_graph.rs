@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use crate::numbers::*;
+use crate::perfect_graphlet_hash::GraphletHashLayout;
+use crate::prelude::*;
+
+/// A view of a graph whose node labels are interned from an arbitrary label type.
+///
+/// The node labels are mapped to the dense range `0..number_of_distinct_labels`
+/// in order of first appearance, so that owned labels such as `String` can be
+/// used to characterize the graphlets. The original labels can be retrieved
+/// with [`InternedGraph::original_label`].
+pub struct InternedGraph<'a, G: Graph, L: Hash + Eq + Clone> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The interned label of each node.
+    node_labels: Vec<usize>,
+    /// The mapping between the original labels and the interned labels.
+    label_map: LabelMap<L>,
+}
+
+/// A view of a graph whose node labels are strings, such as `"paper"` or `"author"`.
+pub type StringLabeledGraph<'a, G> = InternedGraph<'a, G, String>;
+
+impl<'a, G: Graph, L: Hash + Eq + Clone> InternedGraph<'a, G, L> {
+    /// Returns a view of the provided graph with the provided node labels interned.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose nodes should be labelled.
+    /// * `node_labels` - The label of each node of the graph.
+    ///
+    /// # Raises
+    /// * If the number of node labels does not match the number of nodes of the graph.
+    pub fn new<I: IntoIterator<Item = L>>(graph: &'a G, node_labels: I) -> Result<Self, String> {
+        let mut label_map = LabelMap::new();
+        let node_labels = node_labels
+            .into_iter()
+            .map(|label| label_map.get_or_insert(label))
+            .collect::<Vec<usize>>();
+
+        if node_labels.len() != graph.get_number_of_nodes() {
+            return Err(format!(
+                concat!(
+                    "The number of node labels ({}) does not match ",
+                    "the number of nodes of the graph ({})."
+                ),
+                node_labels.len(),
+                graph.get_number_of_nodes()
+            ));
+        }
+
+        Ok(Self {
+            graph,
+            node_labels,
+            label_map,
+        })
+    }
+
+    /// Returns the original label associated to the provided interned label.
+    ///
+    /// # Arguments
+    /// * `index` - The interned label whose original label should be returned.
+    pub fn original_label(&self, index: usize) -> &L {
+        self.label_map.get_label(index)
+    }
+
+    /// Returns the name of the provided interned label.
+    ///
+    /// # Arguments
+    /// * `index` - The interned label whose name should be returned.
+    pub fn label_name(&self, index: usize) -> &str
+    where
+        L: AsRef<str>,
+    {
+        self.original_label(index).as_ref()
+    }
+
+    /// Returns the mapping between the original labels and the interned labels.
+    pub fn get_label_map(&self) -> &LabelMap<L> {
+        &self.label_map
+    }
+
+    /// Returns extensive report describing the graphlets, with the original node labels.
+    ///
+    /// # Arguments
+    /// * `counter` - The graphlet counts computed on this graph.
+    ///
+    /// # Implementation details
+    /// Each line of the report contains the graphlet kind, the original labels of
+    /// the nodes of the graphlet and the count. The dummy label of the three-node
+    /// graphlets, as well as any label that does not decode to an interned label,
+    /// is omitted.
+    pub fn get_report<Graphlet, Count, Layout>(&self, counter: &HashMap<Graphlet, Count>) -> String
+    where
+        L: Display,
+        Count: Debug,
+        Graphlet: Copy + From<ExtendedGraphletType>,
+        ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+        Layout: GraphletHashLayout<Graphlet, usize>,
+    {
+        let mut report = String::new();
+        for (graphlet, count) in counter.iter() {
+            let (graphlet_kind, (first, second, third, fourth)): (ExtendedGraphletType, _) =
+                Layout::decode(*graphlet, self.label_map.len());
+            let labels = [first, second, third, fourth]
+                .into_iter()
+                .filter(|&label| label < self.label_map.len())
+                .map(|label| self.original_label(label).to_string())
+                .collect::<Vec<String>>();
+            report.push_str(&format!(
+                "{} ({}): {:?}\n",
+                graphlet_kind,
+                labels.join(", "),
+                count
+            ));
+        }
+        report
+    }
+}
+
+impl<'a, G: Graph, L: Hash + Eq + Clone> Graph for InternedGraph<'a, G, L> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = G::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: Graph, L: Hash + Eq + Clone> TypedGraph for InternedGraph<'a, G, L> {
+    type NodeLabel = usize;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.label_map.len()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.label_map.len()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl<'a, G, L, Graphlet, Count, Layout> HeterogeneousGraphlets<Graphlet, Count, Layout>
+    for InternedGraph<'a, G, L>
+where
+    G: Graph,
+    L: Hash + Eq + Clone,
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+    Graphlet: Copy
+        + Debug
+        + Eq
+        + Hash
+        + Maximal
+        + Primitive<usize>
+        + From<ReducedGraphletType>
+        + From<ExtendedGraphletType>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + One
+        + Zero
+        + Ord,
+    u128: Primitive<Graphlet>,
+    ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, usize>,
+{
+    type GraphLetCounter = HashMap<Graphlet, Count>;
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A dense mapping between an arbitrary set of labels and the range `0..number_of_labels`.
+///
+/// The graphlet hashes use the node labels as digits, so that labels drawn from a
+/// non-contiguous set such as `{5, 10, 42}` must be mapped to a dense range before
+/// counting, lest the hash space grows with the largest label instead of the number
+/// of distinct labels. The indices follow the order in which the labels are first
+/// inserted, and the original labels can be retrieved with [`LabelMap::get_label`].
+#[derive(Debug, Clone)]
+pub struct LabelMap<L: Hash + Eq + Clone> {
+    /// The index associated to each label.
+    indices: HashMap<L, usize>,
+    /// The label associated to each index.
+    labels: Vec<L>,
+}
+
+impl<L: Hash + Eq + Clone> Default for LabelMap<L> {
+    fn default() -> Self {
+        Self {
+            indices: HashMap::new(),
+            labels: Vec::new(),
+        }
+    }
+}
+
+impl<L: Hash + Eq + Clone> LabelMap<L> {
+    /// Returns a new empty label map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the index of the provided label, inserting it if it is not yet mapped.
+    ///
+    /// # Arguments
+    /// * `label` - The label whose index should be returned.
+    pub fn get_or_insert(&mut self, label: L) -> usize {
+        let labels = &mut self.labels;
+        *self.indices.entry(label).or_insert_with_key(|label| {
+            labels.push(label.clone());
+            labels.len() - 1
+        })
+    }
+
+    /// Returns the index of the provided label, if it is mapped.
+    ///
+    /// # Arguments
+    /// * `label` - The label whose index should be returned.
+    pub fn get_index(&self, label: &L) -> Option<usize> {
+        self.indices.get(label).copied()
+    }
+
+    /// Returns the label associated to the provided index.
+    ///
+    /// # Arguments
+    /// * `index` - The index whose label should be returned.
+    ///
+    /// # Raises
+    /// * If the index is not lower than the number of mapped labels.
+    pub fn get_label(&self, index: usize) -> &L {
+        &self.labels[index]
+    }
+
+    /// Returns the mapped labels, in the order of their indices.
+    pub fn get_labels(&self) -> &[L] {
+        &self.labels
+    }
+
+    /// Returns the number of mapped labels.
+    pub fn len(&self) -> usize {
+        self.labels.len()
+    }
+
+    /// Returns whether no label is mapped.
+    pub fn is_empty(&self) -> bool {
+        self.labels.is_empty()
+    }
+}
+
+impl<L: Hash + Eq + Clone> FromIterator<L> for LabelMap<L> {
+    fn from_iter<I: IntoIterator<Item = L>>(labels: I) -> Self {
+        let mut label_map = Self::new();
+        for label in labels {
+            label_map.get_or_insert(label);
+        }
+        label_map
+    }
+}
@@ -1,18 +1,88 @@
-#![feature(iter_advance_by)]
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
 pub mod graph;
+mod compact_labeled_graph;
+mod hierarchical_label_graph;
+mod product_label_graph;
 mod orbits;
 pub mod perfect_graphlet_hash;
 mod edge_typed_graphlets;
 mod graphlet_counter;
 mod numbers;
 mod graphlet_set;
+mod graphlet_mask;
+mod windowed_graph;
+mod induced_subgraph;
+mod self_loop_free_graph;
+mod rewired_graph;
+mod random_graph;
+#[cfg(feature = "std")]
+mod interned_graph;
+#[cfg(feature = "std")]
+mod label_map;
+#[cfg(feature = "std")]
+mod hashmap_graph;
+#[cfg(feature = "std")]
+mod implicit_graph;
+mod unlabeled_graph;
+#[cfg(feature = "std")]
+mod auto_width;
+#[cfg(feature = "std")]
+mod bounded_reducer;
+#[cfg(feature = "rayon")]
+mod atomic_graphlet_counter;
+#[cfg(feature = "roaring")]
+mod roaring_graph;
+#[cfg(feature = "serde")]
+mod graphlet_report;
+#[cfg(feature = "petgraph")]
+mod petgraph_adapter;
+#[cfg(feature = "rand")]
+mod roles;
 
 mod debug_typed_graph;
 
 pub mod prelude {
     pub use crate::graph::*;
+    #[cfg(feature = "csr")]
+    pub use crate::graph::builder::*;
+    #[cfg(feature = "csr")]
+    pub use crate::graph::csr::*;
+    pub use crate::compact_labeled_graph::*;
+    pub use crate::hierarchical_label_graph::*;
+    pub use crate::product_label_graph::*;
     pub use crate::graphlet_set::*;
+    pub use crate::graphlet_mask::*;
+    pub use crate::perfect_graphlet_hash::{decode, encode};
     pub use crate::graphlet_counter::*;
     pub use crate::edge_typed_graphlets::*;
+    pub use crate::windowed_graph::*;
+    pub use crate::induced_subgraph::*;
+    pub use crate::self_loop_free_graph::*;
+    pub use crate::rewired_graph::*;
+    pub use crate::random_graph::*;
+    #[cfg(feature = "std")]
+    pub use crate::interned_graph::*;
+    #[cfg(feature = "std")]
+    pub use crate::label_map::*;
+    #[cfg(feature = "std")]
+    pub use crate::hashmap_graph::*;
+    #[cfg(feature = "std")]
+    pub use crate::implicit_graph::*;
+    #[cfg(feature = "std")]
+    pub use crate::auto_width::*;
+    #[cfg(feature = "std")]
+    pub use crate::bounded_reducer::*;
+    #[cfg(feature = "rayon")]
+    pub use crate::atomic_graphlet_counter::*;
+    #[cfg(feature = "roaring")]
+    pub use crate::roaring_graph::*;
+    #[cfg(feature = "serde")]
+    pub use crate::graphlet_report::*;
+    #[cfg(feature = "petgraph")]
+    pub use crate::petgraph_adapter::*;
+    #[cfg(feature = "rand")]
+    pub use crate::roles::*;
 }
\ No newline at end of file
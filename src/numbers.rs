@@ -34,6 +34,22 @@ impl One for u128 {
     const ONE: Self = 1;
 }
 
+impl One for i32 {
+    const ONE: Self = 1;
+}
+
+impl One for i64 {
+    const ONE: Self = 1;
+}
+
+impl One for f32 {
+    const ONE: Self = 1.0;
+}
+
+impl One for f64 {
+    const ONE: Self = 1.0;
+}
+
 impl Two for u8 {
     const TWO: Self = 2;
 }
@@ -58,6 +74,22 @@ impl Two for u128 {
     const TWO: Self = 2;
 }
 
+impl Two for i32 {
+    const TWO: Self = 2;
+}
+
+impl Two for i64 {
+    const TWO: Self = 2;
+}
+
+impl Two for f32 {
+    const TWO: Self = 2.0;
+}
+
+impl Two for f64 {
+    const TWO: Self = 2.0;
+}
+
 impl Zero for u8 {
     const ZERO: Self = 0;
 }
@@ -82,6 +114,22 @@ impl Zero for u128 {
     const ZERO: Self = 0;
 }
 
+impl Zero for i32 {
+    const ZERO: Self = 0;
+}
+
+impl Zero for i64 {
+    const ZERO: Self = 0;
+}
+
+impl Zero for f32 {
+    const ZERO: Self = 0.0;
+}
+
+impl Zero for f64 {
+    const ZERO: Self = 0.0;
+}
+
 pub trait Primitive<Other> {
     fn convert(other: Other) -> Self;
 }
@@ -152,6 +200,12 @@ impl Primitive<usize> for u16 {
     }
 }
 
+impl Primitive<u8> for u64 {
+    fn convert(other: u8) -> Self {
+        other as Self
+    }
+}
+
 impl Primitive<u32> for u64 {
     fn convert(other: u32) -> Self {
         other as Self
@@ -236,6 +290,12 @@ impl Primitive<u128> for u128 {
     }
 }
 
+impl Primitive<u8> for u128 {
+    fn convert(other: u8) -> Self {
+        other as Self
+    }
+}
+
 impl Primitive<u16> for u128 {
     fn convert(other: u16) -> Self {
         other as Self
@@ -266,6 +326,204 @@ impl Primitive<u8> for usize {
     }
 }
 
+impl Primitive<u16> for usize {
+    fn convert(other: u16) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u32> for usize {
+    fn convert(other: u32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<usize> for i32 {
+    fn convert(other: usize) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<i32> for i32 {
+    fn convert(other: i32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<usize> for i64 {
+    fn convert(other: usize) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<i32> for i64 {
+    fn convert(other: i32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<i64> for i64 {
+    fn convert(other: i64) -> Self {
+        other as Self
+    }
+}
+
+/// Arithmetic operations returning `None` when the result is not representable.
+pub trait Checked: Sized {
+    /// Returns the sum of the two numbers, if it does not overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Returns the difference of the two numbers, if it does not underflow.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    /// Returns the product of the two numbers, if it does not overflow.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+}
+
+impl Checked for u8 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u8::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u8::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u8::checked_mul(self, other)
+    }
+}
+
+impl Checked for u16 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u16::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u16::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u16::checked_mul(self, other)
+    }
+}
+
+impl Checked for u32 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u32::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u32::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u32::checked_mul(self, other)
+    }
+}
+
+impl Checked for u64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u64::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u64::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u64::checked_mul(self, other)
+    }
+}
+
+impl Checked for usize {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        usize::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        usize::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        usize::checked_mul(self, other)
+    }
+}
+
+impl Checked for u128 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        u128::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        u128::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        u128::checked_mul(self, other)
+    }
+}
+
+impl Checked for i32 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i32::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i32::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i32::checked_mul(self, other)
+    }
+}
+
+impl Checked for i64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        i64::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        i64::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        i64::checked_mul(self, other)
+    }
+}
+
+/// Floating point operations saturate to infinity instead of overflowing,
+/// so the result is representable only when it is finite.
+impl Checked for f32 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other).filter(|result| result.is_finite())
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(self - other).filter(|result| result.is_finite())
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other).filter(|result| result.is_finite())
+    }
+}
+
+/// Floating point operations saturate to infinity instead of overflowing,
+/// so the result is representable only when it is finite.
+impl Checked for f64 {
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Some(self + other).filter(|result| result.is_finite())
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Some(self - other).filter(|result| result.is_finite())
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other).filter(|result| result.is_finite())
+    }
+}
+
 pub trait Maximal {
     const MAXIMAL: Self;
 }
@@ -292,4 +550,176 @@ impl Maximal for usize {
 
 impl Maximal for u128 {
     const MAXIMAL: Self = u128::MAX;
-}
\ No newline at end of file
+}
+
+impl Maximal for i32 {
+    const MAXIMAL: Self = i32::MAX;
+}
+
+impl Maximal for i64 {
+    const MAXIMAL: Self = i64::MAX;
+}
+
+impl Maximal for f32 {
+    const MAXIMAL: Self = f32::MAX;
+}
+
+impl Maximal for f64 {
+    const MAXIMAL: Self = f64::MAX;
+}
+
+impl Primitive<u8> for f32 {
+    fn convert(other: u8) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u16> for f32 {
+    fn convert(other: u16) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u32> for f32 {
+    fn convert(other: u32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u64> for f32 {
+    fn convert(other: u64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<usize> for f32 {
+    fn convert(other: usize) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u128> for f32 {
+    fn convert(other: u128) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f32> for f32 {
+    fn convert(other: f32) -> Self {
+        other
+    }
+}
+
+impl Primitive<u8> for f64 {
+    fn convert(other: u8) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u16> for f64 {
+    fn convert(other: u16) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u32> for f64 {
+    fn convert(other: u32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u64> for f64 {
+    fn convert(other: u64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<usize> for f64 {
+    fn convert(other: usize) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<u128> for f64 {
+    fn convert(other: u128) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f32> for f64 {
+    fn convert(other: f32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for f64 {
+    fn convert(other: f64) -> Self {
+        other
+    }
+}
+
+impl Primitive<i32> for f64 {
+    fn convert(other: i32) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<i64> for f64 {
+    fn convert(other: i64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for u8 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for u16 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for u32 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for u64 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for usize {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for u128 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for i32 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for i64 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
+
+impl Primitive<f64> for f32 {
+    fn convert(other: f64) -> Self {
+        other as Self
+    }
+}
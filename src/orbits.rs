@@ -1,13 +1,21 @@
+use crate::graphlet_set::ExtendedGraphletType;
 use crate::numbers::*;
-use std::ops::{Add, Div, Mul, Sub};
+use core::ops::{Add, Div, Mul, Sub};
 
 #[inline(always)]
 /// Returns the binomial of the provided number of base two.
 ///
 /// # Arguments
 /// * `x` - The number whose binomial with two should be computed.
-fn binomial_two<
-    C: Zero + One + Two + Ord + Mul<C, Output = C> + Sub<C, Output = C> + Div<C, Output = C> + Copy,
+pub(crate) fn binomial_two<
+    C: Zero
+        + One
+        + Two
+        + PartialOrd
+        + Mul<C, Output = C>
+        + Sub<C, Output = C>
+        + Div<C, Output = C>
+        + Copy,
 >(
     x: C,
 ) -> C {
@@ -93,7 +101,7 @@ pub(crate) fn get_homogeneously_typed_four_star_orbit_count<
         + Add<C, Output = C>
         + Sub<C, Output = C>
         + Div<C, Output = C>
-        + Ord
+        + PartialOrd
         + Zero
         + One
         + Two
@@ -130,7 +138,7 @@ pub(crate) fn get_heterogeneously_typed_four_star_orbit_count<
         + Add<C, Output = C>
         + Sub<C, Output = C>
         + Div<C, Output = C>
-        + Ord
+        + PartialOrd
         + Zero
         + One
         + Two
@@ -223,7 +231,7 @@ pub(crate) fn get_homogeneously_typed_chordal_cycle_center_orbit_count<
         + Add<C, Output = C>
         + Sub<C, Output = C>
         + Div<C, Output = C>
-        + Ord
+        + PartialOrd
         + Zero
         + One
         + Two
@@ -252,7 +260,7 @@ pub(crate) fn get_heterogeneously_typed_chordal_cycle_center_orbit_count<
         + Add<C, Output = C>
         + Sub<C, Output = C>
         + Div<C, Output = C>
-        + Ord
+        + PartialOrd
         + Zero
         + One
         + Two
@@ -265,3 +273,192 @@ pub(crate) fn get_heterogeneously_typed_chordal_cycle_center_orbit_count<
     number_of_triangles_with_row_label * number_of_triangles_with_column_label
         - number_of_four_cliques_count
 }
+
+#[inline(always)]
+/// Returns the binomial of the provided number of base two, if it does not overflow.
+///
+/// # Arguments
+/// * `x` - The number whose binomial with two should be computed.
+pub(crate) fn checked_binomial_two<
+    C: Zero + One + Two + PartialOrd + Sub<C, Output = C> + Div<C, Output = C> + Checked + Copy,
+>(
+    x: C,
+) -> Option<C> {
+    if x < C::TWO {
+        Some(C::ZERO)
+    } else {
+        Some(x.checked_mul(x - C::ONE)? / C::TWO)
+    }
+}
+
+#[inline(always)]
+/// Returns the number of 4-paths orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_homogeneously_typed_four_path_orbit_count`] for the arguments.
+pub(crate) fn checked_homogeneously_typed_four_path_orbit_count<C: Checked>(
+    typed_four_cycle_count: C,
+    number_of_src_neighbours: C,
+    number_of_dst_neighbours: C,
+) -> Option<C> {
+    number_of_src_neighbours
+        .checked_mul(number_of_dst_neighbours)?
+        .checked_sub(typed_four_cycle_count)
+}
+
+#[inline(always)]
+/// Returns the number of 4-paths orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_heterogeneously_typed_four_path_orbit_count`] for the arguments.
+pub(crate) fn checked_heterogeneously_typed_four_path_orbit_count<C: Checked>(
+    typed_four_cycle_count: C,
+    number_of_src_neighbours_with_row_label: C,
+    number_of_dst_neighbours_with_row_label: C,
+    number_of_src_neighbours_with_column_label: C,
+    number_of_dst_neighbours_with_column_label: C,
+) -> Option<C> {
+    number_of_src_neighbours_with_row_label
+        .checked_mul(number_of_dst_neighbours_with_column_label)?
+        .checked_add(
+            number_of_src_neighbours_with_column_label
+                .checked_mul(number_of_dst_neighbours_with_row_label)?,
+        )?
+        .checked_sub(typed_four_cycle_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed 4-star orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_homogeneously_typed_four_star_orbit_count`] for the arguments.
+pub(crate) fn checked_homogeneously_typed_four_star_orbit_count<
+    C: Zero + One + Two + PartialOrd + Sub<C, Output = C> + Div<C, Output = C> + Checked + Copy,
+>(
+    typed_tailed_triangle_tail_edge_count: C,
+    number_of_src_neighbours: C,
+    number_of_dst_neighbours: C,
+) -> Option<C> {
+    checked_binomial_two(number_of_src_neighbours)?
+        .checked_add(checked_binomial_two(number_of_dst_neighbours)?)?
+        .checked_sub(typed_tailed_triangle_tail_edge_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed 4-star orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_heterogeneously_typed_four_star_orbit_count`] for the arguments.
+pub(crate) fn checked_heterogeneously_typed_four_star_orbit_count<C: Checked>(
+    typed_tailed_triangle_tail_edge_count: C,
+    number_of_src_neighbours_with_row_label: C,
+    number_of_dst_neighbours_with_row_label: C,
+    number_of_src_neighbours_with_column_label: C,
+    number_of_dst_neighbours_with_column_label: C,
+) -> Option<C> {
+    number_of_src_neighbours_with_row_label
+        .checked_mul(number_of_src_neighbours_with_column_label)?
+        .checked_add(
+            number_of_dst_neighbours_with_column_label
+                .checked_mul(number_of_dst_neighbours_with_row_label)?,
+        )?
+        .checked_sub(typed_tailed_triangle_tail_edge_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed tailed triangle tri-edge orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_homogeneously_typed_tailed_triangle_tri_edge_orbit_count`] for the arguments.
+pub(crate) fn checked_homogeneously_typed_tailed_triangle_tri_edge_orbit_count<C: Checked>(
+    typed_chordal_cycle_edge_count: C,
+    number_of_triangles: C,
+    number_of_src_neighbours: C,
+    number_of_dst_neighbours: C,
+) -> Option<C> {
+    number_of_triangles
+        .checked_mul(number_of_src_neighbours.checked_add(number_of_dst_neighbours)?)?
+        .checked_sub(typed_chordal_cycle_edge_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed tailed triangle tri-edge orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_heterogeneously_typed_tailed_triangle_tri_edge_orbit_count`] for the arguments.
+pub(crate) fn checked_heterogeneously_typed_tailed_triangle_tri_edge_orbit_count<C: Checked>(
+    typed_chordal_cycle_edge_count: C,
+    number_of_triangles_with_row_label: C,
+    number_of_triangles_with_column_label: C,
+    number_of_src_neighbours_with_row_label: C,
+    number_of_dst_neighbours_with_row_label: C,
+    number_of_src_neighbours_with_column_label: C,
+    number_of_dst_neighbours_with_column_label: C,
+) -> Option<C> {
+    number_of_triangles_with_row_label
+        .checked_mul(
+            number_of_src_neighbours_with_column_label
+                .checked_add(number_of_dst_neighbours_with_column_label)?,
+        )?
+        .checked_add(
+            number_of_triangles_with_column_label.checked_mul(
+                number_of_src_neighbours_with_row_label
+                    .checked_add(number_of_dst_neighbours_with_row_label)?,
+            )?,
+        )?
+        .checked_sub(typed_chordal_cycle_edge_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed chordal-cycle center orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_homogeneously_typed_chordal_cycle_center_orbit_count`] for the arguments.
+pub(crate) fn checked_homogeneously_typed_chordal_cycle_center_orbit_count<
+    C: Zero + One + Two + PartialOrd + Sub<C, Output = C> + Div<C, Output = C> + Checked + Copy,
+>(
+    number_of_four_cliques_count: C,
+    number_of_triangles: C,
+) -> Option<C> {
+    checked_binomial_two(number_of_triangles)?.checked_sub(number_of_four_cliques_count)
+}
+
+#[inline(always)]
+/// Returns the number of typed chordal-cycle center orbit associated to the provided edge, if it does not overflow.
+///
+/// # Implementation details
+/// See [`get_heterogeneously_typed_chordal_cycle_center_orbit_count`] for the arguments.
+pub(crate) fn checked_heterogeneously_typed_chordal_cycle_center_orbit_count<C: Checked>(
+    number_of_four_cliques_count: C,
+    number_of_triangles_with_row_label: C,
+    number_of_triangles_with_column_label: C,
+) -> Option<C> {
+    number_of_triangles_with_row_label
+        .checked_mul(number_of_triangles_with_column_label)?
+        .checked_sub(number_of_four_cliques_count)
+}
+
+#[cold]
+/// Panics reporting the orbit whose count of the provided edge is not representable.
+///
+/// # Arguments
+/// * `orbit` - The orbit whose count overflowed.
+/// * `src` - The source node of the edge.
+/// * `dst` - The destination node of the edge.
+/// * `label_indices` - The label indices of the two nodes outside of the edge.
+pub(crate) fn orbit_count_overflow(
+    orbit: ExtendedGraphletType,
+    src: usize,
+    dst: usize,
+    label_indices: (usize, usize),
+) -> ! {
+    panic!(
+        concat!(
+            "The count of the {} orbits of the edge ({}, {}) with the label indices {:?} ",
+            "is not representable in the count type. This happens when the count type is ",
+            "too small, or when the graph is not simple, e.g. when it has self-loops, ",
+            "duplicated edges or edges appearing in a single direction."
+        ),
+        orbit, src, dst, label_indices
+    )
+}
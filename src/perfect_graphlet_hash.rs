@@ -1,21 +1,102 @@
-use crate::{graphlet_set::GraphletSet, numbers::Primitive};
-use std::{
+use crate::{
+    graphlet_set::{ExtendedGraphletType, GraphletSet},
+    numbers::{Checked, Maximal, One, Primitive, Zero},
+};
+use alloc::{format, string::String};
+use core::{
     fmt::Debug,
-    ops::{Add, Div, Mul, Rem},
+    ops::{Add, Div, Mul, Rem, Sub},
 };
 
 #[inline(always)]
-/// Returns the exponentiation of the provided number with the const exponent.
-fn integer_power<const EXPONENT: usize, T: Mul<T, Output = T> + Copy>(x: T) -> T {
-    let mut result = x;
-    for _ in 1..EXPONENT {
-        result = result * x;
-    }
-    result
+/// Returns the exponentiation of the provided number with the provided exponent.
+fn integer_power<T: Mul<T, Output = T> + One + Copy>(x: T, exponent: usize) -> T {
+    (0..exponent).fold(T::ONE, |result, _| result * x)
+}
+
+#[inline(always)]
+/// Returns the number whose digits in the provided base are the provided ones.
+///
+/// # Arguments
+/// * `most_significant` - The most significant digit, which is not bounded by the base.
+/// * `digits` - The remaining digits, from the most to the least significant.
+/// * `base` - The base of the digits.
+fn encode_digits<const N: usize, T: Mul<T, Output = T> + Add<T, Output = T> + Copy>(
+    most_significant: T,
+    digits: [T; N],
+    base: T,
+) -> T {
+    digits
+        .into_iter()
+        .fold(most_significant, |encoded, digit| encoded * base + digit)
+}
+
+/// Returns the number whose digits in the provided base are the provided ones, if it does not overflow.
+///
+/// # Arguments
+/// * `most_significant` - The most significant digit, which is not bounded by the base.
+/// * `digits` - The remaining digits, from the most to the least significant.
+/// * `base` - The base of the digits.
+fn try_encode_digits<const N: usize, T: Checked + Copy>(
+    most_significant: T,
+    digits: [T; N],
+    base: T,
+) -> Option<T> {
+    digits
+        .into_iter()
+        .try_fold(most_significant, |encoded, digit| {
+            encoded.checked_mul(base)?.checked_add(digit)
+        })
 }
 
-/// A trait for quadruple perfect hash functions.
-pub trait PerfectGraphletHash<
+#[inline(always)]
+/// Returns the most significant digit and the remaining digits of the provided number.
+///
+/// # Arguments
+/// * `encoded` - The number to decode.
+/// * `base` - The base of the digits.
+///
+/// # Implementation details
+/// This is the inverse of [`encode_digits`].
+fn decode_digits<
+    const N: usize,
+    T: Mul<T, Output = T> + Div<T, Output = T> + Rem<T, Output = T> + One + Copy,
+>(
+    encoded: T,
+    base: T,
+) -> (T, [T; N]) {
+    (
+        encoded / integer_power(base, N),
+        core::array::from_fn(|index| encoded / integer_power(base, N - 1 - index) % base),
+    )
+}
+
+#[inline(always)]
+/// Returns the base of the polynomial hashes of the provided number of elements.
+///
+/// # Implementation details
+/// The base is one more than the number of elements, so that the dummy label of
+/// the three-node graphlets, which is the number of elements itself, is a digit.
+/// With the number of elements as the base, the dummy label would carry over into
+/// the previous label, or into the graphlet kind for a single element, so that the
+/// three-node graphlets would collide with other labels or graphlet kinds.
+fn polynomial_base<Graphlet, Element>(number_of_elements: Element) -> Graphlet
+where
+    Graphlet: Primitive<Element> + One + Add<Output = Graphlet>,
+{
+    Graphlet::convert(number_of_elements) + Graphlet::ONE
+}
+
+/// A trait for the perfect hash functions of the tuples of `N` node labels.
+///
+/// # Implementation details
+/// The graphlet kind and the node labels are the digits of a number in base
+/// `b = number_of_elements + 1`, with the graphlet kind as the most significant digit:
+/// `kind * b^N + l0 * b^(N - 1) + ... + l(N - 1)`. The four-node graphlets use
+/// `N = 4`, with the dummy label filling the fourth position of the three-node
+/// graphlets, and the five-node graphlets use `N = 5`.
+pub trait NTuplePerfectHash<
+    const N: usize,
     Graphlet: Debug + Copy + Primitive<Element> + Mul<Output = Graphlet> + Add<Output = Graphlet>,
     Element: Mul<Element, Output = Element>
         + Add<Element, Output = Element>
@@ -26,6 +107,13 @@ pub trait PerfectGraphletHash<
         + Ord,
 >: Sized
 {
+    /// Returns the hash value associated to self, without the graphlet kind.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    fn encode_partially(&self, number_of_elements: Element) -> Graphlet;
+
     /// Returns the hash value associated to self and graphlet.
     ///
     /// # Arguments
@@ -40,10 +128,33 @@ pub trait PerfectGraphletHash<
     where
         Graphlet: From<GraphletKind>;
 
+    /// Returns the hash value associated to self and graphlet, checking for overflows.
+    ///
+    /// # Arguments
+    /// * `graphlet` - The graphlet type to encode.
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    /// # Raises
+    /// * If the number of elements or a label does not fit in the graphlet type.
+    /// * If a product or a sum of the hash computation overflows the graphlet type.
+    ///
+    /// # Implementation details
+    /// The hash is computed as in [`encode_with_graphlet`](NTuplePerfectHash::encode_with_graphlet),
+    /// which silently wraps around or panics on overflow depending on the build profile.
+    /// The largest number of labels each graphlet type supports is returned by
+    /// [`max_labels_for_width`].
+    fn try_encode_with_graphlet<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        &self,
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Result<Graphlet, String>
+    where
+        Graphlet: From<GraphletKind> + Checked + Maximal;
+
     /// Returns the graphlet type and object associated to the provided hash value.
     ///
     /// # Arguments
-    /// * `encoded` - The hash value whose quadruple should be computed.
+    /// * `encoded` - The hash value whose tuple should be computed.
     /// * `number_of_elements` - The number of elements in the graphlet.
     ///
     fn decode_with_graphlet<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
@@ -56,7 +167,7 @@ pub trait PerfectGraphletHash<
     /// Returns the graphlet type associated to the provided hash value.
     ///
     /// # Arguments
-    /// * `encoded` - The hash value whose quadruple should be computed.
+    /// * `encoded` - The hash value whose graphlet type should be computed.
     /// * `number_of_elements` - The number of elements in the graphlet.
     fn decode_graphlet_kind<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
         encoded: Graphlet,
@@ -68,19 +179,39 @@ pub trait PerfectGraphletHash<
     /// # Arguments
     /// * `number_of_elements` - The number of elements in the graphlet.
     ///
+    /// # Implementation details
+    /// The returned value is `kinds * b^N - 1`, with `b = number_of_elements + 1`,
+    /// which is the hash of the last graphlet kind with all the labels set to the
+    /// number of elements, as the dummy label is.
+    ///
+    /// # Example
+    /// ```
+    /// use heterogeneous_graphlets::perfect_graphlet_hash::NTuplePerfectHash;
+    /// use heterogeneous_graphlets::prelude::*;
+    ///
+    /// let maximal_hash =
+    ///     <[u8; 4] as NTuplePerfectHash<4, u16, u8>>::maximal_hash::<ExtendedGraphletType>;
+    /// assert_eq!(maximal_hash(1), 12 * 16 - 1);
+    /// assert_eq!(maximal_hash(2), 12 * 81 - 1);
+    /// assert_eq!(maximal_hash(3), 12 * 256 - 1);
+    /// ```
     fn maximal_hash<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
         number_of_elements: Element,
     ) -> Graphlet;
 }
 
 impl<
+        const N: usize,
         Graphlet: Debug
             + Copy
+            + One
+            + Zero
             + Primitive<Element>
             + Div<Output = Graphlet>
             + Rem<Output = Graphlet>
             + Mul<Output = Graphlet>
-            + Add<Output = Graphlet>,
+            + Add<Output = Graphlet>
+            + Sub<Output = Graphlet>,
         Element: Mul<Element, Output = Element>
             + Add<Element, Output = Element>
             + Primitive<Graphlet>
@@ -89,8 +220,17 @@ impl<
             + Copy
             + Debug
             + Ord,
-    > PerfectGraphletHash<Graphlet, Element> for (Element, Element, Element, Element)
+    > NTuplePerfectHash<N, Graphlet, Element> for [Element; N]
 {
+    #[inline(always)]
+    fn encode_partially(&self, number_of_elements: Element) -> Graphlet {
+        encode_digits(
+            Graphlet::ZERO,
+            self.map(Graphlet::convert),
+            polynomial_base(number_of_elements),
+        )
+    }
+
     #[inline(always)]
     fn encode_with_graphlet<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
         &self,
@@ -100,17 +240,49 @@ impl<
     where
         Graphlet: From<GraphletKind>,
     {
-        let graphlet_kind: Graphlet = graphlet_kind.into();
-        let number_of_elements: Graphlet = Graphlet::convert(number_of_elements);
-        let first: Graphlet = Graphlet::convert(self.0);
-        let second: Graphlet = Graphlet::convert(self.1);
-        let third: Graphlet = Graphlet::convert(self.2);
-        let fourth: Graphlet = Graphlet::convert(self.3);
-        graphlet_kind * integer_power::<4, Graphlet>(number_of_elements)
-            + first * integer_power::<3, Graphlet>(number_of_elements)
-            + second * integer_power::<2, Graphlet>(number_of_elements)
-            + third * integer_power::<1, Graphlet>(number_of_elements)
-            + fourth
+        encode_digits(
+            graphlet_kind.into(),
+            self.map(Graphlet::convert),
+            polynomial_base(number_of_elements),
+        )
+    }
+
+    fn try_encode_with_graphlet<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        &self,
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Result<Graphlet, String>
+    where
+        Graphlet: From<GraphletKind> + Checked + Maximal,
+    {
+        // The conversions to the graphlet type truncate the elements that do not fit.
+        let convert = |element: Element| {
+            let converted = Graphlet::convert(element);
+            (Element::convert(converted) == element).then_some(converted)
+        };
+        let overflow = || {
+            format!(
+                concat!(
+                    "The hash of the labels {:?} with {:?} elements overflows the graphlet ",
+                    "type, whose maximal value is {:?}. ",
+                    "See `max_labels_for_width` for the number of labels each graphlet type supports."
+                ),
+                self,
+                number_of_elements,
+                Graphlet::MAXIMAL
+            )
+        };
+        convert(number_of_elements).ok_or_else(overflow)?;
+        let mut labels = [Graphlet::ZERO; N];
+        for (label, &element) in labels.iter_mut().zip(self.iter()) {
+            *label = convert(element).ok_or_else(overflow)?;
+        }
+        try_encode_digits(
+            graphlet_kind.into(),
+            labels,
+            polynomial_base(number_of_elements),
+        )
+        .ok_or_else(overflow)
     }
 
     #[inline(always)]
@@ -121,16 +293,310 @@ impl<
     where
         Graphlet: From<GraphletKind>,
     {
-        let number_of_elements: Graphlet = Graphlet::convert(number_of_elements);
-        let graphlet_kind: Graphlet = encoded / integer_power::<4, Graphlet>(number_of_elements);
-        let encoded: Graphlet = encoded % integer_power::<4, Graphlet>(number_of_elements);
-        let first: Graphlet = encoded / integer_power::<3, Graphlet>(number_of_elements);
-        let encoded: Graphlet = encoded % integer_power::<3, Graphlet>(number_of_elements);
-        let second: Graphlet = encoded / integer_power::<2, Graphlet>(number_of_elements);
-        let encoded: Graphlet = encoded % integer_power::<2, Graphlet>(number_of_elements);
-        let third: Graphlet = encoded / integer_power::<1, Graphlet>(number_of_elements);
-        let encoded: Graphlet = encoded % integer_power::<1, Graphlet>(number_of_elements);
-        let fourth: Graphlet = encoded;
+        let (graphlet_kind, labels) =
+            decode_digits::<N, Graphlet>(encoded, polynomial_base(number_of_elements));
+        (graphlet_kind.into(), labels.map(Element::convert))
+    }
+
+    #[inline(always)]
+    fn decode_graphlet_kind<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        number_of_elements: Element,
+    ) -> GraphletKind {
+        let base: Graphlet = polynomial_base(number_of_elements);
+        (encoded / integer_power(base, N)).into()
+    }
+
+    #[inline(always)]
+    fn maximal_hash<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        number_of_elements: Element,
+    ) -> Graphlet {
+        let number_of_graphlets: Graphlet = GraphletKind::get_number_of_graphlets();
+        let base: Graphlet = polynomial_base(number_of_elements);
+        integer_power(base, N) * number_of_graphlets - Graphlet::ONE
+    }
+}
+
+/// A trait for the layouts of the graphlet hashes.
+///
+/// A layout defines how the graphlet kind and the quadruple of node labels
+/// characterizing a graphlet are packed into a single hash value.
+pub trait GraphletHashLayout<Graphlet, Element> {
+    /// Returns the hash value associated to the provided node labels and graphlet kind.
+    ///
+    /// # Arguments
+    /// * `labels` - The quadruple of node labels to encode.
+    /// * `graphlet_kind` - The graphlet kind to encode.
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    fn encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Graphlet
+    where
+        Graphlet: From<GraphletKind>;
+
+    /// Returns the hash value associated to the provided node labels and graphlet kind,
+    /// checking for overflows.
+    ///
+    /// # Arguments
+    /// * `labels` - The quadruple of node labels to encode.
+    /// * `graphlet_kind` - The graphlet kind to encode.
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    /// # Raises
+    /// * If the hash value does not fit in the graphlet type.
+    ///
+    /// # Implementation details
+    /// By default, the hash value is computed with [`encode`](GraphletHashLayout::encode)
+    /// and no check is performed: layouts whose hashes may overflow should override it.
+    fn try_encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Result<Graphlet, String>
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        Ok(Self::encode(labels, graphlet_kind, number_of_elements))
+    }
+
+    /// Returns the graphlet kind and node labels associated to the provided hash value.
+    ///
+    /// # Arguments
+    /// * `encoded` - The hash value to decode.
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    fn decode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        number_of_elements: Element,
+    ) -> (GraphletKind, (Element, Element, Element, Element))
+    where
+        Graphlet: From<GraphletKind>;
+
+    /// Returns the graphlet kind associated to the provided hash value.
+    ///
+    /// # Arguments
+    /// * `encoded` - The hash value to decode.
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    fn decode_graphlet_kind<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        number_of_elements: Element,
+    ) -> GraphletKind
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        Self::decode::<GraphletKind>(encoded, number_of_elements).0
+    }
+
+    /// Returns the maximal hash value that can be encoded.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    fn maximal_hash<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        number_of_elements: Element,
+    ) -> Graphlet;
+
+    /// Returns the dummy label filling the fourth position of three-node graphlets.
+    ///
+    /// # Arguments
+    /// * `number_of_elements` - The number of elements in the graphlet.
+    ///
+    /// # Implementation details
+    /// By default, the dummy label is the number of elements itself, which is
+    /// the first value that cannot be a node label.
+    fn dummy_label(number_of_elements: Element) -> Element {
+        number_of_elements
+    }
+}
+
+/// The default layout of the graphlet hashes.
+///
+/// The graphlet kind and the node labels are the digits of a number in
+/// base `b = number_of_elements + 1`, with the graphlet kind as the most significant
+/// digit: `kind * b^4 + l0 * b^3 + l1 * b^2 + l2 * b + l3`. The base is one more than
+/// the number of elements so that the dummy label of the three-node graphlets, see
+/// [`GraphletHashLayout::dummy_label`], is a digit and every hash can be decoded.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PolynomialLayout;
+
+impl<Graphlet, Element> GraphletHashLayout<Graphlet, Element> for PolynomialLayout
+where
+    Graphlet: Debug
+        + Copy
+        + Checked
+        + Maximal
+        + Primitive<Element>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>,
+    Element: Mul<Element, Output = Element>
+        + Add<Element, Output = Element>
+        + PartialEq
+        + Eq
+        + Copy
+        + Debug
+        + Ord,
+    [Element; 4]: NTuplePerfectHash<4, Graphlet, Element>,
+{
+    #[inline(always)]
+    fn encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Graphlet
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        [labels.0, labels.1, labels.2, labels.3]
+            .encode_with_graphlet(graphlet_kind, number_of_elements)
+    }
+
+    #[inline(always)]
+    fn try_encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        number_of_elements: Element,
+    ) -> Result<Graphlet, String>
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        [labels.0, labels.1, labels.2, labels.3]
+            .try_encode_with_graphlet(graphlet_kind, number_of_elements)
+    }
+
+    #[inline(always)]
+    fn decode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        number_of_elements: Element,
+    ) -> (GraphletKind, (Element, Element, Element, Element))
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        let (graphlet_kind, [first, second, third, fourth]) =
+            <[Element; 4]>::decode_with_graphlet(encoded, number_of_elements);
+        (graphlet_kind, (first, second, third, fourth))
+    }
+
+    #[inline(always)]
+    fn decode_graphlet_kind<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        number_of_elements: Element,
+    ) -> GraphletKind
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        <[Element; 4]>::decode_graphlet_kind(encoded, number_of_elements)
+    }
+
+    #[inline(always)]
+    fn maximal_hash<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        number_of_elements: Element,
+    ) -> Graphlet {
+        <[Element; 4]>::maximal_hash::<GraphletKind>(number_of_elements)
+    }
+}
+
+/// A layout whose hashes do not depend on the number of elements.
+///
+/// The graphlet kind and the node labels are the digits of a number in
+/// base `Element::MAXIMAL + 1`, and the dummy label of the three-node graphlets
+/// is the reserved sentinel `Element::MAXIMAL`. As a consequence, the same
+/// motif is encoded identically in graphs with a different number of node labels,
+/// at the cost of a much larger hash space.
+///
+/// # Implementation details
+/// Node labels must be strictly smaller than `Element::MAXIMAL`, and the graphlet
+/// type must be wide enough to hold `12 * (Element::MAXIMAL + 1)^4`, e.g. `u64`
+/// for `u8` node labels.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SentinelLayout;
+
+impl SentinelLayout {
+    #[inline(always)]
+    /// Returns the base of the hashes, which is the number of possible elements.
+    fn base<Graphlet, Element>() -> Graphlet
+    where
+        Graphlet: Primitive<Element> + One + Add<Output = Graphlet>,
+        Element: Maximal,
+    {
+        Graphlet::convert(Element::MAXIMAL) + Graphlet::ONE
+    }
+}
+
+impl<Graphlet, Element> GraphletHashLayout<Graphlet, Element> for SentinelLayout
+where
+    Graphlet: Debug
+        + Copy
+        + One
+        + Primitive<Element>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + Checked
+        + Maximal,
+    Element: Primitive<Graphlet> + Maximal + Copy + Debug,
+{
+    #[inline(always)]
+    fn encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        _number_of_elements: Element,
+    ) -> Graphlet
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        encode_digits(
+            graphlet_kind.into(),
+            [labels.0, labels.1, labels.2, labels.3].map(Graphlet::convert),
+            Self::base::<Graphlet, Element>(),
+        )
+    }
+
+    fn try_encode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        labels: (Element, Element, Element, Element),
+        graphlet_kind: GraphletKind,
+        _number_of_elements: Element,
+    ) -> Result<Graphlet, String>
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        // The base itself overflows the graphlet types not wider than the elements.
+        Graphlet::convert(Element::MAXIMAL)
+            .checked_add(Graphlet::ONE)
+            .and_then(|base| {
+                try_encode_digits(
+                    graphlet_kind.into(),
+                    [labels.0, labels.1, labels.2, labels.3].map(Graphlet::convert),
+                    base,
+                )
+            })
+            .ok_or_else(|| {
+                format!(
+                    concat!(
+                        "The sentinel hash of the labels {:?} overflows the graphlet type, ",
+                        "whose maximal value is {:?}."
+                    ),
+                    labels,
+                    Graphlet::MAXIMAL
+                )
+            })
+    }
+
+    #[inline(always)]
+    fn decode<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
+        encoded: Graphlet,
+        _number_of_elements: Element,
+    ) -> (GraphletKind, (Element, Element, Element, Element))
+    where
+        Graphlet: From<GraphletKind>,
+    {
+        let (graphlet_kind, [first, second, third, fourth]) =
+            decode_digits::<4, Graphlet>(encoded, Self::base::<Graphlet, Element>());
         (
             graphlet_kind.into(),
             (
@@ -142,27 +608,289 @@ impl<
         )
     }
 
-    #[inline(always)]
-    fn decode_graphlet_kind<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
-        encoded: Graphlet,
-        number_of_elements: Element,
-    ) -> GraphletKind {
-        let number_of_elements: Graphlet = Graphlet::convert(number_of_elements);
-        let graphlet_kind: Graphlet = encoded / integer_power::<4, Graphlet>(number_of_elements);
-        graphlet_kind.into()
-    }
-
     #[inline(always)]
     fn maximal_hash<GraphletKind: GraphletSet<Graphlet> + From<Graphlet>>(
-        number_of_elements: Element,
+        _number_of_elements: Element,
     ) -> Graphlet {
+        let base = Self::base::<Graphlet, Element>();
         let number_of_graphlets: Graphlet = GraphletKind::get_number_of_graphlets();
-        let number_of_elements: Graphlet = Graphlet::convert(number_of_elements);
+        // The maximal hash is the one of the last graphlet kind with all
+        // the labels set to the sentinel.
+        integer_power(base, 4) * number_of_graphlets - Graphlet::ONE
+    }
 
-        integer_power::<4, Graphlet>(number_of_elements) * number_of_graphlets
-            + integer_power::<4, Graphlet>(number_of_elements)
-            + integer_power::<3, Graphlet>(number_of_elements)
-            + integer_power::<2, Graphlet>(number_of_elements)
-            + number_of_elements
+    #[inline(always)]
+    fn dummy_label(_number_of_elements: Element) -> Element {
+        Element::MAXIMAL
     }
 }
+
+/// Returns the largest number of node labels whose graphlets fit in the provided graphlet type.
+///
+/// # Implementation details
+/// The returned value is the largest `n` such that the maximal hash of the extended
+/// graphlet set with the default [`PolynomialLayout`], that is `12 * (n + 1)^4 - 1`,
+/// is at most `Graphlet::MAXIMAL`. It is found by binary search, computing the
+/// maximal hash in `u128` with overflow checks.
+///
+/// As the hashes grow with the fourth power of the number of labels, the number of
+/// supported labels grows with the fourth root of the maximal value of the graphlet type:
+///
+/// | Graphlet type | Labels |
+/// |---------------|--------|
+/// | `u8`          | 1      |
+/// | `u16`         | 7      |
+/// | `u32`         | 136    |
+/// | `u64`         | 35,210 |
+///
+/// Hashes of graphs with more labels silently collide in release builds, while
+/// debug builds check each hash with [`GraphletHashLayout::try_encode`].
+pub fn max_labels_for_width<Graphlet>() -> usize
+where
+    Graphlet: Maximal,
+    u128: Primitive<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet>,
+{
+    let maximal_graphlet = u128::convert(Graphlet::MAXIMAL);
+    let number_of_graphlets =
+        u128::convert(<ExtendedGraphletType as GraphletSet<Graphlet>>::get_number_of_graphlets());
+    let fits = |number_of_elements: u128| {
+        (number_of_elements + 1)
+            .checked_pow(4)?
+            .checked_mul(number_of_graphlets)
+            .map(|hashes| hashes - 1)
+            .filter(|&maximal_hash| maximal_hash <= maximal_graphlet)
+    };
+
+    // Since `(2^32 + 1)^4` overflows a `u128`, the upper bound never fits.
+    let (mut lower_bound, mut upper_bound) = (0_u128, 1_u128 << 32);
+    while upper_bound - lower_bound > 1 {
+        let middle = lower_bound + (upper_bound - lower_bound) / 2;
+        if fits(middle).is_some() {
+            lower_bound = middle;
+        } else {
+            upper_bound = middle;
+        }
+    }
+    lower_bound as usize
+}
+
+/// Returns the number of bits needed to hold the graphlets of the provided number of node labels.
+///
+/// # Arguments
+/// * `number_of_node_labels` - The number of node labels of the graph.
+///
+/// # Implementation details
+/// The returned value is the number of bits of the maximal hash of the extended graphlet
+/// set with the default [`PolynomialLayout`], so that any unsigned graphlet type at least
+/// as wide can be used to count the graphlets:
+///
+/// | Labels                    | Bits       | Graphlet type |
+/// |---------------------------|------------|---------------|
+/// | up to 1                   | 4 to 8     | `u8`          |
+/// | 2 to 7                    | 10 to 16   | `u16`         |
+/// | 8 to 136                  | 17 to 32   | `u32`         |
+/// | 137 to 35,210             | 33 to 64   | `u64`         |
+/// | 35,211 to 2,307,621,356   | 65 to 128  | `u128`        |
+///
+/// When the maximal hash does not fit in a `u128`, no graphlet type can hold the hashes
+/// and `u128::BITS + 1` is returned.
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::perfect_graphlet_hash::required_graphlet_bits;
+///
+/// assert_eq!(required_graphlet_bits(7), 16);
+/// assert_eq!(required_graphlet_bits(8), 17);
+/// ```
+pub fn required_graphlet_bits(number_of_node_labels: usize) -> u32 {
+    if number_of_node_labels > max_labels_for_width::<u128>() {
+        return u128::BITS + 1;
+    }
+    let maximal_hash = <[u128; 4] as NTuplePerfectHash<4, u128, u128>>::maximal_hash::<
+        ExtendedGraphletType,
+    >(number_of_node_labels as u128);
+    u128::BITS - maximal_hash.leading_zeros()
+}
+
+/// Returns the hash of the provided graphlet kind and node labels.
+///
+/// This is a convenience wrapper of the default [`PolynomialLayout`], monomorphized
+/// to `u64` hashes and `usize` node labels, producing the same keys of the graphlet
+/// counters of graphs with `usize` node labels.
+///
+/// # Arguments
+/// * `graphlet_kind` - The graphlet kind to encode.
+/// * `labels` - The quadruple of node labels to encode, each smaller than the number of elements.
+/// * `number_of_elements` - The number of node labels of the graph.
+///
+/// # Implementation details
+/// The number of elements should not exceed [`max_labels_for_width::<u64>`](max_labels_for_width),
+/// or the hashes would overflow.
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::prelude::*;
+///
+/// let key = encode(ExtendedGraphletType::FourCycle, (0, 2, 1, 2), 3);
+/// assert_eq!(
+///     decode(key, 3),
+///     (ExtendedGraphletType::FourCycle, (0, 2, 1, 2))
+/// );
+/// ```
+pub fn encode(
+    graphlet_kind: ExtendedGraphletType,
+    labels: (usize, usize, usize, usize),
+    number_of_elements: usize,
+) -> u64 {
+    <PolynomialLayout as GraphletHashLayout<u64, usize>>::encode(
+        labels,
+        graphlet_kind,
+        number_of_elements,
+    )
+}
+
+/// Returns the graphlet kind and node labels associated to the provided hash.
+///
+/// This is the inverse of [`encode`], see its documentation for an example.
+///
+/// # Arguments
+/// * `encoded` - The hash to decode.
+/// * `number_of_elements` - The number of node labels of the graph.
+pub fn decode(
+    encoded: u64,
+    number_of_elements: usize,
+) -> (ExtendedGraphletType, (usize, usize, usize, usize)) {
+    <PolynomialLayout as GraphletHashLayout<u64, usize>>::decode(encoded, number_of_elements)
+}
+
+/// Returns the hash of the provided graphlet kind, node labels and edge labels.
+///
+/// # Arguments
+/// * `graphlet_kind` - The graphlet kind to encode.
+/// * `node_labels` - The quadruple of node labels to encode, each at most the number of node labels.
+/// * `edge_labels` - The edge labels of the six node pairs to encode, each at most the number of edge labels.
+/// * `number_of_node_labels` - The number of node labels of the graph.
+/// * `number_of_edge_labels` - The number of edge labels of the graph.
+///
+/// # Implementation details
+/// The graphlet kind, the node labels in base `n + 1` and the edge labels in base
+/// `m + 1` are the digits of a mixed-radix number, with the graphlet kind as the most
+/// significant digit, where `n` and `m` are the numbers of node and edge labels.
+/// The fourth node label of the three-node graphlets is the dummy label `n`, and the
+/// node pairs without an edge have the absent edge label `m`, which do not collide
+/// with the actual labels since the bases are one larger.
+///
+/// The node pairs are, in order, `(0, 1)`, `(0, 2)`, `(0, 3)`, `(1, 2)`, `(1, 3)`
+/// and `(2, 3)`, so that each edge label multiplies the hash space by `m + 1`, and
+/// the hashes are `(m + 1)^6` times as large as the node-labeled ones: see
+/// [`maximal_edge_typed_hash`] for the required graphlet width.
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::perfect_graphlet_hash::{decode_edge_typed, encode_edge_typed};
+/// use heterogeneous_graphlets::prelude::*;
+///
+/// // A triangle of labels (0, 1, 1), whose edges have labels 0, 1 and 1.
+/// let key = encode_edge_typed::<u32>(
+///     ExtendedGraphletType::Triangle,
+///     [0, 1, 1, 2],
+///     [0, 1, 2, 1, 2, 2],
+///     2,
+///     2,
+/// );
+/// assert_eq!(
+///     decode_edge_typed(key, 2, 2),
+///     (
+///         ExtendedGraphletType::Triangle,
+///         [0, 1, 1, 2],
+///         [0, 1, 2, 1, 2, 2]
+///     )
+/// );
+/// ```
+pub fn encode_edge_typed<Graphlet>(
+    graphlet_kind: ExtendedGraphletType,
+    node_labels: [Graphlet; 4],
+    edge_labels: [Graphlet; 6],
+    number_of_node_labels: Graphlet,
+    number_of_edge_labels: Graphlet,
+) -> Graphlet
+where
+    Graphlet:
+        Mul<Output = Graphlet> + Add<Output = Graphlet> + One + Copy + From<ExtendedGraphletType>,
+{
+    encode_digits(
+        encode_digits(
+            graphlet_kind.into(),
+            node_labels,
+            number_of_node_labels + Graphlet::ONE,
+        ),
+        edge_labels,
+        number_of_edge_labels + Graphlet::ONE,
+    )
+}
+
+/// Returns the graphlet kind, node labels and edge labels associated to the provided hash.
+///
+/// This is the inverse of [`encode_edge_typed`], see its documentation for an example.
+///
+/// # Arguments
+/// * `encoded` - The hash to decode.
+/// * `number_of_node_labels` - The number of node labels of the graph.
+/// * `number_of_edge_labels` - The number of edge labels of the graph.
+pub fn decode_edge_typed<Graphlet>(
+    encoded: Graphlet,
+    number_of_node_labels: Graphlet,
+    number_of_edge_labels: Graphlet,
+) -> (ExtendedGraphletType, [Graphlet; 4], [Graphlet; 6])
+where
+    Graphlet: Mul<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + One
+        + Copy,
+    ExtendedGraphletType: From<Graphlet>,
+{
+    let (encoded, edge_labels) =
+        decode_digits::<6, Graphlet>(encoded, number_of_edge_labels + Graphlet::ONE);
+    let (graphlet_kind, node_labels) =
+        decode_digits::<4, Graphlet>(encoded, number_of_node_labels + Graphlet::ONE);
+    (graphlet_kind.into(), node_labels, edge_labels)
+}
+
+/// Returns an upper bound of the hashes of [`encode_edge_typed`].
+///
+/// # Arguments
+/// * `number_of_node_labels` - The number of node labels of the graph.
+/// * `number_of_edge_labels` - The number of edge labels of the graph.
+///
+/// # Implementation details
+/// The returned value is `12 * (n + 1)^4 * (m + 1)^6`, the number of hashes of the
+/// twelve graphlet kinds, so that the hashes are strictly smaller than it. It is
+/// computed in `u128`, and is `None` when it overflows. The edge labels add about
+/// `6 * log2(m + 1)` bits to the hashes:
+///
+/// | Node labels | Edge labels | Bits |
+/// |-------------|-------------|------|
+/// | 1           | 1           | 14   |
+/// | 1           | 2           | 18   |
+/// | 4           | 4           | 27   |
+/// | 8           | 16          | 41   |
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::perfect_graphlet_hash::maximal_edge_typed_hash;
+///
+/// assert_eq!(maximal_edge_typed_hash(1, 1), Some(12 * 16 * 64));
+/// assert!(maximal_edge_typed_hash(4, 4).unwrap() <= u32::MAX as u128);
+/// ```
+pub fn maximal_edge_typed_hash(
+    number_of_node_labels: usize,
+    number_of_edge_labels: usize,
+) -> Option<u128> {
+    (number_of_node_labels as u128 + 1)
+        .checked_pow(4)?
+        .checked_mul((number_of_edge_labels as u128 + 1).checked_pow(6)?)?
+        .checked_mul(12)
+}
@@ -0,0 +1,196 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use petgraph::graph::{NodeIndex, UnGraph};
+
+use crate::numbers::*;
+use crate::perfect_graphlet_hash::GraphletHashLayout;
+use crate::prelude::*;
+
+/// A typed graph adapting an undirected petgraph graph.
+///
+/// The nodes of the graph are the node indices of the petgraph graph, and the label
+/// of each node is computed from its weight by the provided closure, so that graphs
+/// already built with petgraph can be used to count graphlets.
+///
+/// # Implementation details
+/// Petgraph iterates over the neighbours of a node in reverse insertion order of the
+/// edges, while the graphlet counting requires them to be sorted. The neighbours of
+/// each node are therefore sorted and deduplicated once upon construction, and
+/// cached in a compressed adjacency. Self-loops are kept as they are, and graphs
+/// having them should be counted through a [`SelfLoopFreeGraph`].
+///
+/// # Example
+/// ```
+/// use heterogeneous_graphlets::perfect_graphlet_hash::PolynomialLayout;
+/// use heterogeneous_graphlets::prelude::*;
+/// use petgraph::graph::UnGraph;
+/// use std::collections::HashMap;
+///
+/// // A triangle with a tail, whose node weights are the node labels.
+/// let mut graph = UnGraph::<u8, ()>::new_undirected();
+/// let a = graph.add_node(0);
+/// let b = graph.add_node(0);
+/// let c = graph.add_node(1);
+/// let d = graph.add_node(1);
+/// graph.extend_with_edges([(a, b), (b, c), (c, a), (c, d)]);
+///
+/// let adapter = PetgraphAdapter::new(&graph, |&label| label as usize);
+/// let counts: HashMap<u32, u32> =
+///     HeterogeneousGraphlets::<u32, u32>::count_all_graphlets(&adapter);
+///
+/// // Each graphlet is counted once for each of its edges.
+/// assert_eq!(counts.total_for_kind::<_, PolynomialLayout>(ExtendedGraphletType::Triangle, 2_usize), 3);
+/// assert_eq!(counts.total_for_kind::<_, PolynomialLayout>(ExtendedGraphletType::TailedTriTail, 2_usize), 1);
+/// ```
+pub struct PetgraphAdapter<'a, N, E> {
+    /// The adapted petgraph graph.
+    graph: &'a UnGraph<N, E>,
+    /// The offsets of the neighbours of each node.
+    offsets: Vec<usize>,
+    /// The sorted neighbours of each node.
+    neighbours: Vec<usize>,
+    /// The label of each node.
+    node_labels: Vec<usize>,
+    /// The number of node labels of the graph.
+    number_of_node_labels: usize,
+}
+
+impl<'a, N, E> PetgraphAdapter<'a, N, E> {
+    /// Returns a new adapter of the provided petgraph graph.
+    ///
+    /// # Arguments
+    /// * `graph` - The undirected petgraph graph to adapt.
+    /// * `node_label` - The closure returning the label of a node from its weight.
+    ///
+    /// # Implementation details
+    /// The number of node labels is the largest node label plus one. Parallel edges
+    /// are discarded, as duplicated neighbours would be.
+    pub fn new<F: Fn(&N) -> usize>(graph: &'a UnGraph<N, E>, node_label: F) -> Self {
+        let mut offsets = Vec::with_capacity(graph.node_count() + 1);
+        let mut neighbours = Vec::with_capacity(2 * graph.edge_count());
+        offsets.push(0);
+        for node in graph.node_indices() {
+            let mut node_neighbours = graph
+                .neighbors(node)
+                .map(|neighbour| neighbour.index())
+                .collect::<Vec<usize>>();
+            node_neighbours.sort_unstable();
+            node_neighbours.dedup();
+            neighbours.extend(node_neighbours);
+            offsets.push(neighbours.len());
+        }
+
+        let node_labels = graph.node_weights().map(node_label).collect::<Vec<usize>>();
+        let number_of_node_labels = node_labels.iter().max().map_or(0, |&label| label + 1);
+
+        Self {
+            graph,
+            offsets,
+            neighbours,
+            node_labels,
+            number_of_node_labels,
+        }
+    }
+
+    /// Returns the weight of the provided node in the petgraph graph.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose weight should be returned.
+    pub fn get_node_weight(&self, node: usize) -> &'a N {
+        &self.graph[NodeIndex::new(node)]
+    }
+}
+
+impl<'a, N, E> Graph for PetgraphAdapter<'a, N, E> {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = std::iter::Copied<std::slice::Iter<'b, usize>>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.node_labels.len()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.neighbours[self.offsets[node]..self.offsets[node + 1]]
+            .iter()
+            .copied()
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+}
+
+impl<'a, N, E> TypedGraph for PetgraphAdapter<'a, N, E> {
+    type NodeLabel = usize;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.number_of_node_labels
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl<'a, N, E, Graphlet, Count, Layout> HeterogeneousGraphlets<Graphlet, Count, Layout>
+    for PetgraphAdapter<'a, N, E>
+where
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+    Graphlet: Copy
+        + Debug
+        + Eq
+        + Hash
+        + Maximal
+        + Primitive<usize>
+        + From<ReducedGraphletType>
+        + From<ExtendedGraphletType>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + One
+        + Zero
+        + Ord,
+    u128: Primitive<Graphlet>,
+    ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, usize>,
+{
+    type GraphLetCounter = HashMap<Graphlet, Count>;
+}
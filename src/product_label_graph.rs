@@ -0,0 +1,129 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+use alloc::boxed::Box;
+
+/// A view of a typed graph whose node labels are combined with a second labeling.
+///
+/// The graph is structurally identical to the wrapped one, but the label of each
+/// node is the pair of its label in the wrapped graph and of its second label, e.g.
+/// the node type and the community of the node, so that the graphlets can be counted
+/// over the Cartesian product of the two labelings.
+///
+/// # Implementation details
+/// The pair of labels `(a, b)` is encoded as the label `a * n_b + b`, where `n_b` is
+/// the number of second labels, so that the number of node labels of the view is the
+/// product of the number of labels of the two labelings. Since the graphlet hash space
+/// grows with the fourth power of the number of node labels, the graphlet type must be
+/// chosen accordingly, and the combined labels must fit in the node label type of the
+/// wrapped graph.
+pub struct ProductLabelGraph<'a, G: TypedGraph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The mapping from a node to its second label.
+    second_label: Box<dyn Fn(usize) -> G::NodeLabel + 'a>,
+    /// The number of second labels.
+    number_of_second_labels: usize,
+}
+
+impl<'a, G: TypedGraph> ProductLabelGraph<'a, G> {
+    /// Returns a view of the provided graph with its node labels combined with the provided labeling.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose node labels should be combined.
+    /// * `second_label` - The mapping from a node to its second label.
+    /// * `number_of_second_labels` - The number of second labels.
+    pub fn new<F>(graph: &'a G, second_label: F, number_of_second_labels: usize) -> Self
+    where
+        F: Fn(usize) -> G::NodeLabel + 'a,
+    {
+        Self {
+            graph,
+            second_label: Box::new(second_label),
+            number_of_second_labels,
+        }
+    }
+
+    /// Returns the second label of the provided node.
+    ///
+    /// # Arguments
+    /// * `node` - The node whose second label should be returned.
+    pub fn get_second_label(&self, node: usize) -> G::NodeLabel {
+        (self.second_label)(node)
+    }
+
+    /// Returns the labels of the two labelings combined into the provided label.
+    ///
+    /// # Arguments
+    /// * `label` - The combined label to split.
+    pub fn split_label(&self, label: G::NodeLabel) -> (G::NodeLabel, G::NodeLabel) {
+        let label_index = self.graph.get_node_label_index(label);
+        (
+            self.graph
+                .get_node_label_from_usize(label_index / self.number_of_second_labels),
+            self.graph
+                .get_node_label_from_usize(label_index % self.number_of_second_labels),
+        )
+    }
+}
+
+impl<'a, G: TypedGraph> Graph for ProductLabelGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = G::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for ProductLabelGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph
+            .get_node_label_from_usize(self.get_number_of_node_labels_usize())
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize() * self.number_of_second_labels
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(
+            self.graph
+                .get_node_label_index(self.graph.get_node_label(node))
+                * self.number_of_second_labels
+                + self.graph.get_node_label_index(self.get_second_label(node)),
+        )
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(ProductLabelGraph);
@@ -0,0 +1,168 @@
+use crate::prelude::*;
+use alloc::vec::Vec;
+
+/// A random undirected typed graph, mostly meant for testing and benchmarking.
+///
+/// # Implementation details
+/// The graph is generated in two steps:
+///
+/// 1. Every node but the first one is connected to a uniformly sampled node with a
+///    smaller ID, which yields a random recursive tree and makes the graph connected.
+/// 2. Every node, including the first one, is connected to further `edges_per_node - 1`
+///    uniformly sampled distinct nodes, which adds the cycles.
+///
+/// Duplicated edges and self-loops are discarded, and the node labels are
+/// sampled uniformly. The same seed always yields the same graph.
+pub struct RandomGraph {
+    /// The number of nodes of the graph.
+    number_of_nodes: usize,
+    /// The number of node labels of the graph.
+    number_of_node_labels: u8,
+    /// The label of each node.
+    node_labels: Vec<u8>,
+    /// The offsets of the neighbours of each node.
+    offsets: Vec<usize>,
+    /// The sorted neighbours of each node, with each undirected edge appearing in both directions.
+    destinations: Vec<usize>,
+}
+
+/// Returns the next value of the provided SplitMix64 state.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+impl RandomGraph {
+    /// Returns a new random graph.
+    ///
+    /// # Arguments
+    /// * `seed` - The seed of the random number generator.
+    /// * `number_of_nodes` - The number of nodes of the graph.
+    /// * `number_of_node_labels` - The number of node labels of the graph.
+    /// * `edges_per_node` - The number of edges sampled for each node.
+    ///
+    /// # Raises
+    /// * If the number of node labels is zero.
+    pub fn new(
+        mut seed: u64,
+        number_of_nodes: usize,
+        number_of_node_labels: u8,
+        edges_per_node: usize,
+    ) -> Self {
+        assert!(
+            number_of_node_labels > 0,
+            "The number of node labels must be strictly positive."
+        );
+
+        let node_labels = (0..number_of_nodes)
+            .map(|_| (splitmix64(&mut seed) % number_of_node_labels as u64) as u8)
+            .collect::<Vec<u8>>();
+
+        let mut rasterized_edges = Vec::with_capacity(2 * number_of_nodes * edges_per_node);
+        for node_id in 0..number_of_nodes {
+            let mut sampled_edges = 0;
+            // We connect the node to one of the previous nodes, so that the graph is connected.
+            if node_id > 0 && edges_per_node > 0 {
+                let dst = (splitmix64(&mut seed) % node_id as u64) as usize;
+                rasterized_edges.push((node_id, dst));
+                rasterized_edges.push((dst, node_id));
+                sampled_edges += 1;
+            }
+            // We connect the node to further random nodes, which may have any ID.
+            // In small graphs there may not be enough distinct nodes, so the
+            // number of sampled edges is capped by the number of other nodes.
+            for _ in sampled_edges..edges_per_node.min(number_of_nodes - 1) {
+                let dst = (splitmix64(&mut seed) % number_of_nodes as u64) as usize;
+                if dst != node_id {
+                    rasterized_edges.push((node_id, dst));
+                    rasterized_edges.push((dst, node_id));
+                }
+            }
+        }
+
+        rasterized_edges.sort_unstable();
+        rasterized_edges.dedup();
+
+        // The edges are sorted, so the neighbours of each node are a contiguous range.
+        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
+        offsets.push(0);
+        let mut destinations = Vec::with_capacity(rasterized_edges.len());
+        for (src, dst) in rasterized_edges {
+            while offsets.len() <= src {
+                offsets.push(destinations.len());
+            }
+            destinations.push(dst);
+        }
+        while offsets.len() <= number_of_nodes {
+            offsets.push(destinations.len());
+        }
+
+        Self {
+            number_of_nodes,
+            number_of_node_labels,
+            node_labels,
+            offsets,
+            destinations,
+        }
+    }
+
+    /// Iterates over the directed edges of the graph, sorted by source and destination.
+    pub fn iter_edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.number_of_nodes)
+            .flat_map(move |src| self.iter_neighbours(src).map(move |dst| (src, dst)))
+    }
+}
+
+impl Graph for RandomGraph {
+    type Node = usize;
+    type NeighbourIter<'a> = core::iter::Copied<core::slice::Iter<'a, usize>>;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.number_of_nodes
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.destinations.len()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.destinations[self.offsets[node]..self.offsets[node + 1]]
+            .iter()
+            .copied()
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.offsets[node + 1] - self.offsets[node]
+    }
+}
+
+impl TypedGraph for RandomGraph {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.number_of_node_labels
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.number_of_node_labels as usize
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index as u8
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label as usize
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.node_labels[node]
+    }
+}
+
+impl HeterogeneousGraphlets<u16, u32> for RandomGraph {
+    type GraphLetCounter = DefaultCounter<u16, u32>;
+}
@@ -0,0 +1,179 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+
+/// A view of a graph where the neighbours of a node are replaced by the provided ones.
+///
+/// The edges between the node and its neighbours in the wrapped graph are dropped,
+/// and the edges between the node and the provided neighbours are added, in both
+/// directions, so that the view is the graph the wrapped one was before, or will be
+/// after, the neighbours of the node are rewritten.
+pub struct RewiredGraph<'a, G: Graph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The node whose neighbours are replaced.
+    node: usize,
+    /// The neighbours of the node in the view, in strictly ascending order.
+    neighbours: &'a [usize],
+}
+
+impl<'a, G: Graph> RewiredGraph<'a, G> {
+    /// Returns a view of the provided graph with the neighbours of the node replaced.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph to rewire.
+    /// * `node` - The node whose neighbours are replaced.
+    /// * `neighbours` - The neighbours of the node in the view.
+    ///
+    /// # Implementation details
+    /// As for [`Graph::iter_neighbours`], the neighbours must be in strictly ascending
+    /// order and must not include the node itself.
+    pub fn new(graph: &'a G, node: usize, neighbours: &'a [usize]) -> Self {
+        debug_assert!(
+            neighbours.windows(2).all(|window| window[0] < window[1]),
+            "The neighbours of the rewired node must be strictly increasing."
+        );
+        debug_assert!(
+            !neighbours.contains(&node),
+            "The rewired node cannot be a neighbour of itself."
+        );
+        Self {
+            graph,
+            node,
+            neighbours,
+        }
+    }
+
+    /// Returns whether the provided node is a neighbour of the rewired node in the view.
+    ///
+    /// # Arguments
+    /// * `other` - The node to look for.
+    fn is_rewired_neighbour(&self, other: usize) -> bool {
+        self.neighbours.binary_search(&other).is_ok()
+    }
+}
+
+/// Iterator over the neighbours of a node in a [`RewiredGraph`].
+pub struct RewiredNeighbourIter<'a, I> {
+    /// The neighbours of the rewired node, when iterating over its neighbours.
+    rewired_neighbours: core::slice::Iter<'a, usize>,
+    /// The iterator over the neighbours in the wrapped graph, for the other nodes.
+    neighbours: Option<I>,
+    /// The rewired node, which is skipped among the neighbours in the wrapped graph.
+    node: usize,
+    /// The rewired node, if it is a neighbour in the view and was not returned yet.
+    pending: Option<usize>,
+    /// The neighbour in the wrapped graph read ahead while placing the rewired node.
+    buffered: Option<usize>,
+}
+
+impl<'a, I: Iterator<Item = usize>> Iterator for RewiredNeighbourIter<'a, I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Some(neighbours) = self.neighbours.as_mut() else {
+            return self.rewired_neighbours.next().copied();
+        };
+        let node = self.node;
+        let next = self
+            .buffered
+            .take()
+            .or_else(|| neighbours.find(|&neighbour| neighbour != node));
+        // Since the neighbours are sorted, the rewired node is returned
+        // right before the first larger neighbour.
+        match (self.pending, next) {
+            (Some(pending), Some(neighbour)) if pending < neighbour => {
+                self.pending = None;
+                self.buffered = Some(neighbour);
+                Some(pending)
+            }
+            (Some(pending), None) => {
+                self.pending = None;
+                Some(pending)
+            }
+            _ => next,
+        }
+    }
+}
+
+impl<'a, G: Graph> Graph for RewiredGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = RewiredNeighbourIter<'b, G::NeighbourIter<'b>>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        (0..self.get_number_of_nodes())
+            .map(|node| self.get_node_degree(node))
+            .sum()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        if node == self.node {
+            return RewiredNeighbourIter {
+                rewired_neighbours: self.neighbours.iter(),
+                neighbours: None,
+                node,
+                pending: None,
+                buffered: None,
+            };
+        }
+        RewiredNeighbourIter {
+            rewired_neighbours: [].iter(),
+            neighbours: Some(self.graph.iter_neighbours(node)),
+            node: self.node,
+            pending: self.is_rewired_neighbour(node).then_some(self.node),
+            buffered: None,
+        }
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        if node == self.node {
+            return self.neighbours.len();
+        }
+        self.graph.get_node_degree(node) - usize::from(self.graph.is_neighbour(node, self.node))
+            + usize::from(self.is_rewired_neighbour(node))
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        if node == self.node {
+            self.is_rewired_neighbour(other)
+        } else if other == self.node {
+            self.is_rewired_neighbour(node)
+        } else {
+            self.graph.is_neighbour(node, other)
+        }
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for RewiredGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(RewiredGraph);
@@ -0,0 +1,118 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+use roaring::RoaringBitmap;
+
+/// A view of a typed graph whose neighbourhoods are stored as roaring bitmaps.
+///
+/// The graph is structurally identical to the wrapped one, but it supports fast
+/// neighbourhood membership checks, which speed up the counting of the graphlets
+/// in graphs with very dense neighbourhoods.
+pub struct RoaringGraph<'a, G: TypedGraph> {
+    /// The wrapped graph, used for the node labels.
+    graph: &'a G,
+    /// The neighbours of each node.
+    neighbours: Vec<RoaringBitmap>,
+    /// The number of edges of the graph.
+    number_of_edges: usize,
+}
+
+impl<'a, G: TypedGraph> RoaringGraph<'a, G> {
+    /// Returns the roaring view of the provided graph.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose neighbourhoods should be stored as roaring bitmaps.
+    ///
+    /// # Raises
+    /// * If the graph has more than `u32::MAX` nodes.
+    pub fn new(graph: &'a G) -> Result<Self, String> {
+        if graph.get_number_of_nodes() > u32::MAX as usize {
+            return Err(format!(
+                concat!(
+                    "The provided graph has {} nodes, but roaring bitmaps ",
+                    "can only store up to {} nodes."
+                ),
+                graph.get_number_of_nodes(),
+                u32::MAX
+            ));
+        }
+
+        let neighbours = (0..graph.get_number_of_nodes())
+            .map(|node| {
+                // The neighbours are sorted, so we can build the bitmap in a single pass.
+                RoaringBitmap::from_sorted_iter(
+                    graph
+                        .iter_neighbours(node)
+                        .map(|neighbour| neighbour as u32),
+                )
+                .map_err(|error| {
+                    format!("The neighbours of node {} are not sorted: {}", node, error)
+                })
+            })
+            .collect::<Result<Vec<_>, String>>()?;
+
+        Ok(Self {
+            graph,
+            neighbours,
+            number_of_edges: graph.get_number_of_edges(),
+        })
+    }
+}
+
+impl<'a, G: TypedGraph> Graph for RoaringGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = std::iter::Map<roaring::bitmap::Iter<'b>, fn(u32) -> usize>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.neighbours.len()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.number_of_edges
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        // Roaring bitmaps are iterated in ascending order.
+        self.neighbours[node]
+            .iter()
+            .map((|neighbour| neighbour as usize) as fn(u32) -> usize)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.neighbours[node].len() as usize
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = true;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.neighbours[node].contains(other as u32)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for RoaringGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(RoaringGraph);
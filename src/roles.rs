@@ -0,0 +1,198 @@
+use alloc::{vec, vec::Vec};
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use rand::Rng;
+
+use crate::graphlet_set::{ExtendedGraphletType, GraphletSet, ReducedGraphletType};
+use crate::numbers::{Checked, Maximal, One, Primitive, Two, Zero};
+use crate::perfect_graphlet_hash::GraphletHashLayout;
+use crate::prelude::*;
+
+/// Returns the structural role cluster of each node, from its graphlet degree vector.
+///
+/// # Arguments
+/// * `graph` - The graph whose nodes should be clustered.
+/// * `k` - The number of clusters.
+/// * `rng` - The random number generator used to pick the initial centroids.
+///
+/// # Raises
+/// * If the provided number of clusters is zero.
+///
+/// # Implementation details
+/// The graphlet degree vector of a node is the one returned by
+/// [`graphlet_degree_vector`](HeterogeneousGraphlets::graphlet_degree_vector), i.e. the
+/// total count of each graphlet kind over the edges incident to the node, so that the
+/// node labels are ignored.
+///
+/// Since graphlet counts grow polynomially with the degree, each count x is first
+/// compressed to ln(1 + x), and each kind is then divided by its maximum over all the
+/// nodes, so that every kind ranges in [0, 1] and the most frequent kinds, such as
+/// triads, do not dominate the distances. Kinds that never appear are left to zero.
+///
+/// The normalized vectors are clustered with k-means under the Euclidean distance.
+/// The initial centroids are picked with the k-means++ seeding, and the Lloyd iterations
+/// run until the assignments are stable, for at most 100 iterations. When the graph has
+/// fewer nodes than the provided number of clusters, each node gets its own cluster.
+/// Clusters that become empty keep their previous centroid, so some of the `k` cluster
+/// identifiers may not be assigned to any node.
+///
+pub fn cluster_node_roles<G, Graphlet, Count, Layout>(
+    graph: &G,
+    k: usize,
+    rng: &mut impl Rng,
+) -> Vec<usize>
+where
+    G: HeterogeneousGraphlets<Graphlet, Count, Layout>,
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+    Graphlet: Copy
+        + Debug
+        + Maximal
+        + Primitive<G::NodeLabel>
+        + From<ReducedGraphletType>
+        + From<ExtendedGraphletType>
+        + Mul<Output = Graphlet>
+        + Add<Output = Graphlet>
+        + Div<Output = Graphlet>
+        + Rem<Output = Graphlet>
+        + Sub<Output = Graphlet>
+        + One
+        + Zero
+        + Ord,
+    u128: Primitive<Graphlet>,
+    G::NodeLabel: Ord
+        + One
+        + Zero
+        + Mul<G::NodeLabel, Output = G::NodeLabel>
+        + Add<G::NodeLabel, Output = G::NodeLabel>
+        + Div<G::NodeLabel, Output = G::NodeLabel>
+        + Rem<G::NodeLabel, Output = G::NodeLabel>
+        + Copy,
+    ReducedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    ExtendedGraphletType: GraphletSet<Graphlet> + From<Graphlet>,
+    Layout: GraphletHashLayout<Graphlet, G::NodeLabel>,
+    f64: Primitive<Count>,
+{
+    assert!(k > 0, "The number of clusters must be strictly positive.");
+
+    let mut gdvs = (0..graph.get_number_of_nodes())
+        .map(|node| {
+            let mut gdv = [0.0_f64; ExtendedGraphletType::COUNT];
+            for (coordinate, count) in gdv.iter_mut().zip(graph.graphlet_degree_vector(node)) {
+                *coordinate = f64::convert(count);
+            }
+            gdv
+        })
+        .collect::<Vec<_>>();
+
+    let mut maxima = [0.0_f64; ExtendedGraphletType::COUNT];
+    for gdv in gdvs.iter_mut() {
+        for (count, maximum) in gdv.iter_mut().zip(maxima.iter_mut()) {
+            *count = count.ln_1p();
+            *maximum = maximum.max(*count);
+        }
+    }
+    for gdv in gdvs.iter_mut() {
+        for (count, maximum) in gdv.iter_mut().zip(maxima) {
+            if maximum > 0.0 {
+                *count /= maximum;
+            }
+        }
+    }
+
+    if gdvs.len() <= k {
+        return (0..gdvs.len()).collect();
+    }
+
+    let squared_distance = |a: &[f64; ExtendedGraphletType::COUNT],
+                            b: &[f64; ExtendedGraphletType::COUNT]| {
+        a.iter()
+            .zip(b.iter())
+            .map(|(a, b)| (a - b) * (a - b))
+            .sum::<f64>()
+    };
+
+    // We pick each centroid with probability proportional to the squared distance
+    // of the node from the closest centroid picked so far.
+    let mut centroids = vec![gdvs[rng.gen_range(0..gdvs.len())]];
+    let mut closest_squared_distances = gdvs
+        .iter()
+        .map(|gdv| squared_distance(gdv, &centroids[0]))
+        .collect::<Vec<f64>>();
+    while centroids.len() < k {
+        let total = closest_squared_distances.iter().sum::<f64>();
+        let centroid = if total > 0.0 {
+            let threshold = rng.gen::<f64>() * total;
+            let mut cumulative = 0.0;
+            let mut node = 0;
+            while node < gdvs.len() - 1 {
+                cumulative += closest_squared_distances[node];
+                if cumulative > threshold {
+                    break;
+                }
+                node += 1;
+            }
+            gdvs[node]
+        } else {
+            // All the nodes coincide with a centroid, so any of them will do.
+            gdvs[rng.gen_range(0..gdvs.len())]
+        };
+        for (closest_squared_distance, gdv) in closest_squared_distances.iter_mut().zip(gdvs.iter())
+        {
+            *closest_squared_distance =
+                closest_squared_distance.min(squared_distance(gdv, &centroid));
+        }
+        centroids.push(centroid);
+    }
+
+    let mut clusters = vec![usize::MAX; gdvs.len()];
+    for _ in 0..100 {
+        let mut changed = false;
+        for (cluster, gdv) in clusters.iter_mut().zip(gdvs.iter()) {
+            let closest_cluster = (0..k)
+                .min_by(|&a, &b| {
+                    squared_distance(gdv, &centroids[a])
+                        .total_cmp(&squared_distance(gdv, &centroids[b]))
+                })
+                .unwrap();
+            if *cluster != closest_cluster {
+                *cluster = closest_cluster;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = vec![[0.0_f64; ExtendedGraphletType::COUNT]; k];
+        let mut sizes = vec![0_usize; k];
+        for (&cluster, gdv) in clusters.iter().zip(gdvs.iter()) {
+            sizes[cluster] += 1;
+            for (sum, count) in sums[cluster].iter_mut().zip(gdv.iter()) {
+                *sum += count;
+            }
+        }
+        for ((centroid, sum), size) in centroids.iter_mut().zip(sums).zip(sizes) {
+            if size > 0 {
+                for (coordinate, sum) in centroid.iter_mut().zip(sum) {
+                    *coordinate = sum / size as f64;
+                }
+            }
+        }
+    }
+
+    clusters
+}
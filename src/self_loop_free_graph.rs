@@ -0,0 +1,96 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+
+/// A view of a graph that skips its self-loops.
+///
+/// The graphlet counting assumes that no node is a neighbour of itself, and graphs
+/// whose self-loops cannot be stripped should be counted through this view, which
+/// yields the same counts of the graph without the self-loops.
+pub struct SelfLoopFreeGraph<'a, G: Graph> {
+    /// The wrapped graph.
+    graph: &'a G,
+}
+
+impl<'a, G: Graph> SelfLoopFreeGraph<'a, G> {
+    /// Returns a view of the provided graph without its self-loops.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose self-loops should be skipped.
+    pub fn new(graph: &'a G) -> Self {
+        Self { graph }
+    }
+}
+
+/// Iterator over the neighbours of a node other than the node itself.
+pub struct SelfLoopFreeNeighbourIter<I> {
+    /// The iterator over all the neighbours of the node.
+    neighbours: I,
+    /// The node whose neighbours are iterated over.
+    node: usize,
+}
+
+impl<I: Iterator<Item = usize>> Iterator for SelfLoopFreeNeighbourIter<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.node;
+        self.neighbours.find(|&neighbour| neighbour != node)
+    }
+}
+
+impl<'a, G: Graph> Graph for SelfLoopFreeGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = SelfLoopFreeNeighbourIter<G::NeighbourIter<'b>>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        (0..self.get_number_of_nodes())
+            .map(|node| self.get_node_degree(node))
+            .sum()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        SelfLoopFreeNeighbourIter {
+            neighbours: self.graph.iter_neighbours(node),
+            node,
+        }
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        node != other && self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for SelfLoopFreeGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(SelfLoopFreeGraph);
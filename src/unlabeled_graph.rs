@@ -0,0 +1,100 @@
+use core::fmt::Debug;
+use core::ops::{Add, AddAssign, Div, Mul, Rem, Sub};
+
+use crate::numbers::*;
+use crate::prelude::*;
+
+/// A view of a graph with all its nodes sharing a single label.
+///
+/// The graphlets of the view are those of the wrapped graph with their labels
+/// discarded, so that their hashes, which only encode the graphlet kinds, are
+/// small enough to be counted with a [`DenseGraphletCounter`] without hashing.
+pub(crate) struct UnlabeledGraph<'a, G: Graph> {
+    /// The wrapped graph.
+    graph: &'a G,
+}
+
+impl<'a, G: Graph> UnlabeledGraph<'a, G> {
+    /// Returns an unlabeled view of the provided graph.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph whose labels should be discarded.
+    pub(crate) fn new(graph: &'a G) -> Self {
+        Self { graph }
+    }
+}
+
+impl<'a, G: Graph> Graph for UnlabeledGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = G::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.graph.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.graph.iter_neighbours(node)
+    }
+
+    fn get_node_degree(&self, node: usize) -> usize {
+        self.graph.get_node_degree(node)
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: Graph> TypedGraph for UnlabeledGraph<'a, G> {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        1
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        1
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index as u8
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label as usize
+    }
+
+    fn get_node_label(&self, _node: usize) -> Self::NodeLabel {
+        0
+    }
+}
+
+impl<'a, G, Count> HeterogeneousGraphlets<usize, Count> for UnlabeledGraph<'a, G>
+where
+    G: Graph,
+    Count: Debug
+        + Copy
+        + Primitive<usize>
+        + PartialOrd
+        + One
+        + Two
+        + Zero
+        + AddAssign
+        + Add<Count, Output = Count>
+        + Sub<Count, Output = Count>
+        + Div<Count, Output = Count>
+        + Mul<Count, Output = Count>
+        + Rem<Count, Output = Count>
+        + Checked,
+{
+    type GraphLetCounter = DenseGraphletCounter<usize, Count>;
+}
@@ -0,0 +1,135 @@
+use crate::edge_typed_graphlets::impl_heterogeneous_graphlets_for_view;
+use crate::prelude::*;
+
+/// A view of a graph restricted to the subgraph induced by a window of node IDs.
+///
+/// The nodes outside of the window have no neighbours, and the neighbours
+/// of the nodes inside of the window are restricted to the window.
+pub struct WindowedGraph<'a, G: Graph> {
+    /// The wrapped graph.
+    graph: &'a G,
+    /// The first node of the window.
+    start: usize,
+    /// The node following the last node of the window.
+    end: usize,
+}
+
+impl<'a, G: Graph> WindowedGraph<'a, G> {
+    /// Returns a view of the provided graph restricted to the provided window.
+    ///
+    /// # Arguments
+    /// * `graph` - The graph to restrict.
+    /// * `start` - The first node of the window.
+    /// * `window_size` - The number of nodes in the window.
+    pub fn new(graph: &'a G, start: usize, window_size: usize) -> Self {
+        Self {
+            graph,
+            start,
+            end: (start + window_size).min(graph.get_number_of_nodes()),
+        }
+    }
+
+    /// Returns whether the provided node is inside of the window.
+    ///
+    /// # Arguments
+    /// * `node` - The node to check.
+    pub fn contains(&self, node: usize) -> bool {
+        self.start <= node && node < self.end
+    }
+
+    /// Iterates over the nodes inside of the window.
+    pub fn iter_nodes(&self) -> core::ops::Range<usize> {
+        self.start..self.end
+    }
+}
+
+/// Iterator over the neighbours of a node that fall within a window.
+pub struct WindowedNeighbourIter<I> {
+    /// The iterator over all the neighbours of the node.
+    neighbours: I,
+    /// The first node of the window.
+    start: usize,
+    /// The node following the last node of the window.
+    end: usize,
+}
+
+impl<I: Iterator<Item = usize>> Iterator for WindowedNeighbourIter<I> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Since the neighbours are sorted, we can stop as soon as
+        // we exceed the window.
+        for neighbour in self.neighbours.by_ref() {
+            if neighbour >= self.end {
+                return None;
+            }
+            if neighbour >= self.start {
+                return Some(neighbour);
+            }
+        }
+        None
+    }
+}
+
+impl<'a, G: Graph> Graph for WindowedGraph<'a, G> {
+    type Node = G::Node;
+    type NeighbourIter<'b>
+        = WindowedNeighbourIter<G::NeighbourIter<'b>>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.graph.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.iter_nodes()
+            .map(|node| self.get_node_degree(node))
+            .sum()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        let (start, end) = if self.contains(node) {
+            (self.start, self.end)
+        } else {
+            (0, 0)
+        };
+        WindowedNeighbourIter {
+            neighbours: self.graph.iter_neighbours(node),
+            start,
+            end,
+        }
+    }
+
+    const HAS_FAST_NEIGHBOUR_LOOKUP: bool = G::HAS_FAST_NEIGHBOUR_LOOKUP;
+
+    fn is_neighbour(&self, node: usize, other: usize) -> bool {
+        self.contains(node) && self.contains(other) && self.graph.is_neighbour(node, other)
+    }
+}
+
+impl<'a, G: TypedGraph> TypedGraph for WindowedGraph<'a, G> {
+    type NodeLabel = G::NodeLabel;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.graph.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.graph.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.graph.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.graph.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.graph.get_node_label(node)
+    }
+}
+
+impl_heterogeneous_graphlets_for_view!(WindowedGraph);
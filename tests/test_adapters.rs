@@ -0,0 +1,510 @@
+mod test_from_csv;
+use heterogeneous_graphlets::perfect_graphlet_hash::NTuplePerfectHash;
+use heterogeneous_graphlets::prelude::*;
+use std::collections::HashMap;
+use test_from_csv::{count_graphlets, OffsetLayout};
+
+#[test]
+fn test_compact_labels() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/sparse_labels/node_list.csv",
+        "tests/data/sparse_labels/edge_list.csv",
+    )
+    .unwrap();
+    let (compact_graph, original_labels) = graph.compact_labels();
+
+    assert_eq!(graph.get_number_of_node_labels(), 7);
+    assert_eq!(compact_graph.get_number_of_node_labels(), 3);
+    assert_eq!(original_labels, vec![0, 3, 6]);
+
+    let maximal_hash = |number_of_node_labels: u8| -> u16 {
+        <[u8; 4] as NTuplePerfectHash<4, u16, u8>>::maximal_hash::<ExtendedGraphletType>(
+            number_of_node_labels,
+        )
+    };
+    assert!(
+        maximal_hash(compact_graph.get_number_of_node_labels()) * 10
+            < maximal_hash(graph.get_number_of_node_labels())
+    );
+
+    // The three-node graphlets carry the dummy label in the fourth position,
+    // which does not survive the decoding: we compare their totals by kind,
+    // while the four-node graphlets are compared label by label.
+    let is_three_node_graphlet = |graphlet_kind: ExtendedGraphletType| {
+        matches!(
+            graphlet_kind,
+            ExtendedGraphletType::Triad | ExtendedGraphletType::Triangle
+        )
+    };
+    let original_label = |label: u8| original_labels[label as usize];
+
+    let mut compact_counts: HashMap<u16, u32> = HashMap::new();
+    let mut compact_three_node_totals: HashMap<ExtendedGraphletType, u32> = HashMap::new();
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in
+            HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_graphlet(&compact_graph, src, dst)
+        {
+            let (graphlet_kind, labels) = <[u8; 4]>::decode_with_graphlet::<ExtendedGraphletType>(
+                graphlet,
+                compact_graph.get_number_of_node_labels(),
+            );
+            if is_three_node_graphlet(graphlet_kind) {
+                *compact_three_node_totals.entry(graphlet_kind).or_insert(0) += count;
+                continue;
+            }
+            compact_counts.insert_count(
+                labels
+                    .map(original_label)
+                    .encode_with_graphlet(graphlet_kind, graph.get_number_of_node_labels()),
+                count,
+            );
+        }
+    }
+
+    let mut counts = count_graphlets(&graph);
+    let mut three_node_totals: HashMap<ExtendedGraphletType, u32> = HashMap::new();
+    counts.retain(|&graphlet, &mut count| {
+        let graphlet_kind = <[u8; 4]>::decode_graphlet_kind::<ExtendedGraphletType>(
+            graphlet,
+            graph.get_number_of_node_labels(),
+        );
+        if is_three_node_graphlet(graphlet_kind) {
+            *three_node_totals.entry(graphlet_kind).or_insert(0) += count;
+        }
+        !is_three_node_graphlet(graphlet_kind)
+    });
+
+    assert_eq!(compact_counts, counts);
+    assert_eq!(compact_three_node_totals, three_node_totals);
+}
+
+#[test]
+fn test_hierarchical_labels() {
+    for (node_list, edge_list) in [
+        (
+            "tests/data/triangle_five_labels/node_list.csv",
+            "tests/data/triangle_five_labels/edge_list.csv",
+        ),
+        (
+            "tests/data/sparse_labels/node_list.csv",
+            "tests/data/sparse_labels/edge_list.csv",
+        ),
+    ] {
+        let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+        // Each level of the hierarchy merges pairs of labels of the previous level.
+        let mut hierarchical_graph =
+            HierarchicalLabelGraph::new(&graph, |label: u8, level: usize| label >> level, 0);
+        assert_eq!(
+            count_graphlets(&graph),
+            count_hierarchical_graphlets(&hierarchical_graph)
+        );
+
+        for level in 1..4 {
+            hierarchical_graph.set_level(level);
+            assert_eq!(hierarchical_graph.level(), level);
+
+            // We relabel the graph at the same level, and count its graphlets.
+            let adjacency = (0..graph.get_number_of_nodes())
+                .map(|node| (node, graph.iter_neighbours(node).collect()))
+                .collect::<HashMap<usize, Vec<usize>>>();
+            let node_labels = (0..graph.get_number_of_nodes())
+                .map(|node| (node, (graph.get_node_label(node) >> level) as usize))
+                .collect::<HashMap<usize, usize>>();
+            let relabeled_graph = HashMapGraph::new(&adjacency, &node_labels).unwrap();
+
+            assert_eq!(
+                hierarchical_graph.get_number_of_node_labels_usize(),
+                ((graph.get_number_of_node_labels_usize() - 1) >> level) + 1
+            );
+            let mut relabeled_counts: HashMap<u16, u32> = HashMap::new();
+            for src in 0..relabeled_graph.get_number_of_nodes() {
+                for dst in relabeled_graph
+                    .iter_neighbours(src)
+                    .filter(|&dst| src < dst)
+                {
+                    for (graphlet, count) in
+                        HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_graphlet(
+                            &relabeled_graph,
+                            src,
+                            dst,
+                        )
+                    {
+                        relabeled_counts.insert_count(graphlet, count);
+                    }
+                }
+            }
+
+            assert_eq!(
+                count_hierarchical_graphlets(&hierarchical_graph),
+                relabeled_counts,
+                "{} {}",
+                node_list,
+                level
+            );
+        }
+    }
+}
+
+/// Returns the graphlet counts of the provided hierarchical graph, counting each edge once.
+fn count_hierarchical_graphlets(graph: &HierarchicalLabelGraph<CSRGraph>) -> HashMap<u16, u32> {
+    let mut counts: HashMap<u16, u32> = HashMap::new();
+    for src in 0..graph.get_number_of_nodes() {
+        for dst in graph.iter_neighbours(src).filter(|&dst| src < dst) {
+            for (graphlet, count) in graph.get_heterogeneous_graphlet(src, dst) {
+                counts.insert_count(graphlet, count);
+            }
+        }
+    }
+    counts
+}
+
+#[test]
+fn test_product_label_graph() {
+    for graph_name in ["wheel", "clique_and_diamond"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        assert_eq!(graph.get_number_of_node_labels_usize(), 2);
+
+        // We combine the two node types with three communities.
+        let product_graph = ProductLabelGraph::new(&graph, |node| (node % 3) as u8, 3);
+        assert_eq!(product_graph.get_number_of_node_labels_usize(), 6);
+        for node in 0..graph.get_number_of_nodes() {
+            assert_eq!(
+                product_graph.split_label(product_graph.get_node_label(node)),
+                (graph.get_node_label(node), (node % 3) as u8)
+            );
+        }
+
+        // We relabel the graph with the product labels, adding an isolated node with
+        // the last product label so that both graphs have the same number of labels.
+        let relabeled_graph = CSRGraph::from_edges(
+            (0..graph.get_number_of_nodes())
+                .map(|node| graph.get_node_label(node) * 3 + (node % 3) as u8)
+                .chain([5])
+                .collect(),
+            graph.iter_edges().collect(),
+        )
+        .unwrap();
+        assert_eq!(relabeled_graph.get_number_of_node_labels_usize(), 6);
+
+        let mut product_counts: HashMap<u16, u32> = HashMap::new();
+        for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+            for (graphlet, count) in product_graph.get_heterogeneous_graphlet(src, dst) {
+                product_counts.insert_count(graphlet, count);
+            }
+        }
+        let relabeled_counts = count_graphlets(&relabeled_graph);
+        assert_eq!(product_counts, relabeled_counts, "{}", graph_name);
+    }
+}
+
+#[test]
+fn test_windowed_graphlet_counts() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_path/node_list.csv",
+        "tests/data/four_path/edge_list.csv",
+    )
+    .unwrap();
+
+    // A window at least as large as the path recovers the exact counts.
+    for window_size in [4, 10] {
+        assert_eq!(
+            graph.windowed_graphlet_counts(window_size),
+            count_graphlets(&graph)
+        );
+    }
+
+    // With windows of two nodes, each edge is isolated within its window.
+    assert!(graph.windowed_graphlet_counts(2).is_empty());
+
+    // With windows of three nodes, each edge is counted once within a three-path,
+    // missing the triad and the four-path spanning beyond it.
+    let windowed_counts = graph.windowed_graphlet_counts(3);
+    assert_eq!(windowed_counts.values().sum::<u32>(), 3);
+    assert!(windowed_counts.keys().all(|&graphlet| {
+        <[u8; 4]>::decode_graphlet_kind::<ExtendedGraphletType>(
+            graphlet,
+            graph.get_number_of_node_labels(),
+        ) == ExtendedGraphletType::Triad
+    }));
+}
+
+#[cfg(feature = "roaring")]
+#[test]
+fn test_roaring_graph() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/six_clique/node_list.csv",
+        "tests/data/six_clique/edge_list.csv",
+    )
+    .unwrap();
+    let roaring_graph = RoaringGraph::new(&graph).unwrap();
+
+    assert_eq!(
+        roaring_graph.get_number_of_edges(),
+        graph.get_number_of_edges()
+    );
+    for src in 0..graph.get_number_of_nodes() {
+        assert!(roaring_graph
+            .iter_neighbours(src)
+            .eq(graph.iter_neighbours(src)));
+        for dst in 0..graph.get_number_of_nodes() {
+            assert_eq!(roaring_graph.is_neighbour(src, dst), src != dst);
+        }
+    }
+
+    let mut roaring_counts: HashMap<u16, u32> = HashMap::new();
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in roaring_graph.get_heterogeneous_graphlet(src, dst) {
+            roaring_counts.insert_count(graphlet, count);
+        }
+    }
+    assert_eq!(roaring_counts, count_graphlets(&graph));
+}
+
+#[test]
+fn test_interned_graph() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle/node_list.csv",
+        "tests/data/triangle/edge_list.csv",
+    )
+    .unwrap();
+
+    assert!(InternedGraph::new(&graph, ["paper".to_string()]).is_err());
+
+    let labels = ["paper", "author", "paper"].map(String::from);
+    let interned_graph = InternedGraph::new(&graph, labels).unwrap();
+
+    assert_eq!(interned_graph.get_number_of_node_labels(), 2);
+    assert_eq!(
+        interned_graph.get_node_label(0),
+        interned_graph.get_node_label(2)
+    );
+    assert_eq!(interned_graph.original_label(0), "paper");
+    assert_eq!(interned_graph.original_label(1), "author");
+
+    let mut counts: HashMap<u64, u32> = HashMap::new();
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in
+            HeterogeneousGraphlets::<u64, u32, OffsetLayout>::get_heterogeneous_graphlet(
+                &interned_graph,
+                src,
+                dst,
+            )
+        {
+            counts.insert_count(graphlet, count);
+        }
+    }
+
+    let report = interned_graph.get_report::<u64, u32, OffsetLayout>(&counts);
+
+    let mut rows = report.lines().collect::<Vec<_>>();
+    rows.sort_unstable();
+    assert_eq!(rows.len(), counts.len());
+    for row in rows {
+        assert!(row.starts_with("Triangle ("), "{}", row);
+        assert_eq!(row.matches("paper").count(), 2, "{}", row);
+        assert_eq!(row.matches("author").count(), 1, "{}", row);
+    }
+}
+
+#[test]
+fn test_string_labeled_graph() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_path/node_list.csv",
+        "tests/data/four_path/edge_list.csv",
+    )
+    .unwrap();
+    let labels = ["author", "paper", "author", "venue", "paper"].map(String::from);
+    let labeled_graph: StringLabeledGraph<_> = InternedGraph::new(&graph, labels).unwrap();
+
+    // The labels are interned in the dense range in order of first appearance.
+    assert_eq!(labeled_graph.get_number_of_node_labels(), 3);
+    assert_eq!(
+        (0..graph.get_number_of_nodes())
+            .map(|node| labeled_graph.get_node_label(node))
+            .collect::<Vec<_>>(),
+        vec![0, 1, 0, 2, 1]
+    );
+    assert_eq!(
+        (0..3)
+            .map(|index| labeled_graph.label_name(index))
+            .collect::<Vec<_>>(),
+        vec!["author", "paper", "venue"]
+    );
+}
+
+#[test]
+fn test_hashmap_graph() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+
+    // Non-contiguous ids following the node order, so that the densified
+    // graph is the original one and the edges keep their orientation.
+    let to_id = |node: usize| node as u64 * 7_919 + 13;
+    let adjacency = (0..graph.get_number_of_nodes())
+        .map(|node| {
+            (
+                to_id(node),
+                graph.iter_neighbours(node).map(to_id).collect(),
+            )
+        })
+        .collect::<HashMap<u64, Vec<u64>>>();
+    let node_labels = (0..graph.get_number_of_nodes())
+        .map(|node| (to_id(node), graph.get_node_label(node) as usize))
+        .collect::<HashMap<u64, usize>>();
+
+    let hashmap_graph = HashMapGraph::new(&adjacency, &node_labels).unwrap();
+
+    assert_eq!(
+        hashmap_graph.get_number_of_nodes(),
+        graph.get_number_of_nodes()
+    );
+    assert_eq!(
+        hashmap_graph.get_number_of_edges(),
+        graph.get_number_of_edges()
+    );
+    assert_eq!(
+        hashmap_graph.get_number_of_node_labels_usize(),
+        graph.get_number_of_node_labels_usize()
+    );
+    for node in 0..hashmap_graph.get_number_of_nodes() {
+        let id = hashmap_graph.get_node_id(node);
+        assert_eq!(hashmap_graph.get_node_from_id(id), Some(node));
+        let neighbours = hashmap_graph.iter_neighbours(node).collect::<Vec<_>>();
+        assert!(neighbours.windows(2).all(|window| window[0] < window[1]));
+        let mut neighbour_ids = neighbours
+            .into_iter()
+            .map(|neighbour| hashmap_graph.get_node_id(neighbour))
+            .collect::<Vec<_>>();
+        let mut expected_ids = adjacency[&id].clone();
+        neighbour_ids.sort_unstable();
+        expected_ids.sort_unstable();
+        assert_eq!(neighbour_ids, expected_ids);
+    }
+
+    let mut counts: HashMap<u16, u32> = HashMap::new();
+    for src in 0..hashmap_graph.get_number_of_nodes() {
+        for dst in hashmap_graph.iter_neighbours(src).filter(|&dst| src < dst) {
+            for (graphlet, count) in HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_graphlet(
+                &hashmap_graph,
+                src,
+                dst,
+            ) {
+                counts.insert_count(graphlet, count);
+            }
+        }
+    }
+    assert_eq!(counts, count_graphlets(&graph));
+
+    // Ids appearing only in the adjacency map have no label.
+    let mut adjacency = adjacency;
+    adjacency.insert(u64::MAX, vec![to_id(0)]);
+    assert!(HashMapGraph::new(&adjacency, &node_labels).is_err());
+}
+
+#[test]
+fn test_implicit_graph() {
+    // Nodes on a line are adjacent when closer than the cutoff, which yields
+    // a four-path, a chordal cycle, and an isolated node.
+    let positions: [f64; 9] = [0.0, 1.0, 2.0, 3.0, 5.0, 6.0, 6.5, 7.5, 10.0];
+    let node_labels = vec![0, 1, 0, 1, 1, 0, 2, 2, 0];
+    let implicit_graph = ImplicitGraph::new(
+        |src: usize, dst: usize| (positions[src] - positions[dst]).abs() <= 1.5,
+        node_labels.clone(),
+    );
+
+    let edges = [
+        (0, 1),
+        (1, 2),
+        (2, 3),
+        (4, 5),
+        (4, 6),
+        (5, 6),
+        (6, 7),
+        (5, 7),
+    ];
+    let mut adjacency: HashMap<usize, Vec<usize>> = HashMap::new();
+    for (src, dst) in edges {
+        adjacency.entry(src).or_default().push(dst);
+        adjacency.entry(dst).or_default().push(src);
+    }
+    let explicit_graph = HashMapGraph::new(
+        &adjacency,
+        &node_labels.iter().copied().enumerate().collect(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        implicit_graph.get_number_of_edges(),
+        explicit_graph.get_number_of_edges()
+    );
+    for node in 0..explicit_graph.get_number_of_nodes() {
+        assert_eq!(
+            implicit_graph.iter_neighbours(node).collect::<Vec<_>>(),
+            explicit_graph.iter_neighbours(node).collect::<Vec<_>>()
+        );
+    }
+
+    let mut implicit_counts: HashMap<u16, u32> = HashMap::new();
+    let mut explicit_counts: HashMap<u16, u32> = HashMap::new();
+    for (src, dst) in edges {
+        for (graphlet, count) in HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_graphlet(
+            &implicit_graph,
+            src,
+            dst,
+        ) {
+            implicit_counts.insert_count(graphlet, count);
+        }
+        for (graphlet, count) in HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_graphlet(
+            &explicit_graph,
+            src,
+            dst,
+        ) {
+            explicit_counts.insert_count(graphlet, count);
+        }
+    }
+    assert_eq!(implicit_counts, explicit_counts);
+}
+
+#[cfg(feature = "petgraph")]
+#[test]
+fn test_petgraph_adapter() {
+    for graph_name in ["wheel", "tailed_four_clique", "citeseer"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+
+        // We insert the edges in descending order, so that petgraph iterates
+        // over the neighbours of each node in ascending order only by chance.
+        let mut petgraph_graph = petgraph::graph::UnGraph::<u8, ()>::new_undirected();
+        let nodes = (0..graph.get_number_of_nodes())
+            .map(|node| petgraph_graph.add_node(graph.get_node_label(node)))
+            .collect::<Vec<_>>();
+        for src in (0..graph.get_number_of_nodes()).rev() {
+            for dst in graph.iter_neighbours(src).filter(|&dst| src < dst) {
+                petgraph_graph.add_edge(nodes[dst], nodes[src], ());
+                // Parallel edges are discarded by the adapter.
+                petgraph_graph.add_edge(nodes[src], nodes[dst], ());
+            }
+        }
+
+        let adapter = PetgraphAdapter::new(&petgraph_graph, |&label| label as usize);
+        assert_eq!(adapter.validate_sorted_neighbours(), Ok(()));
+        assert_eq!(adapter.get_number_of_edges(), graph.get_number_of_edges());
+        assert_eq!(*adapter.get_node_weight(0), graph.get_node_label(0));
+        assert_eq!(
+            HeterogeneousGraphlets::<u16, u32>::count_all_graphlets(&adapter),
+            graph.count_all_graphlets(),
+            "{}",
+            graph_name
+        );
+    }
+}
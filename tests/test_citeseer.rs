@@ -1,5 +1,10 @@
 mod test_from_csv;
-use test_from_csv::test_from_csv;
+use heterogeneous_graphlets::perfect_graphlet_hash::{GraphletHashLayout, PolynomialLayout};
+use heterogeneous_graphlets::prelude::*;
+use std::collections::HashMap;
+use test_from_csv::{
+    assert_isomorphism_invariant, count_graphlets, count_graphlets_dense, test_from_csv,
+};
 
 #[test]
 fn test_citeseer() {
@@ -9,3 +14,37 @@ fn test_citeseer() {
         "tests/data/citeseer/edge_list.csv",
     );
 }
+
+#[test]
+fn test_citeseer_dense_counter() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/citeseer/node_list.csv",
+        "tests/data/citeseer/edge_list.csv",
+    )
+    .unwrap();
+    let number_of_node_labels = graph.get_number_of_node_labels();
+
+    let decode = |graphlet: u16| {
+        PolynomialLayout::decode::<ExtendedGraphletType>(graphlet, number_of_node_labels)
+    };
+    let hashmap_counts = count_graphlets(&graph)
+        .into_iter()
+        .map(|(graphlet, count)| (decode(graphlet), count))
+        .collect::<HashMap<_, _>>();
+    let dense_counts = count_graphlets_dense(&graph)
+        .iter_graphlets_and_counts()
+        .map(|(graphlet, count)| (decode(graphlet), count))
+        .collect::<HashMap<_, _>>();
+
+    assert_eq!(hashmap_counts, dense_counts);
+}
+
+#[test]
+fn test_citeseer_isomorphism_invariance() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/citeseer/node_list.csv",
+        "tests/data/citeseer/edge_list.csv",
+    )
+    .unwrap();
+    assert_isomorphism_invariant(&graph, 3);
+}
@@ -1,6 +1,9 @@
 mod test_from_csv;
-use test_from_csv::test_from_csv;
-
+use heterogeneous_graphlets::perfect_graphlet_hash::PolynomialLayout;
+use heterogeneous_graphlets::prelude::*;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use test_from_csv::{count_graphlets, test_from_csv, DenseCounterGraph};
 
 #[test]
 fn test_cora() {
@@ -10,3 +13,139 @@ fn test_cora() {
         "tests/data/cora/edge_list.csv",
     );
 }
+
+#[test]
+fn test_cora_cost_scheduled() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+
+    let mut sequential_counts: HashMap<u16, u32> = HashMap::new();
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in graph.get_heterogeneous_graphlet(src, dst) {
+            sequential_counts.insert_count(graphlet, count);
+        }
+    }
+
+    let scheduled_counts = graph
+        .par_iter_edges_by_cost()
+        .filter(|(src, dst)| src < dst)
+        .map(|(src, dst)| graph.get_heterogeneous_graphlet(src, dst))
+        .reduce(HashMap::new, |mut left, right| {
+            left.merge(&right);
+            left
+        });
+
+    assert_eq!(sequential_counts, scheduled_counts);
+}
+
+#[test]
+fn test_cora_chunk_sizes() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+
+    let counts = count_graphlets(&graph);
+    let dense_graph = DenseCounterGraph(&graph);
+    for chunk_size in [1, 7, 64, 1024, usize::MAX] {
+        assert_eq!(
+            graph.par_get_graph_graphlet_counts_with_chunk_size(chunk_size),
+            counts,
+            "{}",
+            chunk_size
+        );
+        assert_eq!(
+            dense_graph
+                .par_get_graph_graphlet_counts_with_chunk_size(chunk_size)
+                .iter_graphlets_and_counts()
+                .collect::<HashMap<u16, u32>>(),
+            counts,
+            "{}",
+            chunk_size
+        );
+    }
+}
+
+#[test]
+fn test_cora_write_coo() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let number_of_node_labels = graph.get_number_of_node_labels();
+
+    let mut stream = Vec::new();
+    counts
+        .write_coo::<_, _, PolynomialLayout>(&mut stream, number_of_node_labels)
+        .unwrap();
+
+    let mut streamed_triples = String::from_utf8(stream)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let values = line.split(',').collect::<Vec<_>>();
+            assert_eq!(values.len(), 3, "{}", line);
+            (
+                values[0].parse::<usize>().unwrap(),
+                values[1].parse::<usize>().unwrap(),
+                values[2].parse::<u32>().unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    let mut triples = counts.to_coo::<_, PolynomialLayout>(number_of_node_labels);
+
+    assert_eq!(triples.len(), counts.len());
+    streamed_triples.sort_unstable();
+    triples.sort_unstable();
+    assert_eq!(streamed_triples, triples);
+}
+
+#[test]
+fn test_cora_bounded_reducer() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/cora/node_list.csv",
+        "tests/data/cora/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    assert!(BoundedReducer::<u16, u32>::new(0).is_err());
+    let maximal_number_of_graphlets = 64;
+    assert!(counts.len() > 4 * maximal_number_of_graphlets);
+
+    // Each thread flushes its own runs, which are then gathered in a single reducer.
+    let reducer = graph
+        .par_iter_edges()
+        .filter(|(src, dst)| src < dst)
+        .try_fold(
+            || BoundedReducer::new(maximal_number_of_graphlets).unwrap(),
+            |mut reducer, (src, dst)| {
+                reducer.insert_counter(&graph.get_heterogeneous_graphlet(src, dst))?;
+                Ok::<_, String>(reducer)
+            },
+        )
+        .try_reduce(
+            || BoundedReducer::new(maximal_number_of_graphlets).unwrap(),
+            |mut left, right| {
+                left.append(right)?;
+                Ok(left)
+            },
+        )
+        .unwrap();
+    assert!(reducer.number_of_runs() > 1);
+
+    let merged_counts = reducer
+        .into_sorted_iter()
+        .unwrap()
+        .collect::<Result<Vec<(u16, u32)>, String>>()
+        .unwrap();
+    assert!(merged_counts
+        .windows(2)
+        .all(|window| window[0].0 < window[1].0));
+    assert_eq!(merged_counts.into_iter().collect::<HashMap<_, _>>(), counts);
+}
@@ -1,235 +1,153 @@
+#![allow(dead_code)]
+
 use std::collections::HashMap;
 
+use heterogeneous_graphlets::perfect_graphlet_hash::{GraphletHashLayout, PolynomialLayout};
 use heterogeneous_graphlets::prelude::*;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
 use rayon::prelude::*;
 
-/// Compressed Sparse Row Graph
-pub struct CSRGraph {
-    /// The number of nodes in the graph.
-    number_of_nodes: usize,
-    /// The number of edges in the graph.
-    number_of_edges: usize,
-    /// The number of node labels in the graph.
-    number_of_node_labels: u8,
-    /// The node labels of the graph.
-    node_labels: Vec<u8>,
-    /// The offsets of the graph.
-    offsets: Vec<usize>,
-    /// The edges of the graph.
-    edges: Vec<usize>,
-}
-
-unsafe impl Send for CSRGraph {}
-unsafe impl Sync for CSRGraph {}
-
-fn read_csv(path: &str) -> Result<Vec<Vec<usize>>, String> {
-    let mut reader = csv::ReaderBuilder::new()
-        .has_headers(false)
-        .from_path(path)
-        .map_err(|e| e.to_string())?;
-    let mut result = Vec::new();
-    for record in reader.records() {
-        let record = record.map_err(|e| e.to_string())?;
-        result.push(
-            record
-                .into_iter()
-                .map(|value| value.parse::<usize>().map_err(|e| e.to_string()))
-                .collect::<Result<Vec<usize>, String>>()?,
-        );
-    }
-    Ok(result)
-}
-
-impl CSRGraph {
-    /// Create a new CSRGraph from the provided node list and edge list.
-    ///
-    /// # Arguments
-    /// * `node_list_path` - The path to the node list.
-    /// * `edge_list_path` - The path to the edge list.
-    ///
-    /// # Implementation details
-    /// We expect the node list to be a csv file containing a single column
-    /// with the NUMERIC node labels. The length of the column must be equal
-    /// to the number of nodes in the graph. An example of the node list is
-    /// the following:
-    ///
-    /// ```csv
-    /// 0
-    /// 1
-    /// 2
-    /// 1
-    /// 4
-    /// ```
-    ///
-    /// The edge list must be a csv file containing two columns with the NUMERIC
-    /// source and destination node IDs. The length of the columns must be equal
-    /// to the number of edges in the graph. An example of the edge list is the
-    /// following:
-    ///
-    /// ```csv
-    /// 0,1
-    /// 1,2
-    /// 2,3
-    /// 1,4
-    /// 4,5
-    /// ```
-    ///
-    pub fn from_csv(node_list_path: &str, edge_list_path: &str) -> Result<Self, String> {
-        let mut edge_list = read_csv(edge_list_path)?;
-        edge_list.sort_unstable();
-
-        let number_of_edges = edge_list.len();
-
-        let node_labels = read_csv(node_list_path)?
+/// A layout in base `number_of_elements + 1`, so that the dummy label is a digit.
+pub struct OffsetLayout;
+
+impl GraphletHashLayout<u64, usize> for OffsetLayout {
+    fn encode<GraphletKind: GraphletSet<u64> + From<u64>>(
+        labels: (usize, usize, usize, usize),
+        graphlet_kind: GraphletKind,
+        number_of_elements: usize,
+    ) -> u64
+    where
+        u64: From<GraphletKind>,
+    {
+        let base = number_of_elements as u64 + 1;
+        [labels.0, labels.1, labels.2, labels.3]
             .into_iter()
-            .map(|node_label| {
-                assert!(node_label.len() == 1);
-                node_label[0] as u8
+            .fold(u64::from(graphlet_kind), |encoded, label| {
+                encoded * base + label as u64
             })
-            .collect::<Vec<u8>>();
-        let number_of_nodes = node_labels.len();
-        let mut offsets = Vec::with_capacity(number_of_nodes + 1);
-        let mut edges = Vec::with_capacity(number_of_edges);
-
-        let mut current_node = 0;
-        offsets.push(0);
-
-        for edge in edge_list {
-            let src = edge[0];
-            let dst = edge[1];
-            assert!(
-                src < number_of_nodes,
-                "src: {}, number_of_nodes: {}",
-                src,
-                number_of_nodes
-            );
-            assert!(
-                dst < number_of_nodes,
-                "dst: {}, number_of_nodes: {}",
-                dst,
-                number_of_nodes
-            );
-            assert!(
-                src != dst,
-                "Primal check: Self-loops are not supported, found: {} -> {}",
-                src,
-                dst
-            );
-            while current_node < src {
-                offsets.push(edges.len());
-                current_node += 1;
-            }
-            edges.push(dst);
-        }
-
-        // We insert the offsets relative to eventual
-        // trailing singleton nodes.
-        while offsets.len() <= number_of_nodes {
-            offsets.push(edges.len());
-        }
-
-        assert_eq!(offsets.len(), number_of_nodes + 1);
-        assert_eq!(edges.len(), number_of_edges);
-        assert_eq!(offsets[0], 0);
-        assert_eq!(offsets[number_of_nodes], edges.len());
-
-        let csr = Self {
-            number_of_nodes,
-            number_of_edges,
-            number_of_node_labels: node_labels.iter().max().unwrap() + 1,
-            node_labels,
-            offsets,
-            edges,
-        };
-
-        csr.par_iter_edges().for_each(|(src, dst)| {
-            assert_ne!(
-                src, dst,
-                "Secondary check: Self-loops are not supported. Found: {} -> {}.\nSpecifically, the src node has neighbours: {:?}, with outbonds from {} to {}.",
-                src, dst,
-                csr.iter_neighbours(src).collect::<Vec<_>>(),
-                csr.offsets[src], csr.offsets[src + 1]
-            )
-        });
+    }
 
-        Ok(csr)
+    fn decode<GraphletKind: GraphletSet<u64> + From<u64>>(
+        encoded: u64,
+        number_of_elements: usize,
+    ) -> (GraphletKind, (usize, usize, usize, usize))
+    where
+        u64: From<GraphletKind>,
+    {
+        let base = number_of_elements as u64 + 1;
+        let digit = |position: u32| (encoded / base.pow(position) % base) as usize;
+        (
+            GraphletKind::from(encoded / base.pow(4)),
+            (digit(3), digit(2), digit(1), digit(0)),
+        )
     }
 
-    /// Iterates in parallel over the edges.
-    pub fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
-        (0..self.number_of_nodes)
-            .into_par_iter()
-            .flat_map(move |node| {
-                self.edges[self.offsets[node]..self.offsets[node + 1]]
-                    .par_iter()
-                    .map(move |dst| (node, *dst))
-            })
+    fn maximal_hash<GraphletKind: GraphletSet<u64> + From<u64>>(number_of_elements: usize) -> u64 {
+        (number_of_elements as u64 + 1).pow(4) * GraphletKind::get_number_of_graphlets() - 1
     }
 }
 
-impl Graph for CSRGraph {
+/// A view of a CSRGraph counting its graphlets with the dense counter.
+pub struct DenseCounterGraph<'a>(pub &'a CSRGraph);
+
+impl<'a> Graph for DenseCounterGraph<'a> {
     type Node = usize;
-    type NeighbourIter<'a> = std::iter::Copied<std::slice::Iter<'a, usize>>;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
 
     fn get_number_of_nodes(&self) -> usize {
-        self.number_of_nodes
+        self.0.get_number_of_nodes()
     }
 
     fn get_number_of_edges(&self) -> usize {
-        self.number_of_edges
+        self.0.get_number_of_edges()
     }
 
     fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
-        let src_offset = self.offsets[node];
-        let dst_offset = self.offsets[node + 1];
-        self.edges[src_offset..dst_offset].iter().copied()
+        self.0.iter_neighbours(node)
     }
 }
 
-impl TypedGraph for CSRGraph {
+impl<'a> ParallelGraph for DenseCounterGraph<'a> {
+    fn par_iter_edges(&self) -> impl ParallelIterator<Item = (usize, usize)> + '_ {
+        self.0.par_iter_edges()
+    }
+}
+
+impl<'a> TypedGraph for DenseCounterGraph<'a> {
     type NodeLabel = u8;
 
     fn get_number_of_node_labels(&self) -> Self::NodeLabel {
-        self.number_of_node_labels
+        self.0.get_number_of_node_labels()
     }
 
     fn get_number_of_node_labels_usize(&self) -> usize {
-        self.number_of_node_labels as usize
+        self.0.get_number_of_node_labels_usize()
     }
 
     fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
-        label_index as u8
+        self.0.get_node_label_from_usize(label_index)
     }
 
     fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
-        label as usize
+        self.0.get_node_label_index(label)
     }
 
     fn get_node_label(&self, node: usize) -> Self::NodeLabel {
-        self.node_labels[node]
+        self.0.get_node_label(node)
     }
 }
 
-impl HeterogeneousGraphlets<u16, u32> for CSRGraph {
-    type GraphLetCounter = HashMap<u16, u32>;
+impl<'a> HeterogeneousGraphlets<u16, u32> for DenseCounterGraph<'a> {
+    type GraphLetCounter = DenseGraphletCounter<u16, u32>;
 }
 
-pub fn test_from_csv(graph_name: &str, node_list: &str, edge_list: &str) {
-    let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+/// Returns the graphlet counts summed over all the edges of the provided graph, using the dense counter.
+///
+/// # Arguments
+/// * `graph` - The graph whose graphlets should be counted.
+pub fn count_graphlets_dense(graph: &CSRGraph) -> DenseGraphletCounter<u16, u32> {
+    let dense_graph = DenseCounterGraph(graph);
+    let mut counter =
+        DenseGraphletCounter::with_number_of_elements(graph.get_number_of_node_labels());
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in dense_graph
+            .get_heterogeneous_graphlet(src, dst)
+            .iter_graphlets_and_counts()
+        {
+            counter.insert_count(graphlet, count);
+        }
+    }
+    counter
+}
 
-    let summed_counts = graph
+/// Returns the graphlet counts summed over all the edges of the provided graph.
+///
+/// # Arguments
+/// * `graph` - The graph whose graphlets should be counted.
+pub fn count_graphlets(graph: &CSRGraph) -> HashMap<u16, u32> {
+    graph
         .par_iter_edges()
         .filter(|(src, dst)| src < dst)
         .map(|(src, dst)| graph.get_heterogeneous_graphlet(src, dst))
         .reduce(
             || HashMap::new(),
             |mut left, right| {
-                for (graphlet, count) in right.iter() {
-                    left.insert_count(*graphlet, *count);
-                }
+                left.merge(&right);
                 left
             },
-        );
+        )
+}
+
+pub fn test_from_csv(graph_name: &str, node_list: &str, edge_list: &str) {
+    let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+
+    let summed_counts = count_graphlets(&graph);
+    assert_eq!(graph.count_all_graphlets(), summed_counts);
     let merged_counts = graph
         .par_iter_edges()
         .filter(|(src, dst)| src < dst)
@@ -237,6 +155,7 @@ pub fn test_from_csv(graph_name: &str, node_list: &str, edge_list: &str) {
         .reduce(
             || HashMap::new(),
             |mut left, right| {
+                // Differently from `merge`, the extension replaces the overlapping counts.
                 left.extend(right);
                 left
             },
@@ -245,10 +164,84 @@ pub fn test_from_csv(graph_name: &str, node_list: &str, edge_list: &str) {
         "{} graph:\nSummed:\n{}\nMerged:\n{}",
         graph_name,
         summed_counts
-            .get_report::<ExtendedGraphletType, u8>(graph.get_number_of_node_labels())
+            .get_report::<ExtendedGraphletType, u8, PolynomialLayout>(
+                graph.get_number_of_node_labels()
+            )
             .unwrap(),
         merged_counts
-            .get_report::<ExtendedGraphletType, u8>(graph.get_number_of_node_labels())
+            .get_report::<ExtendedGraphletType, u8, PolynomialLayout>(
+                graph.get_number_of_node_labels()
+            )
             .unwrap()
     );
 }
+
+/// The graph type of the isomorphic copies returned by [`RandomIsomorphism`].
+pub type RelabeledGraph = CSRGraph;
+
+/// Trait providing randomly node-permuted isomorphic copies of a graph.
+pub trait RandomIsomorphism {
+    /// Returns a copy of the graph with its nodes randomly permuted, and the permutation.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator driving the permutation.
+    ///
+    /// # Implementation details
+    /// The i-th element of the returned permutation is the node of the copy
+    /// corresponding to the i-th node of the graph. The node labels are
+    /// permuted together with the nodes, so the copy is isomorphic to the graph.
+    fn random_isomorphism(&self, rng: &mut impl Rng) -> (RelabeledGraph, Vec<usize>);
+}
+
+impl RandomIsomorphism for CSRGraph {
+    fn random_isomorphism(&self, rng: &mut impl Rng) -> (RelabeledGraph, Vec<usize>) {
+        let number_of_nodes = self.get_number_of_nodes();
+        let mut permutation = (0..number_of_nodes).collect::<Vec<usize>>();
+        permutation.shuffle(rng);
+
+        let mut node_labels = vec![0; number_of_nodes];
+        for (node, &copy_node) in permutation.iter().enumerate() {
+            node_labels[copy_node] = self.get_node_label(node);
+        }
+        let edges = self
+            .iter_edges()
+            .map(|(src, dst)| (permutation[src], permutation[dst]))
+            .collect();
+
+        (
+            CSRGraph::from_edges(node_labels, edges).unwrap(),
+            permutation,
+        )
+    }
+}
+
+/// Checks that the per-kind graphlet counts of the provided graph match those of random isomorphic copies.
+///
+/// # Arguments
+/// * `graph` - The graph to check.
+/// * `number_of_copies` - The number of random isomorphic copies to compare against.
+///
+/// # Implementation details
+/// The counts of the individual keys are not compared, as the order of the labels
+/// in each key follows the node IDs of the endpoints of the edges.
+pub fn assert_isomorphism_invariant(graph: &CSRGraph, number_of_copies: u64) {
+    let counts = count_graphlets(graph);
+    let kind_totals =
+        CounterWithBase::new(counts, graph.get_number_of_node_labels()).get_kind_totals();
+
+    for seed in 1..=number_of_copies {
+        let (copy, permutation) = graph.random_isomorphism(&mut StdRng::seed_from_u64(seed));
+        for (node, &copy_node) in permutation.iter().enumerate() {
+            assert_eq!(graph.get_node_label(node), copy.get_node_label(copy_node));
+            assert_eq!(graph.get_node_degree(node), copy.get_node_degree(copy_node));
+        }
+
+        assert_eq!(
+            CounterWithBase::new(count_graphlets(&copy), copy.get_number_of_node_labels())
+                .get_kind_totals(),
+            kind_totals,
+            "The per-kind counts differ for the copy with seed {}.",
+            seed
+        );
+    }
+}
@@ -0,0 +1,285 @@
+mod test_from_csv;
+use heterogeneous_graphlets::perfect_graphlet_hash::PolynomialLayout;
+use heterogeneous_graphlets::prelude::*;
+use test_from_csv::count_graphlets;
+
+#[test]
+fn test_csr_from_edges() {
+    // The edges are provided unsorted and with duplicates.
+    let graph = CSRGraph::from_edges(
+        vec![0, 1, 0, 1],
+        vec![(2, 1), (0, 1), (1, 0), (1, 2), (0, 1), (2, 3), (3, 2)],
+    )
+    .unwrap();
+
+    assert_eq!(graph.get_number_of_nodes(), 4);
+    assert_eq!(graph.get_number_of_edges(), 6);
+    assert_eq!(graph.get_number_of_node_labels(), 2);
+    assert_eq!(graph.get_neighbours(1), &[0, 2]);
+    assert_eq!(graph.iter_neighbours(3).collect::<Vec<_>>(), vec![2]);
+    assert!(graph.is_neighbour(2, 3));
+    assert!(!graph.is_neighbour(0, 3));
+    assert_eq!(
+        graph.iter_edges().collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (1, 2), (2, 1), (2, 3), (3, 2)]
+    );
+
+    assert!(CSRGraph::from_edges(vec![0, 1], vec![(0, 2)]).is_err());
+    assert!(CSRGraph::from_edges(vec![0, 1], vec![(1, 1)]).is_err());
+    assert!(CSRGraph::from_edges(vec![0, u8::MAX], vec![(0, 1)]).is_err());
+}
+
+#[test]
+fn test_csr_from_directed_edges() {
+    // The directed fixture has the same topology of the undirected one, with most
+    // edges provided in a single direction and one of them in both directions.
+    let directed_graph = CSRGraph::from_directed_csv(
+        "tests/data/directed_triangle_and_path/node_list.csv",
+        "tests/data/directed_triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+
+    assert_eq!(
+        directed_graph.iter_edges().collect::<Vec<_>>(),
+        graph.iter_edges().collect::<Vec<_>>()
+    );
+    assert_eq!(count_graphlets(&directed_graph), count_graphlets(&graph));
+
+    // Loaded as they are, the directed edges are not symmetric.
+    let asymmetric_graph = CSRGraph::from_csv(
+        "tests/data/directed_triangle_and_path/node_list.csv",
+        "tests/data/directed_triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    assert!(asymmetric_graph.has_edge(0, 1));
+    assert!(!asymmetric_graph.has_edge(1, 0));
+    assert!(directed_graph.has_edge(1, 0));
+
+    assert!(CSRGraph::from_directed_edges(vec![0, 1], vec![(0, 2)]).is_err());
+}
+
+#[test]
+fn test_csr_from_edges_with_isolated_nodes() {
+    // Nodes 2 and 3 are consecutive sources without edges, and node 6 is trailing.
+    let graph = CSRGraph::from_edges(
+        vec![0, 1, 0, 1, 0, 1, 0],
+        vec![(0, 1), (1, 0), (1, 4), (4, 1), (4, 5), (5, 4)],
+    )
+    .unwrap();
+
+    assert_eq!(graph.get_number_of_nodes(), 7);
+    assert_eq!(graph.get_number_of_edges(), 6);
+    let neighbours = (0..graph.get_number_of_nodes())
+        .map(|node| graph.iter_neighbours(node).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        neighbours,
+        vec![
+            vec![1],
+            vec![0, 4],
+            vec![],
+            vec![],
+            vec![1, 5],
+            vec![4],
+            vec![],
+        ]
+    );
+    for (node, node_neighbours) in neighbours.iter().enumerate() {
+        assert_eq!(graph.get_node_degree(node), node_neighbours.len());
+    }
+    assert_eq!(
+        graph.iter_edges().collect::<Vec<_>>(),
+        vec![(0, 1), (1, 0), (1, 4), (4, 1), (4, 5), (5, 4)]
+    );
+}
+
+#[test]
+fn test_graph_builder() {
+    let mut builder = GraphBuilder::new();
+    let center = builder.add_node(0);
+    let leaves = [
+        builder.add_node(0),
+        builder.add_node(0),
+        builder.add_node(0),
+    ];
+    assert_eq!(builder.add_node(1), 4);
+    assert_eq!(builder.get_number_of_nodes(), 5);
+    for leaf in leaves {
+        builder.add_edge(center, leaf).unwrap();
+    }
+    // The duplicated and reversed edges are merged with the ones already added.
+    builder.add_edge(leaves[0], center).unwrap();
+    builder.add_edge(center, leaves[1]).unwrap();
+    assert!(builder.add_edge(center, center).is_err());
+
+    let graph = builder.build().unwrap();
+    let expected = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(graph, expected);
+    assert_eq!(graph.count_all_graphlets(), expected.count_all_graphlets());
+
+    // The endpoints are checked against the nodes added when building.
+    let mut builder = GraphBuilder::new();
+    builder.add_node(0);
+    builder.add_edge(0, 1).unwrap();
+    assert!(builder.clone().build().is_err());
+    builder.add_node(1);
+    assert_eq!(builder.build().unwrap().get_number_of_edges(), 2);
+}
+
+#[test]
+fn test_from_csv_with_node_id_column() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let graph_with_node_ids = CSRGraph::from_csv_with_node_id_column(
+        "tests/data/four_star/node_list_with_ids.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+
+    assert_eq!(
+        graph.get_number_of_nodes(),
+        graph_with_node_ids.get_number_of_nodes()
+    );
+    assert_eq!(
+        graph.get_number_of_edges(),
+        graph_with_node_ids.get_number_of_edges()
+    );
+    for node in 0..graph.get_number_of_nodes() {
+        assert_eq!(
+            graph.get_node_label(node),
+            graph_with_node_ids.get_node_label(node)
+        );
+        assert_eq!(
+            graph.iter_neighbours(node).collect::<Vec<_>>(),
+            graph_with_node_ids
+                .iter_neighbours(node)
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_from_mtx() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let mtx_graph = CSRGraph::from_mtx(
+        "tests/data/wheel_mtx/adjacency.mtx",
+        "tests/data/wheel_mtx/node_list.csv",
+    )
+    .unwrap();
+    assert_eq!(mtx_graph, graph);
+    assert_eq!(mtx_graph.count_all_graphlets(), graph.count_all_graphlets());
+
+    // The matrix must have a row and a column per node.
+    assert!(CSRGraph::from_mtx(
+        "tests/data/wheel_mtx/adjacency.mtx",
+        "tests/data/four_path/node_list.csv",
+    )
+    .is_err());
+    // The file must start with the Matrix Market header.
+    assert!(CSRGraph::from_mtx(
+        "tests/data/wheel/edge_list.csv",
+        "tests/data/wheel/node_list.csv",
+    )
+    .is_err());
+}
+
+#[test]
+fn test_from_edge_list() {
+    for (graph_name, number_of_triangles) in [
+        ("triangle", 1),
+        ("four_path", 0),
+        ("four_clique", 4),
+        ("wheel", 5),
+    ] {
+        let labeled_graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let graph =
+            CSRGraph::from_edge_list(&format!("tests/data/{}/edge_list.csv", graph_name), 0)
+                .unwrap();
+        assert_eq!(graph.get_number_of_node_labels(), 1);
+        assert_eq!(
+            graph.iter_edges().collect::<Vec<_>>(),
+            labeled_graph.iter_edges().collect::<Vec<_>>()
+        );
+
+        // Each triangle is counted once per edge.
+        let counts = graph.count_all_graphlets();
+        assert_eq!(
+            counts.total_for_kind::<_, PolynomialLayout>(ExtendedGraphletType::Triangle, 1_u8),
+            3 * number_of_triangles,
+            "{}",
+            graph_name
+        );
+    }
+
+    // The default label must be a valid node label.
+    assert!(CSRGraph::from_edge_list("tests/data/triangle/edge_list.csv", u8::MAX).is_err());
+}
+
+#[cfg(feature = "gzip")]
+#[test]
+fn test_gzipped_csv() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let gzipped_graph = CSRGraph::from_csv(
+        "tests/data/wheel_gzip/node_list.csv.gz",
+        "tests/data/wheel_gzip/edge_list.csv.gz",
+    )
+    .unwrap();
+    assert_eq!(gzipped_graph, graph);
+    assert_eq!(
+        CSRGraph::from_edge_list("tests/data/wheel_gzip/edge_list.csv.gz", 0).unwrap(),
+        CSRGraph::from_edge_list("tests/data/wheel/edge_list.csv", 0).unwrap()
+    );
+}
+
+#[test]
+fn test_from_sorted_csv_streaming() {
+    for graph_name in ["wheel", "four_clique", "tailed_four_clique", "six_clique"] {
+        let node_list_path = format!("tests/data/{}/node_list.csv", graph_name);
+        let edge_list_path = format!("tests/data/{}/edge_list.csv", graph_name);
+        assert_eq!(
+            CSRGraph::from_sorted_csv_streaming(&node_list_path, &edge_list_path).unwrap(),
+            CSRGraph::from_csv(&node_list_path, &edge_list_path).unwrap()
+        );
+    }
+
+    // The edge list of the wheel, starting from the edges of the node 1.
+    assert!(CSRGraph::from_csv(
+        "tests/data/unsorted_wheel/node_list.csv",
+        "tests/data/unsorted_wheel/edge_list.csv",
+    )
+    .is_ok());
+    assert!(CSRGraph::from_sorted_csv_streaming(
+        "tests/data/unsorted_wheel/node_list.csv",
+        "tests/data/unsorted_wheel/edge_list.csv",
+    )
+    .is_err());
+    assert!(CSRGraph::from_sorted_csv_streaming(
+        "tests/data/wheel_with_self_loops/node_list.csv",
+        "tests/data/wheel_with_self_loops/edge_list.csv",
+    )
+    .is_err());
+}
@@ -0,0 +1,66 @@
+use heterogeneous_graphlets::prelude::*;
+
+#[test]
+fn test_random_graph_small() {
+    for seed in 0..100 {
+        let graph = RandomGraph::new(seed, 3, 2, 2);
+        let edges = graph.iter_edges().collect::<Vec<_>>();
+
+        assert!(!edges.is_empty());
+        assert_eq!(edges.len(), graph.get_number_of_edges());
+        // The edges are sorted and without duplicates.
+        assert!(edges.windows(2).all(|window| window[0] < window[1]));
+        // The edges have no self-loops and are undirected.
+        assert!(edges.iter().all(|&(src, dst)| src != dst));
+        assert!(edges.iter().all(|&(src, dst)| edges.contains(&(dst, src))));
+        // Every node, including the first one, has at least one neighbour.
+        for node in 0..graph.get_number_of_nodes() {
+            assert!(graph.iter_neighbours(node).next().is_some());
+            assert!(graph.get_node_label(node) < graph.get_number_of_node_labels());
+        }
+    }
+
+    // Degenerate graphs are handled gracefully.
+    assert_eq!(RandomGraph::new(0, 0, 1, 2).get_number_of_edges(), 0);
+    assert_eq!(RandomGraph::new(0, 1, 1, 2).get_number_of_edges(), 0);
+    assert_eq!(RandomGraph::new(0, 5, 1, 0).get_number_of_edges(), 0);
+}
+
+#[test]
+fn test_random_graph_neighbours() {
+    let graph = RandomGraph::new(0x5EED, 1_000, 4, 3);
+    let edges = graph.iter_edges().collect::<Vec<_>>();
+    assert_eq!(edges.len(), graph.get_number_of_edges());
+
+    for node in 0..graph.get_number_of_nodes() {
+        let neighbours = graph.iter_neighbours(node).collect::<Vec<_>>();
+        // The neighbours are sorted, as the graphlet counting requires.
+        assert!(neighbours.windows(2).all(|window| window[0] < window[1]));
+        assert_eq!(graph.get_node_degree(node), neighbours.len());
+        assert_eq!(
+            neighbours,
+            edges
+                .iter()
+                .filter(|&&(src, _)| src == node)
+                .map(|&(_, dst)| dst)
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "csr")]
+fn test_random_graph_graphlets() {
+    let graph = RandomGraph::new(0x5EED, 200, 3, 3);
+    let csr = CSRGraph::from_edges(
+        (0..graph.get_number_of_nodes())
+            .map(|node| graph.get_node_label(node))
+            .collect(),
+        graph.iter_edges().collect(),
+    )
+    .unwrap();
+
+    let counts: DefaultCounter<u16, u32> = graph.count_all_graphlets();
+    assert!(!counts.is_empty());
+    assert_eq!(counts, csr.count_all_graphlets());
+}
@@ -0,0 +1,579 @@
+mod test_from_csv;
+use heterogeneous_graphlets::perfect_graphlet_hash::{
+    GraphletHashLayout, NTuplePerfectHash, PolynomialLayout, SentinelLayout,
+};
+use heterogeneous_graphlets::prelude::*;
+use std::collections::HashMap;
+use test_from_csv::{count_graphlets, count_graphlets_dense};
+
+/// Returns the provided counts re-encoded with the sentinel layout.
+fn to_sentinel_layout(counts: &HashMap<u16, u32>, number_of_elements: u8) -> HashMap<u64, u32> {
+    counts
+        .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+        .map(|(graphlet_kind, (first, second, third, fourth), count)| {
+            let fourth = if graphlet_kind.node_count() == 3 {
+                <SentinelLayout as GraphletHashLayout<u64, u8>>::dummy_label(number_of_elements)
+            } else {
+                fourth
+            };
+            (
+                <SentinelLayout as GraphletHashLayout<u64, u8>>::encode(
+                    (first, second, third, fourth),
+                    graphlet_kind,
+                    number_of_elements,
+                ),
+                count,
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_get_report_with_labels() {
+    let mut builder = GraphBuilder::new();
+    let nodes = [0, 1, 1, 2].map(|label| builder.add_node(label));
+    for (index, &src) in nodes.iter().enumerate() {
+        for &dst in &nodes[index + 1..] {
+            builder.add_edge(src, dst).unwrap();
+        }
+    }
+    let graph = builder.build().unwrap();
+    let counts = graph.count_all_graphlets();
+    let number_of_elements = graph.get_number_of_node_labels();
+
+    let report = counts
+        .get_report::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+        .unwrap();
+    assert_eq!(report.lines().count(), counts.len());
+    assert!(report.lines().any(|line| line.starts_with("FourClique(")));
+
+    // Each four-clique line holds the names of the labels of the four nodes, while
+    // the three-node graphlets also hold the dummy label, which has no name.
+    let names = ["paper", "author", "venue"];
+    let named_report = counts
+        .get_report_with_label_names::<ExtendedGraphletType, u8, PolynomialLayout, _>(
+            number_of_elements,
+            |label| names.get(label as usize).unwrap_or(&"none").to_string(),
+        )
+        .unwrap();
+    for line in named_report
+        .lines()
+        .filter(|line| line.starts_with("FourClique("))
+    {
+        let labels = &line["FourClique(".len()..line.find(')').unwrap()];
+        let mut labels = labels.split(", ").collect::<Vec<_>>();
+        labels.sort_unstable();
+        assert_eq!(labels, ["author", "author", "paper", "venue"], "{}", line);
+    }
+
+    // The report by type sums the counts over the labels, one line per kind.
+    let report_by_type = counts
+        .get_report_by_type::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+        .unwrap();
+    assert_eq!(report_by_type, "FourClique: 6\nTriangle: 12\n");
+}
+
+#[test]
+fn test_get_report_with_two_labels() {
+    // With more than one label, the three-node graphlets must be decoded with the
+    // layout of the counter, so that their labels and dummy label are not shifted.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let number_of_elements = graph.get_number_of_node_labels();
+    assert_eq!(number_of_elements, 2);
+
+    let mut lines = counts
+        .get_report::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+        .unwrap()
+        .lines()
+        .map(str::to_string)
+        .collect::<Vec<_>>();
+    lines.sort_unstable();
+    assert_eq!(
+        lines,
+        [
+            "FourPathCenter(0, 0, 0, 0): 1",
+            "FourPathEdge(0, 0, 0, 0): 2",
+            "Triad(0, 0, 0, 2): 4",
+            "Triangle(1, 1, 1, 2): 3",
+        ]
+    );
+
+    // The dummy label is passed to the label names as returned by the layout.
+    let names = ["paper", "author"];
+    let dummy_label =
+        <PolynomialLayout as GraphletHashLayout<u16, u8>>::dummy_label(number_of_elements);
+    let named_report = counts
+        .get_report_with_label_names::<ExtendedGraphletType, u8, PolynomialLayout, _>(
+            number_of_elements,
+            |label| {
+                if label == dummy_label {
+                    "-".to_string()
+                } else {
+                    names[label as usize].to_string()
+                }
+            },
+        )
+        .unwrap();
+    assert!(named_report
+        .lines()
+        .any(|line| line == "Triad(paper, paper, paper, -): 4"));
+    assert!(named_report
+        .lines()
+        .any(|line| line == "Triangle(author, author, author, -): 3"));
+
+    let mut decoded = counts
+        .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+        .collect::<Vec<_>>();
+    decoded.sort_unstable_by_key(|&(_, labels, count)| (count, labels));
+    assert_eq!(
+        decoded[2..],
+        [
+            (ExtendedGraphletType::Triangle, (1, 1, 1, 2), 3),
+            (ExtendedGraphletType::Triad, (0, 0, 0, 2), 4),
+        ]
+    );
+    assert_eq!(
+        counts.top_k::<_, PolynomialLayout>(2, number_of_elements),
+        [
+            (ExtendedGraphletType::Triad, (0, 0, 0, 2), 4),
+            (ExtendedGraphletType::Triangle, (1, 1, 1, 2), 3),
+        ]
+    );
+
+    // The same counts encoded with the sentinel layout decode to the same lines,
+    // with the sentinel as the dummy label.
+    let sentinel_counts = to_sentinel_layout(&counts, number_of_elements);
+    let sentinel_report = sentinel_counts
+        .get_report::<ExtendedGraphletType, u8, SentinelLayout>(number_of_elements)
+        .unwrap();
+    assert!(sentinel_report
+        .lines()
+        .any(|line| line == "Triangle(1, 1, 1, 255): 3"));
+    assert_eq!(
+        sentinel_counts.top_k::<_, SentinelLayout>(1, number_of_elements),
+        [(ExtendedGraphletType::Triad, (0, 0, 0, 255), 4)]
+    );
+
+    // The totals and the report by type also decode the kinds with the layout.
+    for graphlet_kind in ExtendedGraphletType::all() {
+        assert_eq!(
+            sentinel_counts.total_for_kind::<_, SentinelLayout>(graphlet_kind, number_of_elements),
+            counts.total_for_kind::<_, PolynomialLayout>(graphlet_kind, number_of_elements),
+            "{}",
+            graphlet_kind
+        );
+    }
+    assert_eq!(
+        sentinel_counts
+            .get_report_by_type::<ExtendedGraphletType, u8, SentinelLayout>(number_of_elements)
+            .unwrap(),
+        counts
+            .get_report_by_type::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_to_coo_columns() {
+    // The columns are the label quadruples in base n+1, with the missing fourth
+    // label of the three-node graphlets written as n whatever the layout.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let number_of_elements = graph.get_number_of_node_labels();
+    assert_eq!(number_of_elements, 2);
+
+    let mut triples = counts.to_coo::<_, PolynomialLayout>(number_of_elements);
+    triples.sort_unstable();
+    let row = |graphlet_kind: ExtendedGraphletType| u8::from(graphlet_kind) as usize;
+    let mut expected_triples = vec![
+        (row(ExtendedGraphletType::Triad), 2, 4),
+        (row(ExtendedGraphletType::Triangle), 27 + 9 + 3 + 2, 3),
+        (row(ExtendedGraphletType::FourPathEdge), 0, 2),
+        (row(ExtendedGraphletType::FourPathCenter), 0, 1),
+    ];
+    expected_triples.sort_unstable();
+    assert_eq!(triples, expected_triples);
+    assert!(triples
+        .iter()
+        .all(|&(_, column, _)| column < 3_usize.pow(4)));
+
+    let mut sentinel_triples = to_sentinel_layout(&counts, number_of_elements)
+        .to_coo::<_, SentinelLayout>(number_of_elements);
+    sentinel_triples.sort_unstable();
+    assert_eq!(sentinel_triples, expected_triples);
+}
+
+#[test]
+fn test_write_report_csv() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/labeled_paths/node_list.csv",
+        "tests/data/labeled_paths/edge_list.csv",
+    )
+    .unwrap();
+    let counts = graph.count_all_graphlets();
+    let number_of_elements = graph.get_number_of_node_labels();
+
+    let mut buffer = Vec::new();
+    counts
+        .write_report_csv::<_, _, PolynomialLayout>(&mut buffer, number_of_elements)
+        .unwrap();
+    let mut reader = csv::Reader::from_reader(buffer.as_slice());
+    assert_eq!(
+        reader.headers().unwrap(),
+        vec![
+            "graphlet_type",
+            "label_0",
+            "label_1",
+            "label_2",
+            "label_3",
+            "count"
+        ]
+    );
+
+    // Each row re-encodes to a graphlet of the counter, with the same count.
+    let mut rebuilt: HashMap<u16, u32> = HashMap::new();
+    for record in reader.records() {
+        let record = record.unwrap();
+        let graphlet_kind = record[0].parse::<ExtendedGraphletType>().unwrap();
+        let labels: [u8; 4] = std::array::from_fn(|index| record[index + 1].parse().unwrap());
+        rebuilt.insert_count(
+            labels.encode_with_graphlet(graphlet_kind, number_of_elements),
+            record[5].parse().unwrap(),
+        );
+    }
+    assert_eq!(rebuilt, counts);
+
+    // The rows of the sentinel layout differ solely by the dummy label.
+    let rows = |buffer: Vec<u8>| {
+        let mut rows = String::from_utf8(buffer)
+            .unwrap()
+            .lines()
+            .map(str::to_string)
+            .collect::<Vec<_>>();
+        rows.sort_unstable();
+        rows
+    };
+    let mut sentinel_buffer = Vec::new();
+    to_sentinel_layout(&counts, number_of_elements)
+        .write_report_csv::<_, _, SentinelLayout>(&mut sentinel_buffer, number_of_elements)
+        .unwrap();
+    let dummy_label = format!(
+        ",{},",
+        <PolynomialLayout as GraphletHashLayout<u16, u8>>::dummy_label(number_of_elements)
+    );
+    assert_eq!(
+        rows(sentinel_buffer),
+        rows(buffer)
+            .into_iter()
+            .map(|row| row.replace(&dummy_label, ",255,"))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_combined_report() {
+    let four_path = CSRGraph::from_csv(
+        "tests/data/four_path/node_list.csv",
+        "tests/data/four_path/edge_list.csv",
+    )
+    .unwrap();
+    let four_star = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let four_clique = CSRGraph::from_csv(
+        "tests/data/four_clique/node_list.csv",
+        "tests/data/four_clique/edge_list.csv",
+    )
+    .unwrap();
+
+    let counters = [
+        CounterWithBase::new(
+            count_graphlets(&four_path),
+            four_path.get_number_of_node_labels(),
+        ),
+        CounterWithBase::new(
+            count_graphlets(&four_star),
+            four_star.get_number_of_node_labels(),
+        ),
+        // The four-clique has a single node label, and its kind totals are decoded
+        // with its own number of elements.
+        CounterWithBase::new(
+            count_graphlets(&four_clique),
+            four_clique.get_number_of_node_labels(),
+        ),
+    ];
+
+    let report = combined_report(&counters);
+
+    let rows = report.lines().collect::<Vec<_>>();
+    assert_eq!(rows[0], "Graphlet\tTotal\tGraph 0\tGraph 1\tGraph 2");
+    assert_eq!(rows.len(), 13);
+
+    for row in &rows[1..] {
+        let columns = row.split('\t').collect::<Vec<_>>();
+        assert_eq!(columns.len(), 5);
+        let total: u32 = columns[1].parse().unwrap();
+        let graph_counts: u32 = columns[2..]
+            .iter()
+            .map(|count| count.parse::<u32>().unwrap())
+            .sum();
+        assert_eq!(total, graph_counts, "Mismatched total in row {}", row);
+    }
+
+    assert!(rows.contains(&"FourClique\t6\t0\t0\t6"));
+    assert!(rows.contains(&"FourStar\t3\t0\t3\t0"));
+
+    // The rows follow the order of the graphlet kinds.
+    assert_eq!(
+        rows[1..]
+            .iter()
+            .map(|row| row.split('\t').next().unwrap().to_string())
+            .collect::<Vec<_>>(),
+        ExtendedGraphletType::all()
+            .map(|graphlet_kind| graphlet_kind.to_string())
+            .collect::<Vec<_>>()
+    );
+
+    // The same counts encoded with the sentinel layout give the same report.
+    let sentinel_counters = counters
+        .iter()
+        .map(|counter| {
+            CounterWithBase::<_, _, _, SentinelLayout>::with_layout(
+                to_sentinel_layout(counter.counter(), counter.number_of_elements()),
+                counter.number_of_elements(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(combined_report(&sentinel_counters), report);
+}
+
+#[test]
+fn test_rebase_with_two_labels() {
+    let encode = |graphlet_kind, labels: (u8, u8, u8, u8)| -> u16 {
+        PolynomialLayout::encode(labels, graphlet_kind, 2)
+    };
+    let counter = HashMap::from([
+        (encode(ExtendedGraphletType::Triangle, (0, 0, 1, 2)), 1),
+        (encode(ExtendedGraphletType::Triad, (1, 1, 0, 2)), 4),
+        (encode(ExtendedGraphletType::FourPathEdge, (0, 1, 1, 0)), 2),
+    ]);
+    let counter = CounterWithBase::new(counter, 2_u8);
+    assert!(counter.rebase(1).is_err());
+
+    // The labels of the nodes are kept, while the dummy label of the three-node
+    // graphlets becomes the new number of elements.
+    let rebased = counter.rebase(3).unwrap();
+    assert_eq!(rebased.number_of_elements(), 3);
+    assert_eq!(
+        rebased
+            .counter()
+            .iter()
+            .map(|(&graphlet, &count)| (PolynomialLayout::decode(graphlet, 3), count))
+            .collect::<HashMap<(ExtendedGraphletType, (u8, u8, u8, u8)), u32>>(),
+        HashMap::from([
+            ((ExtendedGraphletType::Triangle, (0, 0, 1, 3)), 1),
+            ((ExtendedGraphletType::Triad, (1, 1, 0, 3)), 4),
+            ((ExtendedGraphletType::FourPathEdge, (0, 1, 1, 0)), 2),
+        ])
+    );
+    assert_eq!(rebased.get_kind_totals(), counter.get_kind_totals());
+
+    // With the sentinel layout, the dummy label is the sentinel for any number of elements.
+    let sentinel_counter = CounterWithBase::<_, _, _, SentinelLayout>::with_layout(
+        to_sentinel_layout(counter.counter(), 2),
+        2_u8,
+    );
+    let sentinel_rebased = sentinel_counter.rebase(3).unwrap();
+    assert_eq!(
+        sentinel_rebased
+            .counter()
+            .iter()
+            .map(|(&graphlet, &count)| (SentinelLayout::decode(graphlet, 3), count))
+            .collect::<HashMap<(ExtendedGraphletType, (u8, u8, u8, u8)), u32>>(),
+        HashMap::from([
+            ((ExtendedGraphletType::Triangle, (0, 0, 1, u8::MAX)), 1),
+            ((ExtendedGraphletType::Triad, (1, 1, 0, u8::MAX)), 4),
+            ((ExtendedGraphletType::FourPathEdge, (0, 1, 1, 0)), 2),
+        ])
+    );
+    assert_eq!(
+        sentinel_rebased.get_kind_totals(),
+        counter.get_kind_totals()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_graphlet_report_serde() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/clique_and_diamond/node_list.csv",
+        "tests/data/clique_and_diamond/edge_list.csv",
+    )
+    .unwrap();
+    let number_of_node_labels = graph.get_number_of_node_labels();
+    let counts = count_graphlets(&graph);
+
+    let report = counts.get_graphlet_report(number_of_node_labels);
+    assert_eq!(report.entries.len(), counts.len());
+    assert_eq!(
+        report.entries.iter().map(|entry| entry.count).sum::<u32>(),
+        counts.values().sum::<u32>()
+    );
+
+    let json = serde_json::to_string(&report).unwrap();
+    let deserialized: GraphletReport<u8, u32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized, report);
+    assert_eq!(deserialized.to_counter::<u16, HashMap<u16, u32>>(), counts);
+
+    // The dense counter yields the same report.
+    let dense_counts = count_graphlets_dense(&graph);
+    assert_eq!(
+        dense_counts.get_graphlet_report(number_of_node_labels),
+        report
+    );
+
+    assert_eq!(
+        serde_json::to_string(&ExtendedGraphletType::FourClique).unwrap(),
+        "\"FourClique\""
+    );
+    assert_eq!(
+        serde_json::from_str::<ReducedGraphletType>("\"TailedTri\"").unwrap(),
+        ReducedGraphletType::TailedTri
+    );
+}
+
+#[test]
+fn test_iter_decoded() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let number_of_node_labels = graph.get_number_of_node_labels();
+    let counts = count_graphlets(&graph);
+
+    let decoded = counts
+        .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_node_labels)
+        .collect::<Vec<_>>();
+    assert_eq!(decoded.len(), counts.len());
+    for &(graphlet_kind, labels, count) in decoded.iter() {
+        let graphlet = [labels.0, labels.1, labels.2, labels.3]
+            .encode_with_graphlet(graphlet_kind, number_of_node_labels);
+        assert_eq!(counts.get_number_of_graphlets(graphlet), count);
+    }
+
+    let four_stars = decoded
+        .iter()
+        .filter(|(graphlet_kind, _, _)| *graphlet_kind == ExtendedGraphletType::FourStar)
+        .map(|(_, _, count)| count)
+        .sum::<u32>();
+    assert_eq!(four_stars, 3);
+}
+
+#[test]
+fn test_get_normalized_report() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/labeled_paths/node_list.csv",
+        "tests/data/labeled_paths/edge_list.csv",
+    )
+    .unwrap();
+    let counts = graph.count_all_graphlets();
+    let number_of_elements = graph.get_number_of_node_labels();
+    let frequencies = counts.get_normalized_report::<_, PolynomialLayout>(number_of_elements);
+    assert_eq!(frequencies.len(), ExtendedGraphletType::COUNT);
+    assert!(
+        (frequencies
+            .iter()
+            .map(|(_, frequency)| frequency)
+            .sum::<f64>()
+            - 1.0)
+            .abs()
+            < 1e-12
+    );
+
+    // The graphlets of the same kind with different labels are summed before normalizing.
+    let kind_totals =
+        CounterWithBase::new(counts.clone(), graph.get_number_of_node_labels()).get_kind_totals();
+    let total = counts.values().sum::<u32>() as f64;
+    for &(graphlet_kind, frequency) in &frequencies {
+        let kind_total = kind_totals.get(&graphlet_kind).copied().unwrap_or(0);
+        assert!((frequency - kind_total as f64 / total).abs() < 1e-12);
+    }
+
+    // The same counts encoded with the sentinel layout have the same frequencies.
+    assert_eq!(
+        to_sentinel_layout(&counts, number_of_elements)
+            .get_normalized_report::<_, SentinelLayout>(number_of_elements),
+        frequencies
+    );
+
+    // The frequencies of an empty counter are all zero.
+    let empty: HashMap<u16, u32> = HashMap::new();
+    assert!(empty
+        .get_normalized_report::<_, PolynomialLayout>(number_of_elements)
+        .into_iter()
+        .all(|(_, frequency)| frequency == 0.0));
+}
+
+#[test]
+fn test_top_k() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/labeled_paths/node_list.csv",
+        "tests/data/labeled_paths/edge_list.csv",
+    )
+    .unwrap();
+    let counts = graph.count_all_graphlets();
+    let number_of_elements = graph.get_number_of_node_labels();
+
+    let top = counts.top_k::<_, PolynomialLayout>(3, number_of_elements);
+    assert_eq!(top.len(), 3.min(counts.len()));
+    assert_eq!(top[0].2, *counts.values().max().unwrap());
+    assert!(top.windows(2).all(|pair| pair[0].2 >= pair[1].2));
+
+    // All the graphlets are returned when k exceeds their number.
+    let all = counts.top_k::<_, PolynomialLayout>(counts.len() + 1, number_of_elements);
+    assert_eq!(all.len(), counts.len());
+    assert_eq!(&all[..top.len()], &top[..]);
+
+    // The ties are broken by the hash, whatever the insertion order.
+    let mut tied: HashMap<u16, u32> = HashMap::new();
+    let mut reversed: HashMap<u16, u32> = HashMap::new();
+    for &graphlet in counts.keys() {
+        tied.insert_count(graphlet, 1);
+    }
+    let mut graphlets = counts.keys().copied().collect::<Vec<_>>();
+    graphlets.sort_unstable();
+    for &graphlet in graphlets.iter().rev() {
+        reversed.insert_count(graphlet, 1);
+    }
+    let expected = graphlets
+        .iter()
+        .map(|&graphlet| {
+            <[u8; 4]>::decode_with_graphlet::<ExtendedGraphletType>(graphlet, number_of_elements).0
+        })
+        .collect::<Vec<_>>();
+    for counter in [&tied, &reversed] {
+        assert_eq!(
+            counter
+                .top_k::<_, PolynomialLayout>(graphlets.len(), number_of_elements)
+                .into_iter()
+                .map(|(graphlet_kind, _, _)| graphlet_kind)
+                .collect::<Vec<_>>(),
+            expected
+        );
+    }
+    assert!(HashMap::<u16, u32>::new()
+        .top_k::<_, PolynomialLayout>(10, number_of_elements)
+        .is_empty());
+}
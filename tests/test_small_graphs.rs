@@ -1,5 +1,19 @@
 mod test_from_csv;
-use test_from_csv::test_from_csv;
+use heterogeneous_graphlets::perfect_graphlet_hash::{
+    decode_edge_typed, encode_edge_typed, max_labels_for_width, maximal_edge_typed_hash,
+    required_graphlet_bits, GraphletHashLayout, NTuplePerfectHash, PolynomialLayout,
+    SentinelLayout,
+};
+use heterogeneous_graphlets::prelude::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use rayon::prelude::*;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::marker::PhantomData;
+use test_from_csv::{
+    assert_isomorphism_invariant, count_graphlets, count_graphlets_dense, test_from_csv,
+    DenseCounterGraph, OffsetLayout, RandomIsomorphism,
+};
 
 #[test]
 fn test_four_path() {
@@ -18,3 +32,3330 @@ fn test_four_star() {
         "tests/data/four_star/edge_list.csv",
     );
 }
+
+#[test]
+fn test_four_clique() {
+    test_from_csv(
+        "Four clique",
+        "tests/data/four_clique/node_list.csv",
+        "tests/data/four_clique/edge_list.csv",
+    );
+}
+
+/// Returns the total count of the four-node graphlets of the provided kind, across all label combinations.
+fn get_graphlet_kind_total(
+    counts: &HashMap<u16, u32>,
+    graphlet_kind: ExtendedGraphletType,
+    number_of_labels: u8,
+) -> u32 {
+    let mut total = 0;
+    for first in 0..number_of_labels {
+        for second in 0..number_of_labels {
+            for third in 0..number_of_labels {
+                for fourth in 0..number_of_labels {
+                    total += counts
+                        .get(&PolynomialLayout::encode(
+                            (first, second, third, fourth),
+                            graphlet_kind,
+                            number_of_labels,
+                        ))
+                        .copied()
+                        .unwrap_or(0);
+                }
+            }
+        }
+    }
+    total
+}
+
+#[test]
+fn test_wheel_four_node_graphlets() {
+    // In a wheel, the hub is adjacent to every rim node, so that when an edge
+    // is processed the second-order neighbourhood iterators of one endpoint run
+    // out well before those of the other, which previously cut the enumeration short.
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let total = |graphlet_kind| {
+        get_graphlet_kind_total(&counts, graphlet_kind, graph.get_number_of_node_labels())
+    };
+
+    assert_eq!(total(ExtendedGraphletType::FourPathEdge), 10);
+    assert_eq!(total(ExtendedGraphletType::FourPathCenter), 5);
+    assert_eq!(total(ExtendedGraphletType::FourStar), 0);
+    assert_eq!(total(ExtendedGraphletType::FourCycle), 0);
+    assert_eq!(total(ExtendedGraphletType::TailedTriTail), 5);
+    assert_eq!(total(ExtendedGraphletType::TailedTriEdge), 10);
+    assert_eq!(total(ExtendedGraphletType::TailedTriCenter), 5);
+    assert_eq!(total(ExtendedGraphletType::ChordalCycleEdge), 20);
+    assert_eq!(total(ExtendedGraphletType::ChordalCycleCenter), 5);
+    assert_eq!(total(ExtendedGraphletType::FourClique), 0);
+}
+
+#[test]
+fn test_label_order_orbits() {
+    // The order of the last two labels in the keys of the four cycles, tailed-triangle
+    // tails, chordal-cycle edges and four cliques depends on the node IDs met by the
+    // traversal, so the orbits derived from them must account for both orders.
+    let graph = CSRGraph::from_csv(
+        "tests/data/label_order_orbits/node_list.csv",
+        "tests/data/label_order_orbits/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let total = |graphlet_kind| {
+        get_graphlet_kind_total(&counts, graphlet_kind, graph.get_number_of_node_labels())
+    };
+
+    assert_eq!(total(ExtendedGraphletType::FourPathCenter), 3);
+    assert_eq!(total(ExtendedGraphletType::FourStar), 0);
+    assert_eq!(total(ExtendedGraphletType::TailedTriEdge), 12);
+    assert_eq!(total(ExtendedGraphletType::ChordalCycleCenter), 2);
+    assert_eq!(total(ExtendedGraphletType::FourPathEdge), 6);
+    assert_eq!(total(ExtendedGraphletType::FourCycle), 8);
+    assert_eq!(total(ExtendedGraphletType::TailedTriTail), 6);
+    assert_eq!(total(ExtendedGraphletType::TailedTriCenter), 6);
+    assert_eq!(total(ExtendedGraphletType::ChordalCycleEdge), 8);
+    assert_eq!(total(ExtendedGraphletType::FourClique), 6);
+}
+
+#[test]
+fn test_has_any_motif_of_kind() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_clique/node_list.csv",
+        "tests/data/four_clique/edge_list.csv",
+    )
+    .unwrap();
+
+    // Every edge of a four-clique is part of a four-clique and of triangles,
+    // and since every induced subgraph is complete, of no other graphlet.
+    for (src, dst) in [(0, 1), (0, 2), (2, 3)] {
+        assert!(graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::FourClique));
+        assert!(graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::Triangle));
+        assert!(!graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::ChordalCycleCenter));
+        assert!(!graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::Triad));
+        assert!(!graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::FourCycle));
+        assert!(!graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::FourPathCenter));
+    }
+
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_path/node_list.csv",
+        "tests/data/four_path/edge_list.csv",
+    )
+    .unwrap();
+
+    assert!(!graph.has_any_motif_of_kind(0, 1, ExtendedGraphletType::FourClique));
+    assert!(graph.has_any_motif_of_kind(0, 1, ExtendedGraphletType::FourPathEdge));
+    assert!(graph.has_any_motif_of_kind(1, 2, ExtendedGraphletType::FourPathCenter));
+}
+
+/// A view of a CSRGraph iterating the neighbours of one of its nodes in reverse order.
+struct ReversedNeighboursGraph<'a>(&'a CSRGraph, usize);
+
+impl<'a> Graph for ReversedNeighboursGraph<'a> {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = std::vec::IntoIter<usize>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.0.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.0.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        let mut neighbours = self.0.iter_neighbours(node).collect::<Vec<_>>();
+        if node == self.1 {
+            neighbours.reverse();
+        }
+        neighbours.into_iter()
+    }
+}
+
+impl<'a> TypedGraph for ReversedNeighboursGraph<'a> {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.0.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.0.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.0.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.0.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.0.get_node_label(node)
+    }
+}
+
+impl<'a> HeterogeneousGraphlets<u16, u32> for ReversedNeighboursGraph<'a> {
+    type GraphLetCounter = HashMap<u16, u32>;
+}
+
+#[test]
+fn test_validate_sorted_neighbours() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(graph.validate_sorted_neighbours(), Ok(()));
+    assert!((0..graph.get_number_of_nodes()).all(|node| graph.has_sorted_neighbours(node)));
+
+    // The first node whose neighbours are reversed is reported.
+    let reversed_graph = ReversedNeighboursGraph(&graph, 2);
+    assert!(!reversed_graph.has_sorted_neighbours(2));
+    assert_eq!(reversed_graph.validate_sorted_neighbours(), Err(2));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "must be strictly increasing")]
+fn test_unsorted_neighbours_are_rejected() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let reversed_graph = ReversedNeighboursGraph(&graph, 0);
+    let neighbour = graph.iter_neighbours(0).next().unwrap();
+    reversed_graph.get_heterogeneous_graphlet(0, neighbour);
+}
+
+#[test]
+fn test_node_degree() {
+    for graph_name in ["wheel", "tailed_four_clique", "four_star"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        // The layout view does not override the degree, and thus counts the neighbours.
+        let view = LayoutGraph::<SentinelLayout>::new(&graph);
+        let (compact_graph, _) = graph.compact_labels();
+        for node in 0..graph.get_number_of_nodes() {
+            assert_eq!(
+                graph.get_node_degree(node),
+                graph.iter_neighbours(node).count()
+            );
+            assert_eq!(view.get_node_degree(node), graph.get_node_degree(node));
+            assert_eq!(
+                compact_graph.get_node_degree(node),
+                graph.get_node_degree(node)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_has_edge() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/tailed_four_clique/node_list.csv",
+        "tests/data/tailed_four_clique/edge_list.csv",
+    )
+    .unwrap();
+    // The layout view does not override the lookup, and thus scans the neighbours.
+    let view = LayoutGraph::<SentinelLayout>::new(&graph);
+    let edges = graph.iter_edges().collect::<Vec<_>>();
+    for src in 0..graph.get_number_of_nodes() {
+        for dst in 0..graph.get_number_of_nodes() {
+            assert_eq!(graph.has_edge(src, dst), edges.contains(&(src, dst)));
+            assert_eq!(view.has_edge(src, dst), graph.has_edge(src, dst));
+        }
+    }
+}
+
+#[test]
+fn test_single_node_label() {
+    for graph_name in ["triangle_and_path", "tailed_four_clique", "wheel"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let unlabelled_graph = CSRGraph::from_edges(
+            vec![0; graph.get_number_of_nodes()],
+            graph.iter_edges().collect(),
+        )
+        .unwrap();
+        assert_eq!(unlabelled_graph.get_number_of_node_labels(), 1);
+
+        // The sentinel layout never carries the dummy label over into the graphlet kind.
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        let mut expected: HashMap<ExtendedGraphletType, u32> = HashMap::new();
+        for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+            for (graphlet, count) in sentinel_graph.get_heterogeneous_graphlet(src, dst) {
+                *expected
+                    .entry(SentinelLayout::decode_graphlet_kind(
+                        graphlet,
+                        graph.get_number_of_node_labels(),
+                    ))
+                    .or_default() += count;
+            }
+        }
+
+        // With a single label, each graphlet kind has a single key, which would
+        // collide with the following kind if the dummy label carried over.
+        let counts = count_graphlets(&unlabelled_graph);
+        assert_eq!(
+            counts
+                .iter()
+                .map(|(&graphlet, &count)| (
+                    <[u8; 4]>::decode_graphlet_kind::<ExtendedGraphletType>(graphlet, 1),
+                    count
+                ))
+                .collect::<HashMap<ExtendedGraphletType, u32>>(),
+            expected,
+            "{}",
+            graph_name
+        );
+        assert!(counts.keys().all(|&graphlet| {
+            graphlet
+                <= <[u8; 4] as NTuplePerfectHash<4, u16, u8>>::maximal_hash::<ExtendedGraphletType>(
+                    1,
+                )
+        }));
+        assert_eq!(
+            count_graphlets_dense(&unlabelled_graph)
+                .iter_graphlets_and_counts()
+                .collect::<HashMap<u16, u32>>(),
+            counts
+        );
+    }
+}
+
+#[test]
+fn test_polynomial_layout_with_two_labels() {
+    // The dummy label of the three-node graphlets is a digit of the polynomial
+    // hashes, so that with more than one label it does not carry over into the
+    // labels, and the graphlets of a triangle and of a path do not collide.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let decoded = count_graphlets(&graph)
+        .into_iter()
+        .map(|(graphlet, count)| (PolynomialLayout::decode(graphlet, 2), count))
+        .collect::<HashMap<(ExtendedGraphletType, (u8, u8, u8, u8)), u32>>();
+    assert_eq!(
+        decoded,
+        HashMap::from([
+            ((ExtendedGraphletType::Triangle, (1, 1, 1, 2)), 3),
+            ((ExtendedGraphletType::Triad, (0, 0, 0, 2)), 4),
+            ((ExtendedGraphletType::FourPathEdge, (0, 0, 0, 0)), 2),
+            ((ExtendedGraphletType::FourPathCenter, (0, 0, 0, 0)), 1),
+        ])
+    );
+
+    // A triangle with two labels.
+    let triangle = CSRGraph::from_edges(
+        vec![0, 1, 1],
+        vec![(0, 1), (1, 0), (0, 2), (2, 0), (1, 2), (2, 1)],
+    )
+    .unwrap();
+    let decoded = count_graphlets(&triangle)
+        .into_iter()
+        .map(|(graphlet, count)| (PolynomialLayout::decode(graphlet, 2), count))
+        .collect::<HashMap<(ExtendedGraphletType, (u8, u8, u8, u8)), u32>>();
+    assert_eq!(
+        decoded,
+        HashMap::from([
+            ((ExtendedGraphletType::Triangle, (0, 1, 1, 2)), 2),
+            ((ExtendedGraphletType::Triangle, (1, 1, 0, 2)), 1),
+        ])
+    );
+}
+
+#[test]
+fn test_edge_features_with_node_attrs() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let node_features = (0..graph.get_number_of_nodes())
+        .map(|node| vec![node as f32, 1.0, -(node as f32)])
+        .collect::<Vec<_>>();
+
+    for (src, dst) in graph.iter_edges() {
+        let signature = graph.get_orbit_signature(src, dst);
+        let features = graph.edge_features_with_node_attrs(src, dst, &node_features);
+
+        assert_eq!(features.len(), signature.len() + 2 * 3);
+        assert_eq!(
+            &features[..signature.len()],
+            signature
+                .iter()
+                .map(|&count| count as f32)
+                .collect::<Vec<_>>()
+                .as_slice()
+        );
+        assert_eq!(&features[signature.len()..][..3], &node_features[src][..]);
+        assert_eq!(&features[signature.len() + 3..], &node_features[dst][..]);
+    }
+}
+
+/// A layout storing the node labels first and the graphlet kind last.
+struct LabelsFirstLayout;
+
+impl GraphletHashLayout<u16, u8> for LabelsFirstLayout {
+    fn encode<GraphletKind: GraphletSet<u16> + From<u16>>(
+        labels: (u8, u8, u8, u8),
+        graphlet_kind: GraphletKind,
+        number_of_elements: u8,
+    ) -> u16
+    where
+        u16: From<GraphletKind>,
+    {
+        // The labels are encoded in base `number_of_elements + 1`, so that
+        // the dummy label of the three-node graphlets does not carry over.
+        let base = number_of_elements as u16 + 1;
+        let labels_index = ((labels.0 as u16 * base + labels.1 as u16) * base + labels.2 as u16)
+            * base
+            + labels.3 as u16;
+        labels_index * GraphletKind::get_number_of_graphlets() + u16::from(graphlet_kind)
+    }
+
+    fn decode<GraphletKind: GraphletSet<u16> + From<u16>>(
+        encoded: u16,
+        number_of_elements: u8,
+    ) -> (GraphletKind, (u8, u8, u8, u8))
+    where
+        u16: From<GraphletKind>,
+    {
+        let base = number_of_elements as u16 + 1;
+        let number_of_graphlets = GraphletKind::get_number_of_graphlets();
+        let graphlet_kind = GraphletKind::from(encoded % number_of_graphlets);
+        let labels_index = encoded / number_of_graphlets;
+        (
+            graphlet_kind,
+            (
+                (labels_index / (base * base * base)) as u8,
+                (labels_index / (base * base) % base) as u8,
+                (labels_index / base % base) as u8,
+                (labels_index % base) as u8,
+            ),
+        )
+    }
+
+    fn maximal_hash<GraphletKind: GraphletSet<u16> + From<u16>>(number_of_elements: u8) -> u16 {
+        let base = number_of_elements as u16 + 1;
+        base * base * base * base * GraphletKind::get_number_of_graphlets() - 1
+    }
+}
+
+/// A view of a CSR graph counting its graphlets with the provided layout.
+struct LayoutGraph<'a, Layout>(&'a CSRGraph, PhantomData<Layout>);
+
+impl<'a, Layout> LayoutGraph<'a, Layout> {
+    fn new(graph: &'a CSRGraph) -> Self {
+        Self(graph, PhantomData)
+    }
+}
+
+impl<'a, Layout> Graph for LayoutGraph<'a, Layout> {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.0.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.0.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.0.iter_neighbours(node)
+    }
+}
+
+impl<'a, Layout> TypedGraph for LayoutGraph<'a, Layout> {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.0.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.0.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.0.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.0.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.0.get_node_label(node)
+    }
+}
+
+impl<'a> HeterogeneousGraphlets<u16, u32, LabelsFirstLayout>
+    for LayoutGraph<'a, LabelsFirstLayout>
+{
+    type GraphLetCounter = HashMap<u16, u32>;
+}
+
+impl<'a> HeterogeneousGraphlets<u64, u32, SentinelLayout> for LayoutGraph<'a, SentinelLayout> {
+    type GraphLetCounter = HashMap<u64, u32>;
+}
+
+#[test]
+fn test_labels_first_layout() {
+    // The alternative layout round-trips every graphlet kind and label quadruple.
+    let number_of_elements = 3;
+    for graphlet_kind in 0..12_u8 {
+        let graphlet_kind = ExtendedGraphletType::from(graphlet_kind);
+        for labels in (0..=number_of_elements).flat_map(|l0| {
+            (0..=number_of_elements).flat_map(move |l1| {
+                (0..=number_of_elements)
+                    .flat_map(move |l2| (0..=number_of_elements).map(move |l3| (l0, l1, l2, l3)))
+            })
+        }) {
+            let encoded = LabelsFirstLayout::encode(labels, graphlet_kind, number_of_elements);
+            assert!(
+                encoded
+                    <= LabelsFirstLayout::maximal_hash::<ExtendedGraphletType>(number_of_elements)
+            );
+            assert_eq!(
+                LabelsFirstLayout::decode::<ExtendedGraphletType>(encoded, number_of_elements),
+                (graphlet_kind, labels)
+            );
+        }
+    }
+
+    // Counting with the alternative layout identifies the same distinct motifs.
+    for (node_list, edge_list) in [
+        (
+            "tests/data/four_path/node_list.csv",
+            "tests/data/four_path/edge_list.csv",
+        ),
+        (
+            "tests/data/four_star/node_list.csv",
+            "tests/data/four_star/edge_list.csv",
+        ),
+        (
+            "tests/data/four_clique/node_list.csv",
+            "tests/data/four_clique/edge_list.csv",
+        ),
+    ] {
+        let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+        let labels_first_graph = LayoutGraph::<LabelsFirstLayout>::new(&graph);
+        let number_of_node_labels = graph.get_number_of_node_labels();
+
+        let mut reencoded_counts: HashMap<u16, u32> = HashMap::new();
+        for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+            for (graphlet, count) in labels_first_graph.get_heterogeneous_graphlet(src, dst) {
+                let (graphlet_kind, labels) = LabelsFirstLayout::decode::<ExtendedGraphletType>(
+                    graphlet,
+                    number_of_node_labels,
+                );
+                reencoded_counts.insert_count(
+                    PolynomialLayout::encode(labels, graphlet_kind, number_of_node_labels),
+                    count,
+                );
+            }
+        }
+
+        assert_eq!(reencoded_counts, count_graphlets(&graph), "{}", node_list);
+    }
+}
+
+/// A reduced graphlet kind paired with its label quadruple.
+type ReducedGraphlet = (ReducedGraphletType, (u8, u8, u8, u8));
+
+#[test]
+fn test_reduced_graphlets() {
+    assert_eq!(
+        ExtendedGraphletType::ChordalCycleEdge.to_reduced(),
+        ReducedGraphletType::ChordalCycle
+    );
+    assert_eq!(
+        ExtendedGraphletType::TailedTriTail.to_reduced(),
+        ReducedGraphletType::TailedTri
+    );
+    assert_eq!(
+        ExtendedGraphletType::FourPathCenter.to_reduced(),
+        ReducedGraphletType::FourPath
+    );
+
+    for graph_name in ["clique_and_diamond", "wheel", "tailed_four_clique"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        let number_of_node_labels = graph.get_number_of_node_labels();
+
+        for (src, dst) in graph.iter_edges() {
+            let mut expected: HashMap<ReducedGraphlet, u32> = HashMap::new();
+            for (graphlet, count) in sentinel_graph.get_heterogeneous_graphlet(src, dst) {
+                let (graphlet_kind, labels) =
+                    SentinelLayout::decode::<ExtendedGraphletType>(graphlet, number_of_node_labels);
+                *expected
+                    .entry((graphlet_kind.to_reduced(), labels))
+                    .or_default() += count;
+            }
+
+            let reduced = sentinel_graph
+                .get_heterogeneous_graphlet_reduced(src, dst)
+                .into_iter()
+                .map(|(graphlet, count)| {
+                    (
+                        SentinelLayout::decode::<ReducedGraphletType>(
+                            graphlet,
+                            number_of_node_labels,
+                        ),
+                        count,
+                    )
+                })
+                .collect::<HashMap<_, _>>();
+
+            assert_eq!(
+                reduced, expected,
+                "The reduced graphlets of the edge ({}, {}) of the {} graph differ.",
+                src, dst, graph_name
+            );
+        }
+    }
+}
+
+#[test]
+fn test_fold_to_reduced() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/clique_and_diamond/node_list.csv",
+        "tests/data/clique_and_diamond/edge_list.csv",
+    )
+    .unwrap();
+    let number_of_node_labels = graph.get_number_of_node_labels();
+    let counts = count_graphlets(&graph);
+    let reduced = counts.fold_to_reduced::<_, PolynomialLayout>(number_of_node_labels);
+    assert!(reduced.len() <= counts.len());
+
+    let mut extended_totals: HashMap<ReducedGraphletType, u32> = HashMap::new();
+    for (graphlet_kind, _, count) in
+        counts.iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_node_labels)
+    {
+        *extended_totals
+            .entry(graphlet_kind.to_reduced())
+            .or_default() += count;
+    }
+    let mut reduced_totals: HashMap<ReducedGraphletType, u32> = HashMap::new();
+    for (graphlet_kind, _, count) in
+        reduced.iter_decoded::<ReducedGraphletType, u8, PolynomialLayout>(number_of_node_labels)
+    {
+        *reduced_totals.entry(graphlet_kind).or_default() += count;
+    }
+
+    assert!(reduced_totals[&ReducedGraphletType::Triangle] > 0);
+    assert_eq!(
+        reduced_totals[&ReducedGraphletType::Triangle],
+        extended_totals[&ReducedGraphletType::Triangle]
+    );
+    assert_eq!(reduced_totals, extended_totals);
+    assert_eq!(reduced.values().sum::<u32>(), counts.values().sum::<u32>());
+
+    // The counts of the sentinel layout fold onto the same reduced graphlets,
+    // once their dummy labels are dropped.
+    let sentinel_reduced = LayoutGraph::<SentinelLayout>::new(&graph)
+        .count_all_graphlets()
+        .fold_to_reduced::<_, SentinelLayout>(number_of_node_labels);
+    let without_dummy_label = |(graphlet_kind, (first, second, third, fourth), count): (
+        ReducedGraphletType,
+        (u8, u8, u8, u8),
+        u32,
+    )| {
+        let fourth = (graphlet_kind.node_count() == 4).then_some(fourth);
+        ((graphlet_kind, (first, second, third, fourth)), count)
+    };
+    assert_eq!(
+        sentinel_reduced
+            .iter_decoded::<ReducedGraphletType, u8, SentinelLayout>(number_of_node_labels)
+            .map(without_dummy_label)
+            .collect::<HashMap<_, _>>(),
+        reduced
+            .iter_decoded::<ReducedGraphletType, u8, PolynomialLayout>(number_of_node_labels)
+            .map(without_dummy_label)
+            .collect::<HashMap<_, _>>()
+    );
+}
+
+#[test]
+fn test_masked_graphlets() {
+    let mask = [
+        ExtendedGraphletType::FourClique,
+        ExtendedGraphletType::FourCycle,
+    ]
+    .into_iter()
+    .collect::<GraphletMask>();
+    assert!(mask.contains(ExtendedGraphletType::FourClique));
+    assert!(!mask.contains(ExtendedGraphletType::Triangle));
+    assert_eq!(mask.iter().count(), 2);
+    assert_eq!(
+        mask.without(ExtendedGraphletType::FourCycle),
+        GraphletMask::from(ExtendedGraphletType::FourClique)
+    );
+    assert!(GraphletMask::empty().is_empty());
+    assert_eq!(
+        GraphletMask::all().iter().collect::<Vec<_>>(),
+        ExtendedGraphletType::all().collect::<Vec<_>>()
+    );
+    assert_eq!(
+        GraphletMask::from(ExtendedGraphletType::FourStar).with_dependencies(),
+        GraphletMask::from(ExtendedGraphletType::FourStar)
+            .with(ExtendedGraphletType::TailedTriTail)
+    );
+
+    // Besides each single graphlet type, we check the derived orbits without the
+    // orbits they depend on, and the two traversals without the derived orbits.
+    let masks = ExtendedGraphletType::all()
+        .map(GraphletMask::from)
+        .chain([
+            mask,
+            GraphletMask::all()
+                .without(ExtendedGraphletType::FourCycle)
+                .without(ExtendedGraphletType::FourClique),
+            GraphletMask::empty(),
+        ])
+        .collect::<Vec<_>>();
+
+    for graph_name in ["clique_and_diamond", "wheel", "tailed_four_clique"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        let number_of_node_labels = graph.get_number_of_node_labels();
+
+        for (src, dst) in graph.iter_edges() {
+            let counts = sentinel_graph.get_heterogeneous_graphlet(src, dst);
+            assert_eq!(
+                sentinel_graph.get_heterogeneous_graphlet_masked(src, dst, GraphletMask::all()),
+                counts
+            );
+            for &mask in masks.iter() {
+                let expected = counts
+                    .iter()
+                    .filter(|(&graphlet, _)| {
+                        mask.contains(
+                            SentinelLayout::decode_graphlet_kind::<ExtendedGraphletType>(
+                                graphlet,
+                                number_of_node_labels,
+                            ),
+                        )
+                    })
+                    .map(|(&graphlet, &count)| (graphlet, count))
+                    .collect::<HashMap<u64, u32>>();
+                assert_eq!(
+                    sentinel_graph.get_heterogeneous_graphlet_masked(src, dst, mask),
+                    expected,
+                    "Mismatch for the edge ({}, {}) of {} with the mask {:?}.",
+                    src,
+                    dst,
+                    graph_name,
+                    mask
+                );
+            }
+        }
+    }
+}
+
+/// Returns the five-node graphlet kind of the provided edge within the induced subgraph
+/// of the provided nodes, by brute force on the degrees of the subgraph.
+fn classify_five_nodes(
+    graph: &CSRGraph,
+    nodes: [usize; 5],
+    src: usize,
+    dst: usize,
+) -> Option<FiveNodeGraphletType> {
+    let degree = |node: usize| {
+        nodes
+            .iter()
+            .filter(|&&other| graph.has_edge(node, other))
+            .count()
+    };
+    let mut degrees = nodes.map(degree);
+    let number_of_edges = degrees.iter().sum::<usize>() / 2;
+    degrees.sort_unstable();
+    match (number_of_edges, degrees) {
+        (10, _) => Some(FiveNodeGraphletType::FiveClique),
+        (5, [2, 2, 2, 2, 2]) => Some(FiveNodeGraphletType::FiveCycle),
+        (4, [1, 1, 1, 1, 4]) => Some(FiveNodeGraphletType::FiveStar),
+        // A triangle and a disjoint edge have the same degrees of a five-path.
+        (4, [1, 1, 2, 2, 2]) if graph.has_edge(src, dst) => {
+            let mut reached = vec![src];
+            while let Some(node) = nodes.iter().copied().find(|&node| {
+                !reached.contains(&node) && reached.iter().any(|&other| graph.has_edge(node, other))
+            }) {
+                reached.push(node);
+            }
+            (reached.len() == 5).then(|| {
+                if degree(src).min(degree(dst)) == 1 {
+                    FiveNodeGraphletType::FivePathEdge
+                } else {
+                    FiveNodeGraphletType::FivePathInner
+                }
+            })
+        }
+        _ => None,
+    }
+}
+
+#[test]
+fn test_five_node_graphlets() {
+    let number_of_elements = 3_u8;
+    for graphlet_kind in FiveNodeGraphletType::all() {
+        let labels = [2, 0, 1, 2, 1];
+        let encoded: u16 = labels.encode_with_graphlet(graphlet_kind, number_of_elements);
+        let partially_encoded: u16 = labels.encode_partially(number_of_elements);
+        // The base is one more than the number of elements.
+        assert_eq!(
+            encoded,
+            u16::from(graphlet_kind) * 4_u16.pow(5) + partially_encoded
+        );
+        assert_eq!(
+            <[u8; 5]>::decode_with_graphlet::<FiveNodeGraphletType>(encoded, number_of_elements),
+            (graphlet_kind, labels)
+        );
+    }
+
+    let random_graph = RandomGraph::new(0x5EED, 14, 3, 3);
+    let random_graph = CSRGraph::from_edges(
+        (0..random_graph.get_number_of_nodes())
+            .map(|node| random_graph.get_node_label(node))
+            .collect(),
+        random_graph.iter_edges().collect(),
+    )
+    .unwrap();
+    let graphs = [
+        "wheel",
+        "six_clique",
+        "five_clique_minus_edge",
+        "tailed_four_clique",
+    ]
+    .into_iter()
+    .map(|graph_name| {
+        CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap()
+    })
+    .chain([random_graph]);
+
+    let mut kinds_found = HashSet::new();
+    for graph in graphs {
+        let number_of_elements = graph.get_number_of_node_labels();
+        for (src, dst) in graph.iter_edges() {
+            // We count the graphlets by kind and sorted further labels, as the order of
+            // the further labels depends on the kind.
+            let mut counts: HashMap<(FiveNodeGraphletType, [u8; 3]), u32> = HashMap::new();
+            for (graphlet, count) in
+                HeterogeneousGraphlets::<u16, u32>::get_heterogeneous_5_graphlet(&graph, src, dst)
+            {
+                let (graphlet_kind, [_, _, first, second, third]) = <[u8; 5]>::decode_with_graphlet::<
+                    FiveNodeGraphletType,
+                >(
+                    graphlet, number_of_elements
+                );
+                let mut labels = [first, second, third];
+                labels.sort_unstable();
+                *counts.entry((graphlet_kind, labels)).or_default() += count;
+            }
+
+            let mut expected: HashMap<(FiveNodeGraphletType, [u8; 3]), u32> = HashMap::new();
+            let others = (0..graph.get_number_of_nodes())
+                .filter(|&node| node != src && node != dst)
+                .collect::<Vec<_>>();
+            for (i, &first) in others.iter().enumerate() {
+                for (j, &second) in others.iter().enumerate().skip(i + 1) {
+                    for &third in others.iter().skip(j + 1) {
+                        let nodes = [src, dst, first, second, third];
+                        if let Some(graphlet_kind) = classify_five_nodes(&graph, nodes, src, dst) {
+                            let mut labels =
+                                [first, second, third].map(|node| graph.get_node_label(node));
+                            labels.sort_unstable();
+                            *expected.entry((graphlet_kind, labels)).or_default() += 1;
+                        }
+                    }
+                }
+            }
+            kinds_found.extend(expected.keys().map(|&(graphlet_kind, _)| graphlet_kind));
+            assert_eq!(
+                counts, expected,
+                "Mismatch for the edge ({}, {}).",
+                src, dst
+            );
+        }
+    }
+    assert_eq!(kinds_found.len(), FiveNodeGraphletType::COUNT);
+}
+
+#[test]
+fn test_sentinel_layout() {
+    // The same triangle motif is encoded identically regardless of the number of labels.
+    let encode_triangle = |number_of_elements: u8| -> u64 {
+        SentinelLayout::encode(
+            (
+                0,
+                1,
+                2,
+                <SentinelLayout as GraphletHashLayout<u64, u8>>::dummy_label(number_of_elements),
+            ),
+            ExtendedGraphletType::Triangle,
+            number_of_elements,
+        )
+    };
+    assert_eq!(encode_triangle(3), encode_triangle(5));
+    assert_eq!(
+        SentinelLayout::decode::<ExtendedGraphletType>(encode_triangle(3), 5),
+        (ExtendedGraphletType::Triangle, (0, 1, 2, u8::MAX))
+    );
+
+    // The counting routine fills the dummy position with the sentinel, so the
+    // counts of the triangle edge match across graphs with 3 and 5 labels.
+    let three_labels_graph = CSRGraph::from_csv(
+        "tests/data/triangle/node_list.csv",
+        "tests/data/triangle/edge_list.csv",
+    )
+    .unwrap();
+    let five_labels_graph = CSRGraph::from_csv(
+        "tests/data/triangle_five_labels/node_list.csv",
+        "tests/data/triangle_five_labels/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(three_labels_graph.get_number_of_node_labels(), 3);
+    assert_eq!(five_labels_graph.get_number_of_node_labels(), 5);
+
+    let three_labels_counts =
+        LayoutGraph::<SentinelLayout>::new(&three_labels_graph).get_heterogeneous_graphlet(0, 1);
+    let five_labels_counts =
+        LayoutGraph::<SentinelLayout>::new(&five_labels_graph).get_heterogeneous_graphlet(0, 1);
+    assert_eq!(three_labels_counts, five_labels_counts);
+    assert_eq!(
+        three_labels_counts,
+        HashMap::from([(encode_triangle(3), 1)])
+    );
+
+    // Under the polynomial layout, the same triangle is instead encoded differently.
+    assert_ne!(
+        three_labels_graph.get_heterogeneous_graphlet(0, 1),
+        five_labels_graph.get_heterogeneous_graphlet(0, 1)
+    );
+}
+
+#[test]
+fn test_iter_graphlets_by_source() {
+    for (node_list, edge_list) in [
+        (
+            "tests/data/four_path/node_list.csv",
+            "tests/data/four_path/edge_list.csv",
+        ),
+        (
+            "tests/data/four_star/node_list.csv",
+            "tests/data/four_star/edge_list.csv",
+        ),
+        (
+            "tests/data/six_clique/node_list.csv",
+            "tests/data/six_clique/edge_list.csv",
+        ),
+    ] {
+        let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+
+        let mut summed_counts: HashMap<u16, u32> = HashMap::new();
+        let mut number_of_sources = 0;
+        for (expected_src, (src, graphlet_counter)) in graph.iter_graphlets_by_source().enumerate()
+        {
+            assert_eq!(src, expected_src);
+            number_of_sources += 1;
+            for (graphlet, count) in graphlet_counter {
+                summed_counts.insert_count(graphlet, count);
+            }
+        }
+
+        assert_eq!(number_of_sources, graph.get_number_of_nodes());
+        assert_eq!(summed_counts, count_graphlets(&graph), "{}", node_list);
+    }
+}
+
+#[test]
+fn test_recompute_node_motifs() {
+    // We detach the first node of the path of the triangle and path, rewire the last
+    // node of the path to the triangle, and rewrite whole neighbourhoods of the others.
+    for (graph_name, node, new_neighbours) in [
+        ("triangle_and_path", 3, vec![]),
+        ("triangle_and_path", 6, vec![2]),
+        ("wheel", 0, vec![1]),
+        ("wheel", 3, vec![0, 5]),
+        ("labeled_paths", 5, vec![0, 2, 9]),
+        ("clique_and_diamond", 0, vec![4, 5, 6, 7]),
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let node_labels = (0..graph.get_number_of_nodes())
+            .map(|node| (node, graph.get_node_label(node) as usize))
+            .collect::<HashMap<usize, usize>>();
+        let mut adjacency = (0..graph.get_number_of_nodes())
+            .map(|node| (node, graph.iter_neighbours(node).collect()))
+            .collect::<HashMap<usize, Vec<usize>>>();
+        let old_neighbours = adjacency[&node].clone();
+
+        for neighbours in adjacency.values_mut() {
+            neighbours.retain(|&neighbour| neighbour != node);
+        }
+        for &new_neighbour in new_neighbours.iter() {
+            adjacency.get_mut(&new_neighbour).unwrap().push(node);
+        }
+        adjacency.insert(node, new_neighbours.clone());
+        let new_graph = HashMapGraph::new(&adjacency, &node_labels).unwrap();
+
+        // The previous graph is seen from the new one through the rewired view.
+        let old_graph = RewiredGraph::new(&new_graph, node, &old_neighbours);
+        for other in 0..graph.get_number_of_nodes() {
+            assert_eq!(
+                old_graph.iter_neighbours(other).collect::<Vec<_>>(),
+                graph.iter_neighbours(other).collect::<Vec<_>>(),
+                "{} {}",
+                graph_name,
+                other
+            );
+            assert_eq!(
+                old_graph.get_node_degree(other),
+                graph.get_node_degree(other)
+            );
+        }
+
+        let mut graphlet_counter = count_graphlets(&graph);
+        HeterogeneousGraphlets::<u16, u32>::recompute_node_motifs(
+            &new_graph,
+            node,
+            &old_neighbours,
+            &mut graphlet_counter,
+        );
+        assert_eq!(
+            graphlet_counter,
+            HeterogeneousGraphlets::<u16, u32>::count_all_graphlets(&new_graph),
+            "{} {}",
+            graph_name,
+            node
+        );
+    }
+}
+
+#[test]
+fn test_motif_weighted_adjacency() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/tailed_four_clique/node_list.csv",
+        "tests/data/tailed_four_clique/edge_list.csv",
+    )
+    .unwrap();
+
+    let (offsets, edges, weights) =
+        graph.motif_weighted_adjacency(ExtendedGraphletType::FourClique);
+
+    assert_eq!(offsets.len(), graph.get_number_of_nodes() + 1);
+    assert_eq!(edges.len(), graph.get_number_of_edges());
+    assert_eq!(weights.len(), graph.get_number_of_edges());
+    for src in 0..graph.get_number_of_nodes() {
+        assert!(edges[offsets[src]..offsets[src + 1]]
+            .iter()
+            .copied()
+            .eq(graph.iter_neighbours(src)));
+        for (&dst, &weight) in edges[offsets[src]..offsets[src + 1]]
+            .iter()
+            .zip(&weights[offsets[src]..offsets[src + 1]])
+        {
+            // The edges of the four-clique are included in exactly one four-clique,
+            // while the tail edge is not included in any.
+            assert_eq!(weight, u32::from(src < 4 && dst < 4), "{} {}", src, dst);
+        }
+    }
+}
+
+#[test]
+fn test_four_clique_label_breakdown() {
+    // The four-clique has labels (0, 0, 1, 1), and the chordal cycle has no four-clique.
+    let graph = CSRGraph::from_csv(
+        "tests/data/clique_and_diamond/node_list.csv",
+        "tests/data/clique_and_diamond/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(
+        graph.four_clique_label_breakdown(),
+        HashMap::from([([0, 0, 1, 1], 1)])
+    );
+
+    // The six-clique has two nodes for each of the labels 0, 1 and 2.
+    let graph = CSRGraph::from_csv(
+        "tests/data/six_clique/node_list.csv",
+        "tests/data/six_clique/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(
+        graph.four_clique_label_breakdown(),
+        HashMap::from([
+            ([0, 0, 1, 1], 1),
+            ([0, 0, 2, 2], 1),
+            ([1, 1, 2, 2], 1),
+            ([0, 0, 1, 2], 4),
+            ([0, 1, 1, 2], 4),
+            ([0, 1, 2, 2], 4),
+        ])
+    );
+}
+
+#[test]
+fn test_edges_in_labeled_motif() {
+    // Two author-paper-author-venue four-paths, with authors of label 0, papers
+    // of label 1 and venues of label 2, and an author-paper-venue three-path.
+    let graph = CSRGraph::from_csv(
+        "tests/data/labeled_paths/node_list.csv",
+        "tests/data/labeled_paths/edge_list.csv",
+    )
+    .unwrap();
+
+    // The edges between an author and a venue at the end of a four-path
+    // whose other nodes are a paper and an author.
+    let edges = graph.edges_in_labeled_motif(ExtendedGraphletType::FourPathEdge, [0, 2, 0, 1]);
+    assert_eq!(edges, vec![(2, 3), (6, 7)]);
+    for (src, dst) in edges {
+        assert!(graph.has_any_motif_of_kind(src, dst, ExtendedGraphletType::FourPathEdge));
+        assert_eq!(
+            (graph.get_node_label(src), graph.get_node_label(dst)),
+            (0, 2)
+        );
+    }
+
+    // The fourth label of the three-node graphlets is ignored.
+    assert_eq!(
+        graph.edges_in_labeled_motif(ExtendedGraphletType::Triad, [1, 0, 2, 0]),
+        vec![(1, 2), (5, 6)]
+    );
+    assert_eq!(
+        graph.edges_in_labeled_motif(ExtendedGraphletType::Triad, [0, 1, 2, 2]),
+        vec![(8, 9)]
+    );
+    assert!(graph
+        .edges_in_labeled_motif(ExtendedGraphletType::Triangle, [0, 1, 2, 0])
+        .is_empty());
+}
+
+#[test]
+fn test_kind_cooccurrence_matrix() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/five_clique_minus_edge/node_list.csv",
+        "tests/data/five_clique_minus_edge/edge_list.csv",
+    )
+    .unwrap();
+
+    // We use the sentinel layout, as the graph has a single node label.
+    let matrix = LayoutGraph::<SentinelLayout>::new(&graph).kind_cooccurrence_matrix();
+    let four_clique = u8::from(ExtendedGraphletType::FourClique) as usize;
+    let chordal_cycle_center = u8::from(ExtendedGraphletType::ChordalCycleCenter) as usize;
+
+    for (first, row) in matrix.iter().enumerate() {
+        for (second, &cooccurrences) in row.iter().enumerate() {
+            assert_eq!(cooccurrences, matrix[second][first]);
+            assert!(cooccurrences <= row[first]);
+        }
+    }
+
+    // Removing an edge from a five-clique leaves two four-cliques sharing a triangle,
+    // so that every edge is in a four-clique, and the edges of the shared triangle
+    // are also the chords of the chordal cycles spanning both four-cliques.
+    assert_eq!(matrix[four_clique][four_clique], 9);
+    assert_eq!(matrix[four_clique][chordal_cycle_center], 3);
+}
+
+#[test]
+fn test_iter_graphlets_and_counts_ref() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+
+    let mut owned = counts.iter_graphlets_and_counts().collect::<Vec<_>>();
+    let mut borrowed = counts
+        .iter_graphlets_and_counts_ref()
+        .map(|(&graphlet, &count)| (graphlet, count))
+        .collect::<Vec<_>>();
+    owned.sort_unstable();
+    borrowed.sort_unstable();
+
+    assert!(!owned.is_empty());
+    assert_eq!(owned, borrowed);
+}
+
+#[test]
+fn test_counts_approx_equal() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+    let counts = count_graphlets(&graph);
+    let total = counts.values().sum::<u32>() as f64;
+
+    // The frequencies of the graphlets, and a copy perturbed by a small amount.
+    let mut frequencies: HashMap<u16, f64> = HashMap::new();
+    let mut perturbed_frequencies: HashMap<u16, f64> = HashMap::new();
+    for (graphlet, count) in counts.iter_graphlets_and_counts() {
+        frequencies.insert_count(graphlet, count as f64 / total);
+        perturbed_frequencies.insert_count(graphlet, count as f64 / total + 1e-9);
+    }
+
+    assert!(frequencies.counts_approx_equal(&perturbed_frequencies, 1e-6));
+    assert!(perturbed_frequencies.counts_approx_equal(&frequencies, 1e-6));
+    assert!(!frequencies.counts_approx_equal(&perturbed_frequencies, 1e-12));
+
+    // A missing graphlet is treated as having a zero count.
+    perturbed_frequencies.insert_count(u16::MAX, 1e-9);
+    assert!(frequencies.counts_approx_equal(&perturbed_frequencies, 1e-6));
+    perturbed_frequencies.insert_count(u16::MAX, 1e-3);
+    assert!(!frequencies.counts_approx_equal(&perturbed_frequencies, 1e-6));
+    assert!(!perturbed_frequencies.counts_approx_equal(&frequencies, 1e-6));
+
+    // With integer counts and no tolerance, the comparison is an exact equality.
+    assert!(counts.counts_approx_equal(
+        &count_graphlets_dense(&graph)
+            .iter_graphlets_and_counts()
+            .collect(),
+        0.0
+    ));
+}
+
+#[test]
+fn test_group_edges_by_signature() {
+    // A wheel with a hub of label 1 and a rim of five nodes of label 0.
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+
+    let mut groups = graph
+        .group_edges_by_signature()
+        .into_values()
+        .map(|mut edges| {
+            edges.sort_unstable();
+            edges
+        })
+        .collect::<Vec<_>>();
+    groups.sort_unstable();
+
+    // The rotations of the wheel map the spokes onto each other, and the rim edges onto each other.
+    assert_eq!(
+        groups,
+        vec![
+            vec![(0, 1), (0, 2), (0, 3), (0, 4), (0, 5)],
+            vec![(1, 2), (1, 5), (2, 3), (3, 4), (4, 5)],
+        ]
+    );
+}
+
+#[test]
+fn test_edge_signature_diff() {
+    // A four-clique and a chordal cycle with the same labels, where the chord
+    // of the chordal cycle corresponds to an edge of the four-clique.
+    let graph = CSRGraph::from_csv(
+        "tests/data/clique_and_diamond/node_list.csv",
+        "tests/data/clique_and_diamond/edge_list.csv",
+    )
+    .unwrap();
+
+    // Closing the chordal cycle yields the four-clique, so the two edges differ
+    // solely by a four-clique replacing a chordal cycle center.
+    let diff = graph.edge_signature_diff((0, 1), (4, 5), graph.get_number_of_node_labels());
+    assert_eq!(diff.len(), 2, "{:?}", diff);
+    for (graphlet_kind, mut labels, difference) in diff {
+        labels.sort_unstable();
+        assert_eq!(labels, [0, 0, 1, 1]);
+        match graphlet_kind {
+            ExtendedGraphletType::FourClique => assert_eq!(difference, 1),
+            ExtendedGraphletType::ChordalCycleCenter => assert_eq!(difference, -1),
+            graphlet_kind => panic!("Unexpected graphlet kind {:?}", graphlet_kind),
+        }
+    }
+
+    // The differences are antisymmetric, and an edge does not differ from itself.
+    let reverse_diff = graph.edge_signature_diff((4, 5), (0, 1), graph.get_number_of_node_labels());
+    assert!(reverse_diff
+        .iter()
+        .all(|&(graphlet_kind, _, difference)| match graphlet_kind {
+            ExtendedGraphletType::FourClique => difference == -1,
+            _ => difference == 1,
+        }));
+    assert!(graph
+        .edge_signature_diff((0, 1), (0, 1), graph.get_number_of_node_labels())
+        .is_empty());
+}
+
+#[test]
+fn test_non_contiguous_labels() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let sparse_labels = [5, 10, 42, 10, 5, 42];
+    let sparse_graph =
+        CSRGraph::from_edges(sparse_labels.to_vec(), graph.iter_edges().collect()).unwrap();
+    assert_eq!(sparse_graph.get_number_of_node_labels(), 43);
+
+    let label_map = sparse_labels.into_iter().collect::<LabelMap<u8>>();
+    assert_eq!(label_map.len(), 3);
+    assert_eq!(label_map.get_labels(), &[5, 10, 42]);
+    assert_eq!(label_map.get_index(&42), Some(2));
+    assert_eq!(label_map.get_index(&7), None);
+
+    let interned_graph = InternedGraph::new(&graph, sparse_labels).unwrap();
+    assert_eq!(interned_graph.get_number_of_node_labels(), 3);
+    assert_eq!(
+        interned_graph.get_label_map().get_labels(),
+        label_map.get_labels()
+    );
+
+    // The counts of the sparse labels, hashed in base 44, must match those
+    // of the interned labels once these are mapped back to the sparse ones.
+    let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&sparse_graph);
+    let mut sparse_counts: HashMap<(ExtendedGraphletType, Vec<u8>), u32> = HashMap::new();
+    let mut interned_counts: HashMap<(ExtendedGraphletType, Vec<u8>), u32> = HashMap::new();
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        for (graphlet, count) in sentinel_graph.get_heterogeneous_graphlet(src, dst) {
+            let (graphlet_kind, (first, second, third, fourth)) =
+                SentinelLayout::decode::<ExtendedGraphletType>(graphlet, 43);
+            let labels = [first, second, third, fourth]
+                .into_iter()
+                .filter(|&label| label < 43)
+                .collect::<Vec<u8>>();
+            *sparse_counts.entry((graphlet_kind, labels)).or_default() += count;
+        }
+        for (graphlet, count) in
+            HeterogeneousGraphlets::<u64, u32, OffsetLayout>::get_heterogeneous_graphlet(
+                &interned_graph,
+                src,
+                dst,
+            )
+        {
+            let (graphlet_kind, (first, second, third, fourth)) =
+                OffsetLayout::decode::<ExtendedGraphletType>(graphlet, 3);
+            let labels = [first, second, third, fourth]
+                .into_iter()
+                .filter(|&label| label < 3)
+                .map(|label| *label_map.get_label(label))
+                .collect::<Vec<u8>>();
+            *interned_counts.entry((graphlet_kind, labels)).or_default() += count;
+        }
+    }
+
+    assert!(!sparse_counts.is_empty());
+    assert_eq!(sparse_counts, interned_counts);
+}
+
+#[test]
+fn test_floating_point_and_signed_counts() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let adjacency = (0..graph.get_number_of_nodes())
+        .map(|node| (node, graph.iter_neighbours(node).collect()))
+        .collect::<HashMap<usize, Vec<usize>>>();
+    let node_labels = (0..graph.get_number_of_nodes())
+        .map(|node| (node, graph.get_node_label(node) as usize))
+        .collect::<HashMap<usize, usize>>();
+    let hashmap_graph = HashMapGraph::new(&adjacency, &node_labels).unwrap();
+
+    let mut float_counts: HashMap<u16, f64> = HashMap::new();
+    let mut signed_counts: HashMap<u16, i64> = HashMap::new();
+    for src in 0..hashmap_graph.get_number_of_nodes() {
+        for dst in hashmap_graph.iter_neighbours(src).filter(|&dst| src < dst) {
+            for (graphlet, count) in HeterogeneousGraphlets::<u16, f64>::get_heterogeneous_graphlet(
+                &hashmap_graph,
+                src,
+                dst,
+            ) {
+                float_counts.insert_count(graphlet, count);
+            }
+            for (graphlet, count) in HeterogeneousGraphlets::<u16, i64>::get_heterogeneous_graphlet(
+                &hashmap_graph,
+                src,
+                dst,
+            ) {
+                signed_counts.insert_count(graphlet, count);
+            }
+        }
+    }
+
+    let expected = count_graphlets(&graph);
+    assert!(!expected.is_empty());
+    assert_eq!(float_counts.len(), expected.len());
+    assert_eq!(signed_counts.len(), expected.len());
+    for (graphlet, count) in expected {
+        assert_eq!(float_counts[&graphlet], count as f64);
+        assert_eq!(signed_counts[&graphlet], count as i64);
+    }
+}
+
+#[test]
+fn test_conditional_kind_distribution() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+
+    let distributions =
+        HeterogeneousGraphlets::<u64, u32, SentinelLayout>::conditional_kind_distribution(
+            &sentinel_graph,
+        );
+    assert_eq!(distributions.len(), 2);
+
+    let triangle = u8::from(ExtendedGraphletType::Triangle) as usize;
+    // The nodes with label 1 only appear in a triangle.
+    let mut expected = [0.0; 12];
+    expected[triangle] = 1.0;
+    assert_eq!(distributions[&1], expected);
+
+    // The nodes with label 0 form a four-path, and therefore no triangle.
+    assert_eq!(distributions[&0][triangle], 0.0);
+    assert!((distributions[&0].iter().sum::<f64>() - 1.0).abs() < 1e-12);
+    assert!(distributions[&0][u8::from(ExtendedGraphletType::Triad) as usize] > 0.0);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_approximate_gdv() {
+    for (node_list, edge_list) in [
+        (
+            "tests/data/wheel/node_list.csv",
+            "tests/data/wheel/edge_list.csv",
+        ),
+        (
+            "tests/data/tailed_four_clique/node_list.csv",
+            "tests/data/tailed_four_clique/edge_list.csv",
+        ),
+        (
+            "tests/data/triangle_and_path/node_list.csv",
+            "tests/data/triangle_and_path/edge_list.csv",
+        ),
+    ] {
+        let graph = CSRGraph::from_csv(node_list, edge_list).unwrap();
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        let number_of_nodes = graph.get_number_of_nodes();
+        let seed_nodes = (0..number_of_nodes).collect::<Vec<_>>();
+
+        // With enough hops and an unbounded frontier, the approximation is exact.
+        let approximate_gdvs = sentinel_graph.approximate_gdv(
+            &seed_nodes,
+            3,
+            number_of_nodes,
+            &mut StdRng::seed_from_u64(42),
+        );
+        assert_eq!(approximate_gdvs.len(), number_of_nodes);
+
+        for node in seed_nodes {
+            let mut exact_gdv = [0_u32; 12];
+            for neighbour in graph.iter_neighbours(node) {
+                for (graphlet, count) in sentinel_graph
+                    .get_heterogeneous_graphlet(node.min(neighbour), node.max(neighbour))
+                {
+                    let kind = SentinelLayout::decode_graphlet_kind::<ExtendedGraphletType>(
+                        graphlet,
+                        graph.get_number_of_node_labels(),
+                    );
+                    exact_gdv[u8::from(kind) as usize] += count;
+                }
+            }
+            assert_eq!(approximate_gdvs[&node], exact_gdv, "{} {}", node_list, node);
+            assert_eq!(
+                approximate_gdvs[&node].to_vec(),
+                sentinel_graph.graphlet_degree_vector(node)
+            );
+        }
+    }
+
+    // Sampling some of the spokes of the hub of the wheel scales their counts by the
+    // inverse of the sampled fraction, as the spokes share the same graphlets by symmetry.
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+    let mut rng = StdRng::seed_from_u64(0);
+    let exact_gdvs = sentinel_graph.approximate_gdv(&[0], 3, 6, &mut rng);
+    assert_eq!(graph.get_node_degree(0), 5);
+    for max_frontier_size in 1..=3 {
+        let sampled_gdvs = sentinel_graph.approximate_gdv(&[0], 1, max_frontier_size, &mut rng);
+        assert_eq!(sampled_gdvs, exact_gdvs, "{}", max_frontier_size);
+    }
+}
+
+#[test]
+fn test_write_orca_format() {
+    for graph_name in [
+        "four_star",
+        "four_cycle",
+        "triangle_and_path",
+        "tailed_four_clique",
+        "five_clique_minus_edge",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+
+        let mut output = Vec::new();
+        HeterogeneousGraphlets::<u64, u32, SentinelLayout>::write_orca_format(
+            &sentinel_graph,
+            &mut output,
+        )
+        .unwrap();
+
+        let expected =
+            std::fs::read_to_string(format!("tests/data/{}/orca.txt", graph_name)).unwrap();
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            expected,
+            "{}",
+            graph_name
+        );
+    }
+}
+
+#[test]
+fn test_try_encode_with_graphlet() {
+    for graphlet_kind in ExtendedGraphletType::all() {
+        let labels = [2_u8, 0, 1, 3];
+        assert_eq!(
+            labels.try_encode_with_graphlet(graphlet_kind, 3_u8),
+            Ok::<u16, String>(labels.encode_with_graphlet(graphlet_kind, 3_u8))
+        );
+        assert_eq!(
+            PolynomialLayout::try_encode((2_u8, 0, 1, 3), graphlet_kind, 3_u8),
+            Ok::<u16, String>(PolynomialLayout::encode(
+                (2_u8, 0, 1, 3),
+                graphlet_kind,
+                3_u8
+            ))
+        );
+        assert_eq!(
+            SentinelLayout::try_encode((2_u8, 0, 1, 3), graphlet_kind, 3_u8),
+            Ok::<u64, String>(SentinelLayout::encode((2_u8, 0, 1, 3), graphlet_kind, 3_u8))
+        );
+    }
+
+    // A single label is the most a `u8` supports, and the four-clique overflows with two.
+    let maximal_labels = [1_u8, 1, 1, 1];
+    assert!(NTuplePerfectHash::<4, u8, u8>::try_encode_with_graphlet(
+        &maximal_labels,
+        ExtendedGraphletType::FourClique,
+        1
+    )
+    .is_ok());
+    assert!(NTuplePerfectHash::<4, u8, u8>::try_encode_with_graphlet(
+        &maximal_labels,
+        ExtendedGraphletType::FourClique,
+        2
+    )
+    .is_err());
+    // The three-node graphlets are the first ones, and fit with two labels.
+    assert!(NTuplePerfectHash::<4, u8, u8>::try_encode_with_graphlet(
+        &[1, 1, 1, 2],
+        ExtendedGraphletType::Triad,
+        2
+    )
+    .is_ok());
+    // Labels and numbers of elements wider than the graphlet type are detected.
+    assert!(NTuplePerfectHash::<4, u8, u16>::try_encode_with_graphlet(
+        &[0, 0, 0, 1],
+        ExtendedGraphletType::Triad,
+        256
+    )
+    .is_err());
+    // The sentinel base does not fit in a graphlet type as wide as the elements.
+    assert!(
+        <SentinelLayout as GraphletHashLayout<u16, u16>>::try_encode(
+            (0, 0, 0, 0),
+            ExtendedGraphletType::Triad,
+            2
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_max_labels_for_width() {
+    assert_eq!(max_labels_for_width::<u8>(), 1);
+    assert_eq!(max_labels_for_width::<u16>(), 7);
+    assert_eq!(max_labels_for_width::<u32>(), 136);
+    assert_eq!(max_labels_for_width::<u64>(), 35_210);
+
+    // The maximal hash fits with the returned number of labels, and overflows with one more.
+    let n = max_labels_for_width::<u32>() as u64;
+    let maximal_hash =
+        <PolynomialLayout as GraphletHashLayout<u64, u64>>::maximal_hash::<ExtendedGraphletType>;
+    assert!(maximal_hash(n) <= u32::MAX as u64);
+    assert!(maximal_hash(n + 1) > u32::MAX as u64);
+
+    let n = max_labels_for_width::<u64>() as u128;
+    let maximal_hash =
+        <PolynomialLayout as GraphletHashLayout<u128, u128>>::maximal_hash::<ExtendedGraphletType>;
+    assert!(maximal_hash(n) <= u64::MAX as u128);
+    assert!(maximal_hash(n + 1) > u64::MAX as u128);
+}
+
+#[test]
+fn test_required_graphlet_bits() {
+    for number_of_node_labels in 0..300_u64 {
+        let maximal_hash = <[u64; 4] as NTuplePerfectHash<4, u64, u64>>::maximal_hash::<
+            ExtendedGraphletType,
+        >(number_of_node_labels);
+        assert_eq!(
+            required_graphlet_bits(number_of_node_labels as usize),
+            u64::BITS - maximal_hash.leading_zeros()
+        );
+    }
+    assert_eq!(required_graphlet_bits(1), 8);
+    assert_eq!(required_graphlet_bits(2), 10);
+
+    // The largest number of labels of each width is the last one fitting in its bits.
+    for (max_labels, bits) in [
+        (max_labels_for_width::<u8>(), u8::BITS),
+        (max_labels_for_width::<u16>(), u16::BITS),
+        (max_labels_for_width::<u32>(), u32::BITS),
+        (max_labels_for_width::<u64>(), u64::BITS),
+        (max_labels_for_width::<u128>(), u128::BITS),
+    ] {
+        assert_eq!(required_graphlet_bits(max_labels), bits);
+        assert!(required_graphlet_bits(max_labels + 1) > bits);
+    }
+}
+
+/// Returns the widened graphlet counts of a triangle labelled `0`, `1` and `number_of_node_labels - 1`.
+fn count_triangle_with_u128(number_of_node_labels: usize) -> HashMap<u128, u64> {
+    let triangle = ImplicitGraph::new(
+        |_src: usize, _dst: usize| true,
+        vec![0, 1, number_of_node_labels - 1],
+    );
+    let mut counter: HashMap<u128, u64> = HashMap::new();
+    for (src, dst) in [(0, 1), (0, 2), (1, 2)] {
+        let edge_counter =
+            HeterogeneousGraphlets::<u128, u64>::get_heterogeneous_graphlet(&triangle, src, dst);
+        for (graphlet, count) in edge_counter {
+            counter.insert_count(graphlet, count);
+        }
+    }
+    counter
+}
+
+#[test]
+fn test_count_with_auto_width() {
+    let boundaries = [
+        (max_labels_for_width::<u16>(), 16),
+        (max_labels_for_width::<u32>(), 32),
+        (max_labels_for_width::<u64>(), 64),
+        (max_labels_for_width::<u128>(), 128),
+    ];
+    // The counting of each edge takes time quadratic in the number of node labels,
+    // so only the smaller boundaries are crossed.
+    for (boundary_index, &(boundary, width)) in boundaries[..2].iter().enumerate() {
+        for number_of_node_labels in [boundary, boundary + 1] {
+            let triangle = ImplicitGraph::new(
+                |_src: usize, _dst: usize| true,
+                vec![0, 1, number_of_node_labels - 1],
+            );
+            let counts = count_with_auto_width(&triangle);
+            let expected_width = if number_of_node_labels == boundary {
+                width
+            } else {
+                boundaries[boundary_index + 1].1
+            };
+            assert_eq!(
+                counts.width(),
+                expected_width,
+                "Unexpected width for {} node labels.",
+                number_of_node_labels
+            );
+            assert_eq!(
+                counts.to_widened(),
+                count_triangle_with_u128(number_of_node_labels)
+            );
+        }
+    }
+}
+
+#[test]
+fn test_isomorphism_invariance() {
+    for graph_name in [
+        "four_cycle",
+        "wheel",
+        "tailed_four_clique",
+        "five_clique_minus_edge",
+        "triangle_and_path",
+        "labeled_paths",
+        "clique_and_diamond",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        assert_isomorphism_invariant(&graph, 10);
+    }
+}
+
+#[test]
+fn test_alternating_four_cycle() {
+    // In a four-cycle with alternating labels, the labels of the two nodes closing
+    // the cycle appear in opposite orders depending on the edge.
+    let graph = CSRGraph::from_edges(
+        vec![0, 1, 0, 1],
+        vec![
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 2),
+            (3, 0),
+            (0, 3),
+        ],
+    )
+    .unwrap();
+    let kind_totals =
+        CounterWithBase::new(count_graphlets(&graph), graph.get_number_of_node_labels())
+            .get_kind_totals();
+    assert_eq!(
+        kind_totals
+            .into_iter()
+            .filter(|&(_, count)| count > 0)
+            .collect::<HashMap<_, _>>(),
+        HashMap::from([
+            (ExtendedGraphletType::Triad, 8),
+            (ExtendedGraphletType::FourCycle, 4),
+        ])
+    );
+}
+
+/// Returns the count of the provided graphlet kind of the edge (0, 1) of the graph with the
+/// provided undirected edges, whose first two nodes have label 0, for the provided pair of
+/// labels of the other two nodes.
+fn get_edge_orbit_count(
+    node_labels: Vec<u8>,
+    edges: Vec<(usize, usize)>,
+    graphlet_kind: ExtendedGraphletType,
+    labels: (u8, u8),
+) -> u32 {
+    let graph = CSRGraph::from_edges(
+        node_labels,
+        edges
+            .into_iter()
+            .flat_map(|(src, dst)| [(src, dst), (dst, src)])
+            .collect(),
+    )
+    .unwrap();
+    graph
+        .get_heterogeneous_graphlet(0, 1)
+        .get_number_of_graphlets(PolynomialLayout::encode(
+            (0, 0, labels.0, labels.1),
+            graphlet_kind,
+            graph.get_number_of_node_labels(),
+        ))
+}
+
+#[test]
+fn test_four_path_center_orbit_formula() {
+    // Two exclusive neighbours per endpoint, with the 4-cycle 0-2-4-1 closed by (2, 4):
+    // of the 2 * 2 paths x-0-1-y, the one through (2, 4) is not induced.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 1, 1, 1],
+            vec![(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (2, 4)],
+            ExtendedGraphletType::FourPathCenter,
+            (1, 1),
+        ),
+        3
+    );
+
+    // With distinct labels, the paths are 2-0-1-5 and 3-0-1-4,
+    // and the first one is closed into a 4-cycle by (2, 5).
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 2, 1, 2],
+            vec![(0, 1), (0, 2), (0, 3), (1, 4), (1, 5), (2, 5)],
+            ExtendedGraphletType::FourPathCenter,
+            (1, 2),
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_four_star_orbit_formula() {
+    // Three exclusive neighbours of the source and one of the destination:
+    // of the binomial(3, 2) + binomial(1, 2) leaf pairs, (2, 3) is adjacent.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 1, 1, 1],
+            vec![(0, 1), (0, 2), (0, 3), (0, 4), (1, 5), (2, 3)],
+            ExtendedGraphletType::FourStar,
+            (1, 1),
+        ),
+        2
+    );
+
+    // With distinct labels, the leaf pairs are (2, 3) and (2, 4) around the source
+    // and (5, 6) around the destination, where (2, 3) is adjacent.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 2, 2, 1, 2],
+            vec![(0, 1), (0, 2), (0, 3), (0, 4), (1, 5), (1, 6), (2, 3)],
+            ExtendedGraphletType::FourStar,
+            (1, 2),
+        ),
+        2
+    );
+}
+
+#[test]
+fn test_tailed_tri_edge_orbit_formula() {
+    // A triangle node and one exclusive neighbour per endpoint, where the
+    // tail 3 of the source is adjacent to the triangle node, forming a chordal cycle.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 1, 1],
+            vec![(0, 1), (0, 2), (1, 2), (0, 3), (1, 4), (2, 3)],
+            ExtendedGraphletType::TailedTriEdge,
+            (1, 1),
+        ),
+        1
+    );
+
+    // With distinct labels, the triangle node 2 takes the tail 5 of the destination,
+    // while the triangle node 3 and the tail 4 of the source form a chordal cycle.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 2, 1, 2],
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (0, 3),
+                (1, 3),
+                (0, 4),
+                (1, 5),
+                (3, 4)
+            ],
+            ExtendedGraphletType::TailedTriEdge,
+            (1, 2),
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_chordal_cycle_center_orbit_formula() {
+    // Three triangle nodes, where (2, 3) closes a 4-clique:
+    // of the binomial(3, 2) pairs of triangle nodes, two are not adjacent.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 1, 1],
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (0, 3),
+                (1, 3),
+                (0, 4),
+                (1, 4),
+                (2, 3)
+            ],
+            ExtendedGraphletType::ChordalCycleCenter,
+            (1, 1),
+        ),
+        2
+    );
+
+    // With distinct labels, the pairs of triangle nodes are (2, 3) and (2, 4),
+    // where (2, 3) closes a 4-clique.
+    assert_eq!(
+        get_edge_orbit_count(
+            vec![0, 0, 1, 2, 2],
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 2),
+                (0, 3),
+                (1, 3),
+                (0, 4),
+                (1, 4),
+                (2, 3)
+            ],
+            ExtendedGraphletType::ChordalCycleCenter,
+            (1, 2),
+        ),
+        1
+    );
+}
+
+#[test]
+fn test_get_heterogeneous_graphlet_min_count() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/six_clique/node_list.csv",
+        "tests/data/six_clique/edge_list.csv",
+    )
+    .unwrap();
+
+    for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+        let counts = graph.get_heterogeneous_graphlet(src, dst);
+        // A zero threshold keeps every graphlet.
+        assert_eq!(
+            graph.get_heterogeneous_graphlet_min_count(src, dst, 0),
+            counts
+        );
+
+        for min_count in 1..=4 {
+            let filtered_counts = graph.get_heterogeneous_graphlet_min_count(src, dst, min_count);
+            for (graphlet, count) in counts.iter_graphlets_and_counts() {
+                if count >= min_count {
+                    assert_eq!(filtered_counts.get(&graphlet), Some(&count));
+                } else {
+                    assert!(!filtered_counts.contains_key(&graphlet));
+                }
+            }
+            assert!(filtered_counts.values().all(|&count| count >= min_count));
+        }
+    }
+
+    // The threshold is met by some graphlets of the edge and not by others.
+    let counts = graph.get_heterogeneous_graphlet(0, 1);
+    assert!(counts.values().any(|&count| count < 2));
+    assert!(counts.values().any(|&count| count >= 2));
+}
+
+#[test]
+fn test_count_all_graphlets() {
+    for graph_name in ["four_star", "wheel", "six_clique", "labeled_paths"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        assert_eq!(graph.count_all_graphlets(), count_graphlets(&graph));
+    }
+
+    // The counts of a graph without edges are empty.
+    let graph = CSRGraph::from_edges(vec![0, 1, 2], Vec::new()).unwrap();
+    assert!(graph.count_all_graphlets().is_empty());
+}
+
+/// Returns the degrees of the nodes of the provided graph, computed in parallel.
+///
+/// # Arguments
+/// * `graph` - The graph whose node degrees should be returned.
+fn par_node_degrees<G: ParallelGraph + Sync>(graph: &G) -> Vec<usize> {
+    graph
+        .par_iter_nodes()
+        .map(|node| graph.get_node_degree(node))
+        .collect()
+}
+
+#[test]
+fn test_get_heterogeneous_graphlet_in_subset() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/tailed_four_clique/node_list.csv",
+        "tests/data/tailed_four_clique/edge_list.csv",
+    )
+    .unwrap();
+    let number_of_elements = graph.get_number_of_node_labels();
+
+    // Restricted to the clique, the tail is absent and only the clique graphlets remain.
+    let clique = [true, true, true, true, false];
+    for (src, dst) in graph.iter_edges().filter(|&(src, dst)| src < 4 && dst < 4) {
+        let counts = graph.get_heterogeneous_graphlet_in_subset(src, dst, &clique);
+        let mut kinds = counts
+            .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+            .map(|(kind, _, count)| (kind, count))
+            .collect::<Vec<_>>();
+        kinds.sort_unstable_by_key(|&(kind, _)| kind.orbit_index());
+        assert_eq!(
+            kinds,
+            vec![
+                (ExtendedGraphletType::Triangle, 2),
+                (ExtendedGraphletType::FourClique, 1)
+            ]
+        );
+        assert_ne!(counts, graph.get_heterogeneous_graphlet(src, dst));
+    }
+
+    // With all the nodes, the counts are the ones of the whole graph.
+    let all_nodes = [true; 5];
+    for (src, dst) in graph.iter_edges() {
+        assert_eq!(
+            graph.get_heterogeneous_graphlet_in_subset(src, dst, &all_nodes),
+            graph.get_heterogeneous_graphlet(src, dst)
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_get_heterogeneous_graphlet_in_subset_outside() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/tailed_four_clique/node_list.csv",
+        "tests/data/tailed_four_clique/edge_list.csv",
+    )
+    .unwrap();
+    graph.get_heterogeneous_graphlet_in_subset(3, 4, &[true, true, true, true, false]);
+}
+
+#[test]
+fn test_get_edge_orbit_counts() {
+    for graph_name in [
+        "four_path",
+        "wheel",
+        "tailed_four_clique",
+        "labeled_paths",
+        "sparse_labels",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        for (src, dst) in graph.iter_edges() {
+            // The counts of the map are summed over the labels of each kind.
+            let mut expected = [0; 12];
+            for (graphlet, count) in sentinel_graph.get_heterogeneous_graphlet(src, dst) {
+                let kind = SentinelLayout::decode_graphlet_kind::<ExtendedGraphletType>(
+                    graphlet,
+                    graph.get_number_of_node_labels(),
+                );
+                expected[kind.orbit_index()] += count;
+            }
+            assert_eq!(
+                graph.get_edge_orbit_counts(src, dst),
+                expected,
+                "{} ({}, {})",
+                graph_name,
+                src,
+                dst
+            );
+        }
+    }
+}
+
+#[test]
+fn test_graphlet_feature_vector() {
+    for graph_name in ["four_path", "wheel", "labeled_paths", "sparse_labels"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let number_of_labels = graph.get_number_of_node_labels_usize();
+        let features = graph.graphlet_feature_vector();
+
+        // Two three-node kinds and ten four-node kinds, with a slot per label combination.
+        assert_eq!(
+            features.len(),
+            2 * number_of_labels.pow(3) + 10 * number_of_labels.pow(4)
+        );
+        assert_eq!(features, graph.graphlet_feature_vector());
+        assert_eq!(
+            features.iter().sum::<u32>(),
+            graph.count_all_graphlets().values().sum::<u32>()
+        );
+
+        // The slots do not depend on the layout the graphlets are encoded with.
+        assert_eq!(
+            LayoutGraph::<SentinelLayout>::new(&graph).graphlet_feature_vector(),
+            features
+        );
+    }
+
+    // The triads of the four path are in the first slots, indexed by their labels.
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_path/node_list.csv",
+        "tests/data/four_path/edge_list.csv",
+    )
+    .unwrap();
+    let features = graph.graphlet_feature_vector();
+    // The nodes of the path have label zero, and the isolated node has label one.
+    let triads = &features[..8];
+    assert_eq!(triads[0], 4);
+    assert_eq!(triads.iter().sum::<u32>(), 4);
+
+    // The triangle of the nodes with label one is in the last slot of the triangles,
+    // which follow the eight slots of the triads.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle_and_path/node_list.csv",
+        "tests/data/triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let features = graph.graphlet_feature_vector();
+    assert_eq!(features[0], 4);
+    assert_eq!(features[8 + 7], 3);
+    assert_eq!(features[..16].iter().sum::<u32>(), 7);
+}
+
+#[test]
+fn test_parallel_graph() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+
+    let mut parallel_edges = graph.par_iter_edges().collect::<Vec<_>>();
+    parallel_edges.sort_unstable();
+    assert_eq!(parallel_edges, graph.iter_edges().collect::<Vec<_>>());
+
+    let degrees = par_node_degrees(&graph);
+    assert_eq!(degrees.len(), graph.get_number_of_nodes());
+    assert_eq!(degrees.iter().sum::<usize>(), parallel_edges.len());
+    assert_eq!(par_node_degrees(&DenseCounterGraph(&graph)), degrees,);
+}
+
+#[test]
+fn test_count_all_graphlets_with_progress() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let mut processed_edges = Vec::new();
+    let counts =
+        graph.count_all_graphlets_with_progress(|src, dst| processed_edges.push((src, dst)));
+
+    // The callback is invoked once per undirected edge, and does not change the counts.
+    assert_eq!(counts, graph.count_all_graphlets());
+    assert_eq!(
+        processed_edges,
+        graph
+            .iter_edges()
+            .filter(|(src, dst)| src < dst)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_par_count_all_graphlets() {
+    for graph_name in ["four_star", "wheel", "six_clique", "labeled_paths", "cora"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        assert_eq!(graph.par_count_all_graphlets(), graph.count_all_graphlets());
+
+        // The examined quadruples of the tasks are summed as well.
+        let dense_graph = DenseCounterGraph(&graph);
+        let parallel_counts = dense_graph.par_count_all_graphlets();
+        let sequential_counts = dense_graph.count_all_graphlets();
+        assert_eq!(
+            parallel_counts
+                .iter_graphlets_and_counts()
+                .collect::<HashMap<u16, u32>>(),
+            sequential_counts
+                .iter_graphlets_and_counts()
+                .collect::<HashMap<u16, u32>>()
+        );
+        assert_eq!(parallel_counts.examined(), sequential_counts.examined());
+    }
+
+    // The counts of a graph without edges are empty.
+    let graph = CSRGraph::from_edges(vec![0, 1, 2], Vec::new()).unwrap();
+    assert!(graph.par_count_all_graphlets().is_empty());
+}
+
+#[test]
+fn test_graphlet_type_try_from_u8() {
+    for value in 0..12 {
+        let graphlet_kind = ExtendedGraphletType::try_from_u8(value).unwrap();
+        assert_eq!(graphlet_kind, ExtendedGraphletType::from(value));
+        assert_eq!(u8::from(graphlet_kind), value);
+    }
+    for value in 0..8 {
+        let graphlet_kind = ReducedGraphletType::try_from_u8(value).unwrap();
+        assert_eq!(graphlet_kind, ReducedGraphletType::from(value));
+        assert_eq!(u8::from(graphlet_kind), value);
+    }
+
+    // Values past the last graphlet type are reported instead of panicking.
+    assert_eq!(
+        ExtendedGraphletType::try_from_u8(12),
+        Err(InvalidGraphletType {
+            value: 12,
+            number_of_graphlet_types: 12
+        })
+    );
+    assert_eq!(
+        ReducedGraphletType::try_from_u8(8).unwrap_err().to_string(),
+        "Invalid graphlet type: 8 (should be between 0 and 7)"
+    );
+    assert_eq!(
+        FiveNodeGraphletType::try_from_u8(5)
+            .unwrap_err()
+            .number_of_graphlet_types,
+        5
+    );
+    let error: Box<dyn std::error::Error> =
+        Box::new(ExtendedGraphletType::try_from_u8(u8::MAX).unwrap_err());
+    assert!(error.to_string().contains("between 0 and 11"));
+}
+
+#[test]
+fn test_graphlet_type_all() {
+    let extended = ExtendedGraphletType::all().collect::<Vec<_>>();
+    assert_eq!(extended.len(), ExtendedGraphletType::COUNT);
+    assert_eq!(extended[0], ExtendedGraphletType::Triad);
+    assert_eq!(extended[11], ExtendedGraphletType::FourClique);
+    for (index, graphlet_kind) in extended.iter().enumerate() {
+        assert_eq!(graphlet_kind.orbit_index(), index);
+    }
+    assert_eq!(
+        ExtendedGraphletType::COUNT,
+        <ExtendedGraphletType as GraphletSet<usize>>::get_number_of_graphlets()
+    );
+
+    let reduced = ReducedGraphletType::all().collect::<Vec<_>>();
+    assert_eq!(reduced.len(), ReducedGraphletType::COUNT);
+    assert_eq!(reduced[0], ReducedGraphletType::Triad);
+    assert_eq!(reduced[7], ReducedGraphletType::FourClique);
+    for (index, graphlet_kind) in reduced.iter().enumerate() {
+        assert_eq!(graphlet_kind.orbit_index(), index);
+    }
+    assert_eq!(
+        ReducedGraphletType::COUNT,
+        <ReducedGraphletType as GraphletSet<usize>>::get_number_of_graphlets()
+    );
+}
+
+#[test]
+fn test_graphlet_type_node_count() {
+    let triadic = ExtendedGraphletType::all()
+        .filter(|graphlet_kind| graphlet_kind.node_count() == 3)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        triadic,
+        vec![ExtendedGraphletType::Triad, ExtendedGraphletType::Triangle]
+    );
+    assert_eq!(ExtendedGraphletType::FourClique.node_count(), 4);
+    assert_eq!(ExtendedGraphletType::FourPathEdge.node_count(), 4);
+
+    // The orbits have as many nodes as their graphlets.
+    for graphlet_kind in ExtendedGraphletType::all() {
+        assert_eq!(
+            graphlet_kind.node_count(),
+            graphlet_kind.to_reduced().node_count()
+        );
+    }
+    assert_eq!(
+        ReducedGraphletType::all()
+            .filter(|graphlet_kind| graphlet_kind.node_count() == 4)
+            .count(),
+        6
+    );
+}
+
+#[test]
+fn test_graphlet_type_paper_orbit() {
+    assert_eq!(ExtendedGraphletType::Triad.paper_orbit(), 1);
+    assert_eq!(ExtendedGraphletType::FourClique.paper_orbit(), 12);
+    assert_eq!(
+        ExtendedGraphletType::FourClique.paper_description(),
+        "four-clique (g12)"
+    );
+    for graphlet_kind in ExtendedGraphletType::all() {
+        assert_eq!(
+            graphlet_kind.paper_orbit() as usize,
+            graphlet_kind.orbit_index() + 1
+        );
+        assert!(graphlet_kind
+            .paper_description()
+            .ends_with(&format!("(g{})", graphlet_kind.paper_orbit())));
+    }
+}
+
+#[test]
+fn test_graphlet_type_display() {
+    for graphlet_kind in ExtendedGraphletType::all() {
+        let name: &str = (&graphlet_kind).into();
+        assert_eq!(format!("{}", graphlet_kind), name);
+        assert_eq!(format!("{:>20}", graphlet_kind), format!("{:>20}", name));
+    }
+    for graphlet_kind in ReducedGraphletType::all() {
+        let name: &str = (&graphlet_kind).into();
+        assert_eq!(graphlet_kind.to_string(), name);
+    }
+    for graphlet_kind in FiveNodeGraphletType::all() {
+        let name: &str = (&graphlet_kind).into();
+        assert_eq!(format!("{:<15}|", graphlet_kind), format!("{:<15}|", name));
+    }
+    assert_eq!(
+        format!(
+            "{} and {}",
+            ExtendedGraphletType::FourClique,
+            ReducedGraphletType::TailedTri
+        ),
+        "FourClique and TailedTri"
+    );
+}
+
+#[test]
+fn test_graphlet_type_from_str() {
+    for graphlet_kind in ExtendedGraphletType::all() {
+        assert_eq!(graphlet_kind.to_string().parse(), Ok(graphlet_kind));
+    }
+    for graphlet_kind in ReducedGraphletType::all() {
+        assert_eq!(graphlet_kind.to_string().parse(), Ok(graphlet_kind));
+    }
+
+    let selection = "FourClique,Triangle"
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<ExtendedGraphletType>, String>>()
+        .unwrap();
+    assert_eq!(
+        selection,
+        vec![
+            ExtendedGraphletType::FourClique,
+            ExtendedGraphletType::Triangle
+        ]
+    );
+
+    // The names are case sensitive, and the error lists the valid ones.
+    let error = "fourclique".parse::<ExtendedGraphletType>().unwrap_err();
+    assert!(error.contains("fourclique"));
+    assert!(error.contains("ChordalCycleCenter"));
+    let error = "ChordalCycleCenter"
+        .parse::<ReducedGraphletType>()
+        .unwrap_err();
+    assert!(error.contains("ChordalCycle,"));
+}
+
+#[test]
+fn test_graphlet_degree_vector() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/four_star/node_list.csv",
+        "tests/data/four_star/edge_list.csv",
+    )
+    .unwrap();
+
+    // Each edge of the center is in a three-path with each of the two other edges
+    // of the center, and in the single four-star of the graph.
+    let gdv = graph.graphlet_degree_vector(0);
+    assert_eq!(gdv.len(), 12);
+    assert_eq!(gdv[u8::from(ExtendedGraphletType::Triad) as usize], 6);
+    assert_eq!(gdv[u8::from(ExtendedGraphletType::FourStar) as usize], 3);
+    assert_eq!(gdv.iter().sum::<u32>(), 9);
+
+    // Each leaf only has the edge to the center.
+    for leaf in 1..4 {
+        assert_eq!(
+            graph.get_node_graphlets(leaf),
+            graph.get_heterogeneous_graphlet(0, leaf)
+        );
+        let gdv = graph.graphlet_degree_vector(leaf);
+        assert_eq!(gdv[u8::from(ExtendedGraphletType::FourStar) as usize], 1);
+        assert_eq!(gdv.iter().sum::<u32>(), 3);
+    }
+}
+
+#[test]
+fn test_examined_quadruples() {
+    for graph_name in [
+        "four_path",
+        "four_cycle",
+        "wheel",
+        "tailed_four_clique",
+        "clique_and_diamond",
+        "labeled_paths",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let dense_graph = DenseCounterGraph(&graph);
+        let sentinel_graph = LayoutGraph::<SentinelLayout>::new(&graph);
+        let mut total_examined = 0;
+
+        for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+            // The quadruples are the pairs of neighbours of the edge, and the
+            // neighbours of the neighbours of the edge outside of its neighbourhood.
+            let neighbours = graph
+                .iter_neighbours(src)
+                .chain(graph.iter_neighbours(dst))
+                .filter(|&node| node != src && node != dst)
+                .collect::<std::collections::HashSet<usize>>();
+            let second_order_neighbours = neighbours
+                .iter()
+                .map(|&neighbour| {
+                    graph
+                        .iter_neighbours(neighbour)
+                        .filter(|node| *node != src && *node != dst && !neighbours.contains(node))
+                        .count()
+                })
+                .sum::<usize>();
+            let expected_examined = (neighbours.len() * neighbours.len().saturating_sub(1) / 2
+                + second_order_neighbours) as u32;
+
+            let counts = dense_graph.get_heterogeneous_graphlet(src, dst);
+            assert_eq!(
+                counts.examined(),
+                expected_examined,
+                "{} ({}, {})",
+                graph_name,
+                src,
+                dst
+            );
+
+            // Each examined quadruple is in exactly one four-node graphlet.
+            let four_node_counts = sentinel_graph
+                .get_heterogeneous_graphlet(src, dst)
+                .into_iter()
+                .filter(|&(graphlet, _)| {
+                    !matches!(
+                        SentinelLayout::decode_graphlet_kind::<ExtendedGraphletType>(
+                            graphlet,
+                            graph.get_number_of_node_labels(),
+                        ),
+                        ExtendedGraphletType::Triad | ExtendedGraphletType::Triangle
+                    )
+                })
+                .map(|(_, count)| count)
+                .sum::<u32>();
+            assert_eq!(
+                counts.examined(),
+                four_node_counts,
+                "{} ({}, {})",
+                graph_name,
+                src,
+                dst
+            );
+
+            // The HashMap counter does not track the examined quadruples.
+            assert_eq!(graph.get_heterogeneous_graphlet(src, dst).examined(), 0);
+
+            total_examined += expected_examined;
+        }
+
+        assert_eq!(dense_graph.count_all_graphlets().examined(), total_examined);
+    }
+}
+
+#[test]
+fn test_merge_counters() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let dense_graph = DenseCounterGraph(&graph);
+    let edges = graph
+        .iter_edges()
+        .filter(|(src, dst)| src < dst)
+        .collect::<Vec<_>>();
+
+    let mut merged: HashMap<u16, u32> = HashMap::new();
+    let mut extended: HashMap<u16, u32> = HashMap::new();
+    let mut dense_merged = DenseGraphletCounter::<u16, u32>::with_number_of_elements(
+        graph.get_number_of_node_labels(),
+    );
+    for &(src, dst) in edges.iter() {
+        merged.merge(&graph.get_heterogeneous_graphlet(src, dst));
+        extended.extend(graph.get_heterogeneous_graphlet(src, dst));
+        dense_merged.merge(&dense_graph.get_heterogeneous_graphlet(src, dst));
+    }
+
+    // The overlapping counts are summed by the merge, and replaced by the extension.
+    assert_eq!(merged, count_graphlets(&graph));
+    assert_ne!(merged, extended);
+    assert_eq!(merged.len(), extended.len());
+    assert!(extended
+        .iter()
+        .all(|(&graphlet, &count)| count <= merged[&graphlet]));
+
+    assert_eq!(
+        dense_merged
+            .iter_graphlets_and_counts()
+            .collect::<HashMap<u16, u32>>(),
+        merged
+    );
+    assert_eq!(
+        dense_merged.examined(),
+        dense_graph.count_all_graphlets().examined()
+    );
+}
+
+#[test]
+fn test_get_heterogeneous_graphlet_into() {
+    // The same scratch and counter are reused across edges and graphs
+    // with a different number of node labels.
+    let mut scratch = GraphletScratch::default();
+    let mut graphlet_counter = HashMap::new();
+    for graph_name in ["four_star", "wheel", "six_clique", "labeled_paths"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        for (src, dst) in graph.iter_edges() {
+            graph.get_heterogeneous_graphlet_into(src, dst, &mut scratch, &mut graphlet_counter);
+            assert_eq!(
+                graphlet_counter,
+                graph.get_heterogeneous_graphlet(src, dst),
+                "{} ({}, {})",
+                graph_name,
+                src,
+                dst
+            );
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_cluster_node_roles() {
+    // Two disjoint stars, with hubs 0 and 5, where the nodes only differ by their role.
+    let graph = CSRGraph::from_edges(
+        vec![0; 10],
+        [1, 2, 3, 4]
+            .into_iter()
+            .map(|leaf| (0, leaf))
+            .chain([6, 7, 8, 9].into_iter().map(|leaf| (5, leaf)))
+            .flat_map(|(hub, leaf)| [(hub, leaf), (leaf, hub)])
+            .collect(),
+    )
+    .unwrap();
+
+    for random_state in 0..10 {
+        let clusters = cluster_node_roles(&graph, 2, &mut StdRng::seed_from_u64(random_state));
+        assert_eq!(clusters.len(), 10);
+        assert_eq!(clusters[0], clusters[5]);
+        assert_ne!(clusters[0], clusters[1]);
+        for leaf in [1, 2, 3, 4, 6, 7, 8, 9] {
+            assert_eq!(clusters[leaf], clusters[1], "{} {}", random_state, leaf);
+        }
+    }
+
+    // With at least as many clusters as nodes, each node gets its own cluster.
+    assert_eq!(
+        cluster_node_roles(&graph, 10, &mut StdRng::seed_from_u64(0)),
+        (0..10).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_self_loops() {
+    // The wheel with self-loops on the hub 0 and on the rim node 3.
+    assert!(CSRGraph::from_csv(
+        "tests/data/wheel_with_self_loops/node_list.csv",
+        "tests/data/wheel_with_self_loops/edge_list.csv",
+    )
+    .is_err());
+
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let graph_without_self_loops = CSRGraph::from_csv_dropping_self_loops(
+        "tests/data/wheel_with_self_loops/node_list.csv",
+        "tests/data/wheel_with_self_loops/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(graph_without_self_loops, graph);
+
+    // Graphs keeping their self-loops are counted by skipping them.
+    let mut adjacency = (0..graph.get_number_of_nodes())
+        .map(|node| (node, graph.iter_neighbours(node).collect()))
+        .collect::<HashMap<usize, Vec<usize>>>();
+    for node in [0, 3] {
+        adjacency.get_mut(&node).unwrap().push(node);
+    }
+    let node_labels = (0..graph.get_number_of_nodes())
+        .map(|node| (node, graph.get_node_label(node) as usize))
+        .collect::<HashMap<usize, usize>>();
+    let graph_with_self_loops = HashMapGraph::new(&adjacency, &node_labels).unwrap();
+    assert!(graph_with_self_loops.is_neighbour(0, 0));
+
+    let self_loop_free_graph = SelfLoopFreeGraph::new(&graph_with_self_loops);
+    assert!(!self_loop_free_graph.is_neighbour(0, 0));
+    for node in 0..graph.get_number_of_nodes() {
+        assert_eq!(
+            self_loop_free_graph
+                .iter_neighbours(node)
+                .collect::<Vec<_>>(),
+            graph.iter_neighbours(node).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            self_loop_free_graph.get_node_degree(node),
+            graph.get_node_degree(node)
+        );
+    }
+    assert_eq!(
+        self_loop_free_graph.get_number_of_edges(),
+        graph.get_number_of_edges()
+    );
+    assert_eq!(
+        HeterogeneousGraphlets::<u16, u32>::count_all_graphlets(&self_loop_free_graph),
+        graph.count_all_graphlets()
+    );
+}
+
+/// A graph whose edge labels are the sum of the IDs of their nodes modulo the number of edge labels.
+struct EdgeLabeledGraph<'a>(&'a CSRGraph, u16);
+
+impl<'a> Graph for EdgeLabeledGraph<'a> {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.0.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.0.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.0.iter_neighbours(node)
+    }
+}
+
+impl<'a> TypedGraph for EdgeLabeledGraph<'a> {
+    type NodeLabel = u16;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.0.get_number_of_node_labels() as u16
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.0.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        label_index as u16
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        label as usize
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.0.get_node_label(node) as u16
+    }
+}
+
+impl<'a> EdgeTypedGraph for EdgeLabeledGraph<'a> {
+    type EdgeLabel = u16;
+
+    fn get_number_of_edge_labels(&self) -> Self::EdgeLabel {
+        self.1
+    }
+
+    fn get_edge_label(&self, src: usize, dst: usize) -> Self::EdgeLabel {
+        ((src + dst) % self.1 as usize) as u16
+    }
+}
+
+impl<'a> HeterogeneousGraphlets<u32, u32> for EdgeLabeledGraph<'a> {
+    type GraphLetCounter = HashMap<u32, u32>;
+}
+
+#[test]
+fn test_get_edge_typed_graphlet() {
+    for graph_name in [
+        "triangle",
+        "four_path",
+        "four_star",
+        "four_cycle",
+        "tailed_four_clique",
+        "clique_and_diamond",
+        "wheel",
+        "labeled_paths",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let number_of_node_labels = graph.get_number_of_node_labels() as u32;
+        for number_of_edge_labels in [1, 2, 3] {
+            let edge_labeled_graph = EdgeLabeledGraph(&graph, number_of_edge_labels);
+            for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+                // The counts of each kind match the node-labeled orbit counts of the edge.
+                let mut orbit_counts = [0_u32; 12];
+                for (graphlet, count) in edge_labeled_graph.get_edge_typed_graphlet(src, dst) {
+                    let (graphlet_kind, node_labels, edge_labels) = decode_edge_typed(
+                        graphlet,
+                        number_of_node_labels,
+                        number_of_edge_labels as u32,
+                    );
+                    orbit_counts[graphlet_kind.orbit_index()] += count;
+                    assert!(node_labels[..3]
+                        .iter()
+                        .all(|&label| label < number_of_node_labels));
+                    let expected_edge_label = ((src + dst) % number_of_edge_labels as usize) as u32;
+                    assert_eq!(edge_labels[0], expected_edge_label);
+                    assert_eq!(
+                        edge_labeled_graph.get_edge_typed_graphlet(dst, src)[&graphlet],
+                        count
+                    );
+                }
+                assert_eq!(
+                    orbit_counts,
+                    graph.get_edge_orbit_counts(src, dst),
+                    "{} ({}, {})",
+                    graph_name,
+                    src,
+                    dst
+                );
+            }
+        }
+    }
+
+    // In the triangle, whose nodes have labels 0, 1 and 2, the edges (0, 1), (0, 2) and
+    // (1, 2) have labels 1, 0 and 1 with two edge labels.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle/node_list.csv",
+        "tests/data/triangle/edge_list.csv",
+    )
+    .unwrap();
+    let edge_labeled_graph = EdgeLabeledGraph(&graph, 2);
+    let counts = edge_labeled_graph.count_all_edge_typed_graphlets();
+    assert_eq!(counts.len(), 3);
+    for (src, dst, third) in [(0, 1, 2), (0, 2, 1), (1, 2, 0)] {
+        let edge_label =
+            |src: usize, dst: usize| edge_labeled_graph.get_edge_label(src, dst) as u32;
+        let graphlet = encode_edge_typed(
+            ExtendedGraphletType::Triangle,
+            [src as u32, dst as u32, third as u32, 3],
+            [
+                edge_label(src, dst),
+                edge_label(src, third),
+                2,
+                edge_label(dst, third),
+                2,
+                2,
+            ],
+            3,
+            2,
+        );
+        assert_eq!(counts[&graphlet], 1, "{} {}", src, dst);
+    }
+}
+
+/// A graph whose edge weights are computed by the provided function of their nodes.
+struct WeightedTestGraph<'a, W>(&'a CSRGraph, fn(usize, usize) -> W);
+
+impl<'a, W> Graph for WeightedTestGraph<'a, W> {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.0.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.0.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.0.iter_neighbours(node)
+    }
+}
+
+impl<'a, W> TypedGraph for WeightedTestGraph<'a, W> {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.0.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.0.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.0.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.0.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.0.get_node_label(node)
+    }
+}
+
+impl<'a, W: Copy> WeightedGraph for WeightedTestGraph<'a, W> {
+    type Weight = W;
+
+    fn get_edge_weight(&self, src: usize, dst: usize) -> Self::Weight {
+        (self.1)(src.min(dst), src.max(dst))
+    }
+}
+
+impl<'a> HeterogeneousGraphlets<u16, u32> for WeightedTestGraph<'a, u32> {
+    type GraphLetCounter = HashMap<u16, u32>;
+}
+
+impl<'a> HeterogeneousGraphlets<u16, f64> for WeightedTestGraph<'a, f64> {
+    type GraphLetCounter = HashMap<u16, f64>;
+}
+
+#[test]
+fn test_get_weighted_graphlet() {
+    assert_eq!(WeightAggregation::Product.aggregate([2, 3, 4]), 24);
+    assert_eq!(WeightAggregation::Sum.aggregate([2, 3, 4]), 9);
+    assert_eq!(WeightAggregation::Min.aggregate([3.0, 2.0, 4.0]), 2.0);
+
+    for graph_name in [
+        "triangle",
+        "four_path",
+        "four_star",
+        "four_cycle",
+        "tailed_four_clique",
+        "clique_and_diamond",
+        "wheel",
+        "labeled_paths",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        // With unit weights, the products and the minima count each graphlet once.
+        let unit_weighted_graph = WeightedTestGraph(&graph, |_, _| 1_u32);
+        for (src, dst) in graph.iter_edges() {
+            for aggregation in [WeightAggregation::Product, WeightAggregation::Min] {
+                assert_eq!(
+                    unit_weighted_graph.get_weighted_graphlet(src, dst, aggregation),
+                    graph.get_heterogeneous_graphlet(src, dst),
+                    "{} ({}, {}) {:?}",
+                    graph_name,
+                    src,
+                    dst,
+                    aggregation
+                );
+            }
+        }
+        assert_eq!(
+            unit_weighted_graph.count_all_weighted_graphlets(WeightAggregation::Product),
+            graph.count_all_graphlets()
+        );
+
+        // With unit weights, the sums count the edges of each graphlet.
+        let number_of_edges = |graphlet_kind: ExtendedGraphletType| match graphlet_kind {
+            ExtendedGraphletType::Triad => 2,
+            ExtendedGraphletType::Triangle
+            | ExtendedGraphletType::FourPathCenter
+            | ExtendedGraphletType::FourPathEdge
+            | ExtendedGraphletType::FourStar => 3,
+            ExtendedGraphletType::FourCycle
+            | ExtendedGraphletType::TailedTriEdge
+            | ExtendedGraphletType::TailedTriCenter
+            | ExtendedGraphletType::TailedTriTail => 4,
+            ExtendedGraphletType::ChordalCycleEdge | ExtendedGraphletType::ChordalCycleCenter => 5,
+            ExtendedGraphletType::FourClique => 6,
+        };
+        for (graphlet, count) in
+            unit_weighted_graph.count_all_weighted_graphlets(WeightAggregation::Sum)
+        {
+            let graphlet_kind =
+                <PolynomialLayout as GraphletHashLayout<u16, u8>>::decode_graphlet_kind::<
+                    ExtendedGraphletType,
+                >(graphlet, graph.get_number_of_node_labels());
+            assert_eq!(
+                count,
+                number_of_edges(graphlet_kind) * graph.count_all_graphlets()[&graphlet]
+            );
+        }
+    }
+
+    // The edges (0, 1), (0, 2) and (1, 2) of the triangle weigh 0.5, 2 and 4.
+    let graph = CSRGraph::from_csv(
+        "tests/data/triangle/node_list.csv",
+        "tests/data/triangle/edge_list.csv",
+    )
+    .unwrap();
+    let weighted_graph = WeightedTestGraph(&graph, |src, dst| match (src, dst) {
+        (0, 1) => 0.5,
+        (0, 2) => 2.0,
+        _ => 4.0,
+    });
+    for (aggregation, expected) in [
+        (WeightAggregation::Product, 4.0),
+        (WeightAggregation::Sum, 6.5),
+        (WeightAggregation::Min, 0.5),
+    ] {
+        assert_eq!(
+            weighted_graph
+                .get_weighted_graphlet(0, 1, aggregation)
+                .into_values()
+                .collect::<Vec<f64>>(),
+            vec![expected]
+        );
+    }
+}
+
+/// A graph counting its graphlets with the sorted `BTreeMap` counter.
+struct SortedCounterGraph(CSRGraph);
+
+impl Graph for SortedCounterGraph {
+    type Node = usize;
+    type NeighbourIter<'b>
+        = <CSRGraph as Graph>::NeighbourIter<'b>
+    where
+        Self: 'b;
+
+    fn get_number_of_nodes(&self) -> usize {
+        self.0.get_number_of_nodes()
+    }
+
+    fn get_number_of_edges(&self) -> usize {
+        self.0.get_number_of_edges()
+    }
+
+    fn iter_neighbours(&self, node: usize) -> Self::NeighbourIter<'_> {
+        self.0.iter_neighbours(node)
+    }
+}
+
+impl TypedGraph for SortedCounterGraph {
+    type NodeLabel = u8;
+
+    fn get_number_of_node_labels(&self) -> Self::NodeLabel {
+        self.0.get_number_of_node_labels()
+    }
+
+    fn get_number_of_node_labels_usize(&self) -> usize {
+        self.0.get_number_of_node_labels_usize()
+    }
+
+    fn get_node_label_from_usize(&self, label_index: usize) -> Self::NodeLabel {
+        self.0.get_node_label_from_usize(label_index)
+    }
+
+    fn get_node_label_index(&self, label: Self::NodeLabel) -> usize {
+        self.0.get_node_label_index(label)
+    }
+
+    fn get_node_label(&self, node: usize) -> Self::NodeLabel {
+        self.0.get_node_label(node)
+    }
+}
+
+impl HeterogeneousGraphlets<u16, u32> for SortedCounterGraph {
+    type GraphLetCounter = BTreeMap<u16, u32>;
+}
+
+#[test]
+fn test_btree_map_counter() {
+    // The same labeled paths, loaded from the csv files and built from the reversed edges.
+    let graph = CSRGraph::from_csv(
+        "tests/data/labeled_paths/node_list.csv",
+        "tests/data/labeled_paths/edge_list.csv",
+    )
+    .unwrap();
+    let node_labels = (0..graph.get_number_of_nodes())
+        .map(|node| graph.get_node_label(node))
+        .collect::<Vec<u8>>();
+    let mut edges = graph.iter_edges().collect::<Vec<_>>();
+    edges.reverse();
+    let rebuilt_graph = CSRGraph::from_directed_edges(node_labels, edges).unwrap();
+    let number_of_elements = graph.get_number_of_node_labels();
+
+    let expected = graph.count_all_graphlets();
+    let counts = SortedCounterGraph(graph).count_all_graphlets();
+    let rebuilt_counts = SortedCounterGraph(rebuilt_graph).count_all_graphlets();
+
+    // The graphlets are iterated in ascending order, with the counts of the `HashMap` counter.
+    let graphlets = counts
+        .iter_graphlets_and_counts()
+        .map(|(graphlet, _)| graphlet)
+        .collect::<Vec<u16>>();
+    assert!(graphlets.windows(2).all(|window| window[0] < window[1]));
+    assert_eq!(counts.len(), expected.len());
+    for (graphlet, count) in expected {
+        assert_eq!(counts.get_number_of_graphlets(graphlet), count);
+    }
+
+    // The reports and the csv exports of the two graphs are byte-identical.
+    let report = |counts: &BTreeMap<u16, u32>| {
+        counts
+            .get_report::<ExtendedGraphletType, u8, PolynomialLayout>(number_of_elements)
+            .unwrap()
+    };
+    assert_eq!(report(&counts), report(&rebuilt_counts));
+    let write_csv = |counts: &BTreeMap<u16, u32>| {
+        let mut buffer = Vec::new();
+        counts
+            .write_report_csv::<_, _, PolynomialLayout>(&mut buffer, number_of_elements)
+            .unwrap();
+        buffer
+    };
+    assert_eq!(write_csv(&counts), write_csv(&rebuilt_counts));
+}
+
+#[test]
+fn test_dense_atomic_counter() {
+    for graph_name in ["wheel", "tailed_four_clique", "labeled_paths", "cora"] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let dense_graph = DenseCounterGraph(&graph);
+        let maximal_hash = <PolynomialLayout as GraphletHashLayout<u16, u8>>::maximal_hash::<
+            ExtendedGraphletType,
+        >(graph.get_number_of_node_labels());
+
+        let atomic_counter = DenseAtomicCounter::new(maximal_hash);
+        dense_graph.par_count_all_graphlets_into(&atomic_counter);
+        let counts = atomic_counter.into_counter();
+
+        let expected = dense_graph.count_all_graphlets();
+        assert_eq!(
+            counts
+                .iter_graphlets_and_counts()
+                .map(|(graphlet, count)| (graphlet, count as u32))
+                .collect::<HashMap<u16, u32>>(),
+            expected
+                .iter_graphlets_and_counts()
+                .collect::<HashMap<u16, u32>>(),
+            "{}",
+            graph_name
+        );
+        assert_eq!(counts.examined(), expected.examined() as u64);
+    }
+}
+
+#[test]
+fn test_clear_preserves_capacity() {
+    let graph = CSRGraph::from_csv(
+        "tests/data/wheel/node_list.csv",
+        "tests/data/wheel/edge_list.csv",
+    )
+    .unwrap();
+    let dense_graph = DenseCounterGraph(&graph);
+    let mut scratch = GraphletScratch::default();
+    let mut graphlet_counter: HashMap<u16, u32> = HashMap::new();
+    let mut dense_graphlet_counter =
+        DenseGraphletCounter::with_number_of_elements(graph.get_number_of_node_labels());
+
+    for (src, dst) in graph.iter_edges() {
+        graph.get_heterogeneous_graphlet_into(src, dst, &mut scratch, &mut graphlet_counter);
+        dense_graph.get_heterogeneous_graphlet_into(
+            src,
+            dst,
+            &mut scratch,
+            &mut dense_graphlet_counter,
+        );
+        let capacity = graphlet_counter.capacity();
+        assert!(capacity > 0);
+
+        graphlet_counter.clear();
+        dense_graphlet_counter.clear();
+        assert!(graphlet_counter.is_empty());
+        assert_eq!(graphlet_counter.capacity(), capacity);
+        assert_eq!(
+            dense_graphlet_counter.iter_graphlets_and_counts().count(),
+            0
+        );
+        assert_eq!(dense_graphlet_counter.examined(), 0);
+    }
+}
+
+#[test]
+fn test_total_for_kind() {
+    for graph_name in [
+        "wheel",
+        "tailed_four_clique",
+        "clique_and_diamond",
+        "labeled_paths",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let number_of_elements = graph.get_number_of_node_labels();
+        let counts = graph.count_all_graphlets();
+        let dense_counts = count_graphlets_dense(&graph);
+
+        // The totals of each kind match the orbit counts summed over the edges.
+        let mut expected = [0_u32; 12];
+        for (src, dst) in graph.iter_edges().filter(|(src, dst)| src < dst) {
+            for (total, count) in expected
+                .iter_mut()
+                .zip(graph.get_edge_orbit_counts(src, dst))
+            {
+                *total += count;
+            }
+        }
+        for graphlet_kind in ExtendedGraphletType::all() {
+            let total =
+                counts.total_for_kind::<_, PolynomialLayout>(graphlet_kind, number_of_elements);
+            assert_eq!(
+                total,
+                expected[graphlet_kind.orbit_index()],
+                "{} {:?}",
+                graph_name,
+                graphlet_kind
+            );
+            assert_eq!(
+                dense_counts
+                    .total_for_kind::<_, PolynomialLayout>(graphlet_kind, number_of_elements),
+                total
+            );
+        }
+        assert_eq!(counts.total_count(), expected.iter().sum::<u32>());
+        assert_eq!(dense_counts.total_count(), counts.total_count());
+    }
+}
+
+#[test]
+fn test_maximal_hash_values() {
+    // The hashes of the graphlets counted by `get_heterogeneous_graphlet` follow the
+    // polynomial layout, in base one more than the number of elements, so that its
+    // maximal hash is the one of the dummy label of the three-node graphlets.
+    let polynomial =
+        <PolynomialLayout as GraphletHashLayout<u64, u64>>::maximal_hash::<ExtendedGraphletType>;
+    assert_eq!(polynomial(1), 191);
+    assert_eq!(polynomial(2), 971);
+    assert_eq!(polynomial(3), 3_071);
+    assert_eq!(polynomial(5), 15_551);
+    assert_eq!(polynomial(10), 175_691);
+    for number_of_elements in 1..20 {
+        assert_eq!(
+            polynomial(number_of_elements),
+            <[u64; 4] as NTuplePerfectHash<4, u64, u64>>::maximal_hash::<ExtendedGraphletType>(
+                number_of_elements
+            )
+        );
+    }
+    assert_eq!(
+        <PolynomialLayout as GraphletHashLayout<u64, u64>>::maximal_hash::<ReducedGraphletType>(3),
+        2_047
+    );
+
+    // The sentinel layout does not depend on the number of elements.
+    let sentinel =
+        <SentinelLayout as GraphletHashLayout<u64, u8>>::maximal_hash::<ExtendedGraphletType>;
+    assert_eq!(sentinel(1), 12 * 256_u64.pow(4) - 1);
+    assert_eq!(sentinel(1), sentinel(200));
+
+    // The edge-typed hashes are strictly smaller than their maximal hash.
+    assert_eq!(maximal_edge_typed_hash(1, 1), Some(12 * 16 * 64));
+    assert_eq!(maximal_edge_typed_hash(2, 1), Some(12 * 81 * 64));
+    assert_eq!(maximal_edge_typed_hash(usize::MAX, 1), None);
+}
+
+#[test]
+fn test_canonical_labels() {
+    for graph_name in [
+        "four_cycle",
+        "wheel",
+        "clique_and_diamond",
+        "triangle_and_path",
+        "labeled_paths",
+        "six_clique",
+        "citeseer",
+    ] {
+        let graph = CSRGraph::from_csv(
+            &format!("tests/data/{}/node_list.csv", graph_name),
+            &format!("tests/data/{}/edge_list.csv", graph_name),
+        )
+        .unwrap();
+        let number_of_node_labels = graph.get_number_of_node_labels();
+
+        // The canonical keys do not depend on the node IDs, under both the default
+        // and the sentinel layout, as both decode every key exactly.
+        let canonical_counts = graph.count_all_canonical_graphlets();
+        let sentinel_canonical_counts =
+            LayoutGraph::<SentinelLayout>::new(&graph).count_all_canonical_graphlets();
+        for seed in 1..=3 {
+            let (copy, _) = graph.random_isomorphism(&mut StdRng::seed_from_u64(seed));
+            assert_eq!(
+                copy.count_all_canonical_graphlets(),
+                canonical_counts,
+                "{} {}",
+                graph_name,
+                seed
+            );
+            assert_eq!(
+                LayoutGraph::<SentinelLayout>::new(&copy).count_all_canonical_graphlets(),
+                sentinel_canonical_counts,
+                "{} {}",
+                graph_name,
+                seed
+            );
+        }
+
+        // The canonicalization of the counters agrees with the canonical counting,
+        // whatever the layout.
+        assert_eq!(
+            LayoutGraph::<SentinelLayout>::new(&graph)
+                .count_all_graphlets()
+                .canonicalize::<_, SentinelLayout>(number_of_node_labels),
+            sentinel_canonical_counts
+        );
+
+        // The canonicalization only merges keys of the same graphlet kind.
+        let counts = graph.count_all_graphlets();
+        assert!(canonical_counts.len() <= counts.len());
+        assert_eq!(
+            counts.canonicalize::<_, PolynomialLayout>(number_of_node_labels),
+            canonical_counts
+        );
+        for graphlet_kind in ExtendedGraphletType::all() {
+            assert_eq!(
+                canonical_counts
+                    .total_for_kind::<_, PolynomialLayout>(graphlet_kind, number_of_node_labels),
+                counts.total_for_kind::<_, PolynomialLayout>(graphlet_kind, number_of_node_labels),
+            );
+        }
+    }
+
+    // The four-cycle with alternating labels has a single canonical key,
+    // while its keys follow the node IDs of the endpoints of the edges.
+    let graph = CSRGraph::from_edges(
+        vec![0, 1, 0, 1],
+        [(0, 1), (1, 2), (2, 3), (3, 0)]
+            .into_iter()
+            .flat_map(|(src, dst)| [(src, dst), (dst, src)])
+            .collect(),
+    )
+    .unwrap();
+    let four_cycles = |counts: HashMap<u16, u32>| {
+        counts
+            .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(2)
+            .filter(|(graphlet_kind, _, _)| *graphlet_kind == ExtendedGraphletType::FourCycle)
+            .map(|(_, labels, count)| (labels, count))
+            .collect::<BTreeMap<_, _>>()
+    };
+    assert_eq!(
+        four_cycles(graph.count_all_graphlets()),
+        BTreeMap::from([((0, 1, 1, 0), 3), ((1, 0, 0, 1), 1)])
+    );
+    assert_eq!(
+        four_cycles(graph.count_all_canonical_graphlets()),
+        BTreeMap::from([((0, 1, 1, 0), 4)])
+    );
+}
+
+#[test]
+fn test_directed_heterogeneous_graphlets() {
+    let graph = DirectedCSRGraph::from_csv(
+        "tests/data/directed_triangle_and_path/node_list.csv",
+        "tests/data/directed_triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    let undirected_graph = CSRGraph::from_directed_csv(
+        "tests/data/directed_triangle_and_path/node_list.csv",
+        "tests/data/directed_triangle_and_path/edge_list.csv",
+    )
+    .unwrap();
+    assert_eq!(graph.as_undirected(), &undirected_graph);
+    assert_eq!(
+        graph.count_all_graphlets(),
+        count_graphlets(&undirected_graph)
+    );
+
+    // Node 5 points to 4 and 6, and 6 points back to it.
+    assert_eq!(graph.iter_out_neighbours(5).collect::<Vec<_>>(), [4, 6]);
+    assert_eq!(graph.iter_in_neighbours(5).collect::<Vec<_>>(), [6]);
+    assert_eq!(graph.iter_in_neighbours(4).collect::<Vec<_>>(), [3, 5]);
+    assert_eq!(graph.iter_neighbours(5).collect::<Vec<_>>(), [4, 6]);
+
+    assert_eq!(graph.get_edge_direction(3, 4), Some(EdgeDirection::Forward));
+    assert_eq!(
+        graph.get_edge_direction(4, 5),
+        Some(EdgeDirection::Backward)
+    );
+    assert_eq!(
+        graph.get_edge_direction(5, 6),
+        Some(EdgeDirection::Reciprocal)
+    );
+    assert_eq!(graph.get_edge_direction(3, 5), None);
+    assert!(graph.get_directed_heterogeneous_graphlet(3, 5).is_none());
+
+    // The graphlets are counted from the tail to the head of the edge, whichever
+    // order the nodes are provided in.
+    let (direction, counts) = graph.get_directed_heterogeneous_graphlet(4, 3).unwrap();
+    assert_eq!(direction, EdgeDirection::Backward);
+    assert_eq!(counts, undirected_graph.get_heterogeneous_graphlet(3, 4));
+
+    // With two labels, the orbit position of the nodes of the edge follows its direction.
+    let graph = DirectedCSRGraph::from_edges(vec![0, 1, 1], vec![(0, 1), (2, 0), (1, 2)]).unwrap();
+    let decode = |counts: HashMap<u16, u32>| {
+        counts
+            .iter_decoded::<ExtendedGraphletType, u8, PolynomialLayout>(2)
+            .collect::<Vec<_>>()
+    };
+    for (src, dst, direction, labels) in [
+        (0, 1, EdgeDirection::Forward, (0, 1, 1, 2)),
+        (1, 0, EdgeDirection::Backward, (0, 1, 1, 2)),
+        (0, 2, EdgeDirection::Backward, (1, 0, 1, 2)),
+        (2, 0, EdgeDirection::Forward, (1, 0, 1, 2)),
+    ] {
+        let (edge_direction, counts) = graph.get_directed_heterogeneous_graphlet(src, dst).unwrap();
+        assert_eq!(edge_direction, direction, "{} {}", src, dst);
+        assert_eq!(
+            decode(counts),
+            [(ExtendedGraphletType::Triangle, labels, 1)],
+            "{} {}",
+            src,
+            dst
+        );
+    }
+}